@@ -1,6 +1,7 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use intervals_general::bound_pair::BoundPair;
 use intervals_general::interval::Interval;
+use intervals_general::typed::{IntervalOps, LeftHalfOpenInterval};
 
 fn interval_operations(c: &mut Criterion) {
     c.bench_function("intervals_general_u32_intersect", |b| {
@@ -21,6 +22,13 @@ fn interval_operations(c: &mut Criterion) {
             .width()
         })
     });
+    c.bench_function("typed_u32_intersect", |b| {
+        b.iter(|| {
+            LeftHalfOpenInterval::new(20u32, 30u32)
+                .unwrap()
+                .intersect(&LeftHalfOpenInterval::new(20u32, 30u32).unwrap())
+        })
+    });
 }
 
 criterion_group!(benches, interval_operations);
@@ -0,0 +1,187 @@
+//! Power-of-two aligned decomposition of integer Intervals
+//!
+//! Memory ranges, address prefixes (CIDR) and buddy allocators deal in blocks
+//! of `2^k` values starting at a multiple of `2^k`.  Any bounded Interval of
+//! u64 values splits into a minimal sequence of such blocks, up to a maximum
+//! block order.
+
+use crate::bound_pair::BoundPair;
+use crate::interval::{Bound, Interval};
+
+/// Iterator over the aligned power-of-two blocks covering an Interval
+///
+/// Constructed by [Interval::aligned_blocks].  Blocks are yielded in
+/// ascending order as Closed intervals, or Singletons for blocks of order 0.
+#[derive(Debug, Clone)]
+pub struct AlignedBlocks {
+    // The (first, last) values yet to be covered, None once exhausted
+    remaining: Option<(u64, u64)>,
+    max_order: u32,
+}
+
+impl Iterator for AlignedBlocks {
+    type Item = Interval<u64>;
+
+    fn next(&mut self) -> Option<Interval<u64>> {
+        let (first, last) = self.remaining?;
+        // The largest order fitting the values left, last - first + 1 of them
+        let fits = match (last - first).checked_add(1) {
+            Some(count) => u64::BITS - 1 - count.leading_zeros(),
+            None => u64::BITS,
+        };
+        let order = first.trailing_zeros().min(fits).min(self.max_order);
+        let end = match order {
+            u64::BITS => u64::MAX,
+            order => first + ((1 << order) - 1),
+        };
+        self.remaining = end
+            .checked_add(1)
+            .filter(|next| *next <= last)
+            .map(|next| (next, last));
+        Some(match BoundPair::new(first, end) {
+            Some(bound_pair) => Interval::Closed { bound_pair },
+            None => Interval::Singleton { at: first },
+        })
+    }
+}
+
+impl std::iter::FusedIterator for AlignedBlocks {}
+
+impl Interval<u64> {
+    /// Decompose into the fewest aligned blocks of at most `2^max_order` values
+    ///
+    /// Each block holds `2^k` consecutive values for some `k <= max_order`,
+    /// starting at a multiple of `2^k`, so that e.g. `[5..12]` decomposes
+    /// into `[5]`, `[6..7]`, `[8..11]` and `[12]`.  Open bounds are stepped
+    /// inward to the nearest contained value.  A max_order of 0 yields every
+    /// value as a Singleton, and one of 64 or more places no limit.
+    ///
+    /// Returns None if the interval lacks a finite left or right bound.  The
+    /// Empty interval yields no blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let range = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(0x1000, 0x3800).ok_or("invalid BoundPair")?,
+    /// };
+    /// let blocks: Vec<_> = range.aligned_blocks(64).ok_or("unbounded range")?.collect();
+    /// assert_eq!(
+    ///     blocks,
+    ///     vec![
+    ///         Interval::Closed {
+    ///             bound_pair: BoundPair::new(0x1000, 0x1fff).ok_or("invalid BoundPair")?
+    ///         },
+    ///         Interval::Closed {
+    ///             bound_pair: BoundPair::new(0x2000, 0x2fff).ok_or("invalid BoundPair")?
+    ///         },
+    ///         Interval::Closed {
+    ///             bound_pair: BoundPair::new(0x3000, 0x37ff).ok_or("invalid BoundPair")?
+    ///         },
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn aligned_blocks(&self, max_order: u32) -> Option<AlignedBlocks> {
+        let remaining = match self.tighten_open_bounds() {
+            Interval::Empty => None,
+            closed => match (closed.left_bound(), closed.right_bound()) {
+                (Bound::Closed(first), Bound::Closed(last)) => Some((first, last)),
+                _ => return None,
+            },
+        };
+        Some(AlignedBlocks {
+            remaining,
+            max_order,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocks(interval: Interval<u64>, max_order: u32) -> Vec<Interval<u64>> {
+        interval.aligned_blocks(max_order).unwrap().collect()
+    }
+
+    fn closed(left: u64, right: u64) -> Interval<u64> {
+        Interval::Closed {
+            bound_pair: BoundPair::new(left, right).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_aligned_blocks() {
+        assert_eq!(
+            blocks(closed(5, 12), 64),
+            vec![
+                Interval::Singleton { at: 5 },
+                closed(6, 7),
+                closed(8, 11),
+                Interval::Singleton { at: 12 },
+            ]
+        );
+        assert_eq!(blocks(closed(0, 15), 64), vec![closed(0, 15)]);
+        assert_eq!(
+            blocks(closed(0, 15), 2),
+            vec![closed(0, 3), closed(4, 7), closed(8, 11), closed(12, 15)]
+        );
+        assert_eq!(blocks(closed(6, 9), 0).len(), 4);
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(3, 8).unwrap(),
+        };
+        assert_eq!(blocks(open, 64), vec![closed(4, 7)]);
+    }
+
+    #[test]
+    fn test_aligned_blocks_cover_exactly() {
+        for left in 0..40 {
+            for right in left..40 {
+                for max_order in [0, 1, 3, 64] {
+                    let interval = Interval::from_bounds(Bound::Closed(left), Bound::Closed(right));
+                    let mut next = left;
+                    for block in blocks(interval, max_order) {
+                        let (first, last) = match block {
+                            Interval::Singleton { at } => (at, at),
+                            Interval::Closed { bound_pair } => {
+                                (*bound_pair.left(), *bound_pair.right())
+                            }
+                            other => panic!("unexpected {}", other),
+                        };
+                        let size = last - first + 1;
+                        assert_eq!(first, next);
+                        assert!(size.is_power_of_two());
+                        assert!(size <= 1 << max_order.min(63));
+                        assert_eq!(first % size, 0);
+                        next = last + 1;
+                    }
+                    assert_eq!(next, right + 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_aligned_blocks_extrema() {
+        assert_eq!(blocks(closed(0, u64::MAX), 64), vec![closed(0, u64::MAX)]);
+        assert_eq!(
+            blocks(closed(0, u64::MAX), 63),
+            vec![closed(0, u64::MAX >> 1), closed(1 << 63, u64::MAX)]
+        );
+        assert_eq!(blocks(closed(1, u64::MAX), 64).len(), 64);
+        assert_eq!(
+            blocks(Interval::Singleton { at: u64::MAX }, 64),
+            vec![Interval::Singleton { at: u64::MAX }]
+        );
+        assert_eq!(blocks(Interval::Empty, 64), vec![]);
+        assert!(Interval::UnboundedClosedLeft { left: 0 }
+            .aligned_blocks(64)
+            .is_none());
+        assert!(Interval::<u64>::Unbounded.aligned_blocks(64).is_none());
+    }
+}
@@ -28,6 +28,8 @@ mod with_serde {
     }
 }
 
+use crate::error::IntervalError;
+
 #[cfg(feature = "serde")]
 pub use with_serde::BoundPair;
 #[cfg(not(feature = "serde"))]
@@ -66,6 +68,37 @@ where
         }
     }
 
+    /// Create a new Bound Pair, reporting why mal-formed bounds were rejected
+    ///
+    /// Equivalent to [BoundPair::new], save that failure yields an
+    /// [IntervalError] distinguishing reversed, equal, and incomparable (e.g.
+    /// NaN) bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::error::IntervalError;
+    /// # fn main() -> std::result::Result<(), IntervalError> {
+    /// let bounds = BoundPair::try_new(1.0, 2.0)?;
+    /// assert_eq!(BoundPair::try_new(2, 1), Err(IntervalError::ReversedBounds));
+    /// assert_eq!(BoundPair::try_new(1, 1), Err(IntervalError::EqualBounds));
+    /// assert_eq!(
+    ///     BoundPair::try_new(f64::NAN, 1.0),
+    ///     Err(IntervalError::IncomparableBounds)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_new(left: T, right: T) -> Result<BoundPair<T>, IntervalError> {
+        match left.partial_cmp(&right) {
+            Some(std::cmp::Ordering::Less) => Ok(BoundPair { left, right }),
+            Some(std::cmp::Ordering::Equal) => Err(IntervalError::EqualBounds),
+            Some(std::cmp::Ordering::Greater) => Err(IntervalError::ReversedBounds),
+            None => Err(IntervalError::IncomparableBounds),
+        }
+    }
+
     /// Fetch an immutable reference to the left bound
     ///
     /// # Examples
@@ -97,6 +130,220 @@ where
     pub fn right(&self) -> &T {
         &self.right
     }
+
+    /// Replace the left bound, provided left < right still holds
+    ///
+    /// On failure self is left unchanged, and the [IntervalError] reports why
+    /// the new bound was rejected as per [BoundPair::try_new].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::error::IntervalError;
+    /// # fn main() -> std::result::Result<(), IntervalError> {
+    /// let mut bounds = BoundPair::try_new(1, 5)?;
+    /// bounds.try_set_left(3)?;
+    /// assert_eq!(*bounds.left(), 3);
+    /// assert_eq!(bounds.try_set_left(6), Err(IntervalError::ReversedBounds));
+    /// assert_eq!(*bounds.left(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_set_left(&mut self, left: T) -> Result<(), IntervalError> {
+        *self = BoundPair::try_new(left, self.right)?;
+        Ok(())
+    }
+
+    /// Replace the right bound, provided left < right still holds
+    ///
+    /// On failure self is left unchanged, see [BoundPair::try_set_left].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::error::IntervalError;
+    /// # fn main() -> std::result::Result<(), IntervalError> {
+    /// let mut bounds = BoundPair::try_new(1, 5)?;
+    /// bounds.try_set_right(9)?;
+    /// assert_eq!(*bounds.right(), 9);
+    /// assert_eq!(bounds.try_set_right(1), Err(IntervalError::EqualBounds));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_set_right(&mut self, right: T) -> Result<(), IntervalError> {
+        *self = BoundPair::try_new(self.left, right)?;
+        Ok(())
+    }
+
+    /// Replace both bounds with those computed from the current bounds
+    ///
+    /// The closure receives the current (left, right) bounds and returns the
+    /// new pair, which is validated as per [BoundPair::try_new].  On failure
+    /// self is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::error::IntervalError;
+    /// # fn main() -> std::result::Result<(), IntervalError> {
+    /// let mut bounds = BoundPair::try_new(1.0, 5.0)?;
+    /// bounds.try_update(|left, right| (left * 2.0, right * 2.0))?;
+    /// assert_eq!((*bounds.left(), *bounds.right()), (2.0, 10.0));
+    /// assert_eq!(
+    ///     bounds.try_update(|left, right| (right, left)),
+    ///     Err(IntervalError::ReversedBounds)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_update<F>(&mut self, f: F) -> Result<(), IntervalError>
+    where
+        F: FnOnce(T, T) -> (T, T),
+    {
+        let (left, right) = f(self.left, self.right);
+        *self = BoundPair::try_new(left, right)?;
+        Ok(())
+    }
+
+    /// The smallest BoundPair spanning both self and other
+    ///
+    /// Takes the lesser left bound and the greater right bound, e.g. to
+    /// accumulate the extent of successive batches of data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let a = BoundPair::new(1, 5).ok_or("invalid BoundPair")?;
+    /// let b = BoundPair::new(3, 8).ok_or("invalid BoundPair")?;
+    /// assert_eq!(a.widen(&b), BoundPair::new(1, 8).ok_or("invalid BoundPair")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn widen(&self, other: &BoundPair<T>) -> BoundPair<T> {
+        BoundPair {
+            left: if other.left < self.left {
+                other.left
+            } else {
+                self.left
+            },
+            right: if other.right > self.right {
+                other.right
+            } else {
+                self.right
+            },
+        }
+    }
+
+    /// The BoundPair common to both self and other
+    ///
+    /// Takes the greater left bound and the lesser right bound, returning
+    /// None if these do not form a valid BoundPair (i.e. the pairs overlap in
+    /// at most a single value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let a = BoundPair::new(1, 5).ok_or("invalid BoundPair")?;
+    /// let b = BoundPair::new(3, 8).ok_or("invalid BoundPair")?;
+    /// assert_eq!(a.narrow(&b), BoundPair::new(3, 5));
+    /// assert_eq!(a.narrow(&BoundPair::new(5, 8).ok_or("invalid BoundPair")?), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn narrow(&self, other: &BoundPair<T>) -> Option<BoundPair<T>> {
+        let left = if other.left > self.left {
+            other.left
+        } else {
+            self.left
+        };
+        let right = if other.right < self.right {
+            other.right
+        } else {
+            self.right
+        };
+        BoundPair::new(left, right)
+    }
+
+    /// Transform both bounds, revalidating the left < right invariant
+    ///
+    /// Returns None if the mapped bounds do not form a valid BoundPair, e.g.
+    /// because f is not strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let millis = BoundPair::new(1500, 2500).ok_or("invalid BoundPair")?;
+    /// let seconds = millis.map(|ms| f64::from(ms) / 1000.0);
+    /// assert_eq!(seconds, BoundPair::new(1.5, 2.5));
+    /// assert_eq!(millis.map(|ms| -ms), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map<U, F>(&self, mut f: F) -> Option<BoundPair<U>>
+    where
+        U: Copy,
+        U: PartialOrd,
+        F: FnMut(T) -> U,
+    {
+        BoundPair::new(f(self.left), f(self.right))
+    }
+}
+
+/// Destructure a BoundPair into its (left, right) bounds
+impl<T> From<BoundPair<T>> for (T, T) {
+    fn from(bound_pair: BoundPair<T>) -> (T, T) {
+        (bound_pair.left, bound_pair.right)
+    }
+}
+
+impl<T> BoundPair<T> {
+    // Borrow both bounds through Deref, the ordering invariant is unaffected
+    pub(crate) fn as_deref(&self) -> BoundPair<&T::Target>
+    where
+        T: std::ops::Deref,
+    {
+        BoundPair {
+            left: self.left.deref(),
+            right: self.right.deref(),
+        }
+    }
+}
+
+/// Generate BoundPairs from fuzzer input, maintaining left < right
+///
+/// Two bound values are drawn and ordered; equal or incomparable values are
+/// rejected with [arbitrary::Error::IncorrectFormat].  As exhausted input
+/// yields equal default values, short inputs are rejected too.  The
+/// Interval implementation instead falls back to a Singleton for equal
+/// values, so that short inputs still yield Intervals.
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for BoundPair<T>
+where
+    T: arbitrary::Arbitrary<'a>,
+    T: Copy,
+    T: PartialOrd,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (a, b) = (T::arbitrary(u)?, T::arbitrary(u)?);
+        match a.partial_cmp(&b) {
+            Some(std::cmp::Ordering::Less) => Ok(BoundPair { left: a, right: b }),
+            Some(std::cmp::Ordering::Greater) => Ok(BoundPair { left: b, right: a }),
+            _ => Err(arbitrary::Error::IncorrectFormat),
+        }
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(T::size_hint(depth), T::size_hint(depth))
+    }
 }
 
 #[cfg(test)]
@@ -117,6 +364,23 @@ mod tests {
         assert!(BoundPair::new(100u32, 50u32).is_none());
     }
 
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            BoundPair::try_new(1, 2),
+            Ok(BoundPair { left: 1, right: 2 })
+        );
+        assert_eq!(BoundPair::try_new(2, 1), Err(IntervalError::ReversedBounds));
+        assert_eq!(
+            BoundPair::try_new(1.0, 1.0),
+            Err(IntervalError::EqualBounds)
+        );
+        assert_eq!(
+            BoundPair::try_new(1.0, f64::NAN),
+            Err(IntervalError::IncomparableBounds)
+        );
+    }
+
     #[test]
     fn test_accessors() {
         let bp = BoundPair::new(1.5, 2.5).unwrap();
@@ -124,6 +388,79 @@ mod tests {
         assert_eq!(*bp.right(), 2.5);
     }
 
+    #[test]
+    fn test_try_set() {
+        let mut bp = BoundPair::new(1, 5).unwrap();
+        assert_eq!(bp.try_set_left(4), Ok(()));
+        assert_eq!(bp.try_set_right(4), Err(IntervalError::EqualBounds));
+        assert_eq!(bp.try_set_right(2), Err(IntervalError::ReversedBounds));
+        assert_eq!(bp, BoundPair { left: 4, right: 5 });
+        assert_eq!(bp.try_set_right(8), Ok(()));
+        assert_eq!(bp, BoundPair { left: 4, right: 8 });
+
+        let mut bp = BoundPair::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            bp.try_set_left(f64::NAN),
+            Err(IntervalError::IncomparableBounds)
+        );
+        assert_eq!(*bp.left(), 0.0);
+    }
+
+    #[test]
+    fn test_try_update() {
+        let mut bp = BoundPair::new(1, 5).unwrap();
+        assert_eq!(bp.try_update(|l, r| (l - 1, r + 1)), Ok(()));
+        assert_eq!(bp, BoundPair { left: 0, right: 6 });
+        assert_eq!(
+            bp.try_update(|l, _| (l, l)),
+            Err(IntervalError::EqualBounds)
+        );
+        assert_eq!(bp, BoundPair { left: 0, right: 6 });
+    }
+
+    #[test]
+    fn test_widen() {
+        let a = BoundPair::new(1, 5).unwrap();
+        let b = BoundPair::new(7, 9).unwrap();
+        assert_eq!(a.widen(&b), BoundPair { left: 1, right: 9 });
+        assert_eq!(b.widen(&a), BoundPair { left: 1, right: 9 });
+        assert_eq!(a.widen(&a), a);
+
+        let batches = [(0.5, 1.0), (-2.0, 0.0), (0.25, 3.5)];
+        let extent = batches
+            .iter()
+            .filter_map(|&(l, r)| BoundPair::new(l, r))
+            .reduce(|acc, bp| acc.widen(&bp));
+        assert_eq!(extent, BoundPair::new(-2.0, 3.5));
+    }
+
+    #[test]
+    fn test_narrow() {
+        let a = BoundPair::new(1, 5).unwrap();
+        assert_eq!(
+            a.narrow(&BoundPair::new(2, 3).unwrap()),
+            BoundPair::new(2, 3)
+        );
+        assert_eq!(a.narrow(&BoundPair::new(0, 9).unwrap()), Some(a));
+        assert_eq!(a.narrow(&BoundPair::new(5, 9).unwrap()), None);
+        assert_eq!(a.narrow(&BoundPair::new(6, 9).unwrap()), None);
+    }
+
+    #[test]
+    fn test_map() {
+        let bp = BoundPair::new(1, 5).unwrap();
+        assert_eq!(bp.map(|v| v * 10), BoundPair::new(10, 50));
+        assert_eq!(bp.map(|v| v % 2), None);
+        assert_eq!(bp.map(|_| 0), None);
+        assert_eq!(bp.map(|v| if v > 1 { f64::NAN } else { 0.0 }), None);
+    }
+
+    #[test]
+    fn test_into_tuple() {
+        let (left, right) = BoundPair::new(1.5, 2.5).unwrap().into();
+        assert_eq!((left, right), (1.5, 2.5));
+    }
+
     #[test]
     fn test_floating_point() {
         assert!(BoundPair::new(f64::NEG_INFINITY, f64::INFINITY).is_some());
@@ -167,3 +504,22 @@ mod serde_tests {
         assert_eq!(bp1, bp2);
     }
 }
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use super::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary_orders_bounds() {
+        let mut u = Unstructured::new(&[9, 0, 0, 0, 2, 0, 0, 0]);
+        let bp = BoundPair::<u32>::arbitrary(&mut u).unwrap();
+        assert_eq!((*bp.left(), *bp.right()), (2, 9));
+    }
+
+    #[test]
+    fn test_arbitrary_rejects_equal_bounds() {
+        let mut u = Unstructured::new(&[7, 7]);
+        assert!(BoundPair::<u8>::arbitrary(&mut u).is_err());
+    }
+}
@@ -0,0 +1,394 @@
+use crate::bound_pair::BoundPair;
+use crate::error::IntervalError;
+use crate::interval::{Bound, Interval};
+
+/// Construct the key range covering every byte string beginning with prefix
+///
+/// Yields the right-half-open range `[prefix, successor)`, where successor is
+/// the shortest byte string greater than every string with the prefix: the
+/// prefix with trailing `0xFF` bytes stripped and its last byte incremented.
+/// A prefix consisting entirely of `0xFF` bytes (including the empty prefix)
+/// has no successor, yielding the unbounded range `[prefix, inf)`.
+///
+/// Bounds are owned, use [Interval::as_deref] to obtain an `Interval<&[u8]>`
+/// on which Interval operations are available.
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::bound_pair::BoundPair;
+/// use intervals_general::bytes::prefix_range;
+/// use intervals_general::interval::Interval;
+/// # fn main() -> std::result::Result<(), String> {
+/// let range = prefix_range(b"user/");
+/// let keys = range.as_deref();
+///
+/// assert!(keys.contains(&Interval::Singleton { at: &b"user/42"[..] }));
+/// assert!(!keys.contains(&Interval::Singleton { at: &b"users"[..] }));
+/// assert_eq!(
+///     prefix_range(&[0x01, 0xFF]).as_deref(),
+///     Interval::RightHalfOpen {
+///         bound_pair: BoundPair::new(&[0x01, 0xFF][..], &[0x02][..])
+///             .ok_or("invalid BoundPair")?
+///     }
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn prefix_range(prefix: &[u8]) -> Interval<Vec<u8>> {
+    match prefix.iter().rposition(|&byte| byte != u8::MAX) {
+        Some(last) => {
+            let mut successor = prefix[..=last].to_vec();
+            successor[last] += 1;
+            Interval::RightHalfOpen {
+                bound_pair: BoundPair {
+                    left: prefix.to_vec(),
+                    right: successor,
+                },
+            }
+        }
+        None => Interval::UnboundedClosedLeft {
+            left: prefix.to_vec(),
+        },
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    macro_rules! sealed {
+        ($($t:ty),*) => {
+            $(impl Sealed for $t {})*
+        };
+    }
+    sealed!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+    #[cfg(feature = "chrono")]
+    impl Sealed for chrono::DateTime<chrono::Utc> {}
+}
+
+/// Bound data types with a fixed width, order preserving byte encoding
+///
+/// Integers encode big-endian (with the sign bit flipped for signed types),
+/// and with the chrono feature `DateTime<Utc>` encodes as its signed seconds
+/// and nanoseconds since the epoch, so that byte order matches value order.
+/// This trait is sealed and cannot be implemented outside of
+/// intervals-general.
+pub trait OrderedBytes: private::Sealed + Copy + PartialOrd {
+    /// The encoded width in bytes
+    const WIDTH: usize;
+
+    /// Append the encoding of self to out
+    fn write_ordered(&self, out: &mut Vec<u8>);
+
+    /// Decode exactly WIDTH bytes, None if they encode no valid value
+    fn read_ordered(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_ordered_bytes {
+    ($($t:ty => $unsigned:ty, $flip:expr);*) => {
+        $(
+            impl OrderedBytes for $t {
+                const WIDTH: usize = std::mem::size_of::<$t>();
+
+                fn write_ordered(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&((*self as $unsigned) ^ $flip).to_be_bytes());
+                }
+
+                fn read_ordered(bytes: &[u8]) -> Option<Self> {
+                    let bytes = bytes.try_into().ok()?;
+                    Some((<$unsigned>::from_be_bytes(bytes) ^ $flip) as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_ordered_bytes!(
+    u8 => u8, 0;
+    u16 => u16, 0;
+    u32 => u32, 0;
+    u64 => u64, 0;
+    u128 => u128, 0;
+    i8 => u8, 1 << 7;
+    i16 => u16, 1 << 15;
+    i32 => u32, 1 << 31;
+    i64 => u64, 1 << 63;
+    i128 => u128, 1 << 127
+);
+
+#[cfg(feature = "chrono")]
+impl OrderedBytes for chrono::DateTime<chrono::Utc> {
+    const WIDTH: usize = 12;
+
+    fn write_ordered(&self, out: &mut Vec<u8>) {
+        self.timestamp().write_ordered(out);
+        self.timestamp_subsec_nanos().write_ordered(out);
+    }
+
+    fn read_ordered(bytes: &[u8]) -> Option<Self> {
+        let (seconds, nanos) = bytes.split_at_checked(8)?;
+        chrono::DateTime::from_timestamp(i64::read_ordered(seconds)?, u32::read_ordered(nanos)?)
+    }
+}
+
+// Leading byte of the left bound: Empty sorts first, then left-unbounded
+const LEFT_EMPTY: u8 = 0;
+const LEFT_UNBOUNDED: u8 = 1;
+const LEFT_FINITE: u8 = 2;
+// Leading byte of the right bound: right-unbounded sorts last
+const RIGHT_FINITE: u8 = 0;
+const RIGHT_UNBOUNDED: u8 = 1;
+
+impl<T> Interval<T>
+where
+    T: OrderedBytes,
+{
+    /// The length in bytes of [Interval::to_ordered_bytes] encodings
+    pub const ORDERED_BYTES_LEN: usize = 2 * (T::WIDTH + 2);
+
+    /// Encode self as bytes whose lexicographic order follows the left bound
+    ///
+    /// Suitable as keys of ordered key-value stores.  Encodings have the
+    /// fixed length [Interval::ORDERED_BYTES_LEN] and sort by left bound
+    /// (left-unbounded first, and a closed bound before an open bound at the
+    /// same value), then by right bound (an open bound before a closed bound
+    /// at the same value, and right-unbounded last).  The Empty interval
+    /// sorts before all others.  The encoding depends only on the bounds, not
+    /// on Interval variant numbering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let early = Interval::UnboundedOpenRight { right: 0i64 };
+    /// let late = Interval::Closed {
+    ///     bound_pair: BoundPair::new(-5i64, 10).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert!(early.to_ordered_bytes() < late.to_ordered_bytes());
+    /// assert_eq!(Interval::from_ordered_bytes(&late.to_ordered_bytes())?, late);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_ordered_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(Self::ORDERED_BYTES_LEN);
+        // Each bound fills half the encoding with a tag, value and openness
+        // trailer, zero padded where the bound has no value
+        let mut push = |tag, value: Option<(T, u8)>| {
+            let end = out.len() + Self::ORDERED_BYTES_LEN / 2;
+            out.push(tag);
+            if let Some((value, trailer)) = value {
+                value.write_ordered(&mut out);
+                out.push(trailer);
+            }
+            out.resize(end, 0);
+        };
+        match self.left_bound() {
+            Bound::None => push(LEFT_EMPTY, None),
+            Bound::Unbounded => push(LEFT_UNBOUNDED, None),
+            Bound::Closed(left) => push(LEFT_FINITE, Some((left, 0))),
+            Bound::Open(left) => push(LEFT_FINITE, Some((left, 1))),
+        }
+        match self.right_bound() {
+            Bound::None => push(RIGHT_FINITE, None),
+            Bound::Unbounded => push(RIGHT_UNBOUNDED, None),
+            Bound::Open(right) => push(RIGHT_FINITE, Some((right, 0))),
+            Bound::Closed(right) => push(RIGHT_FINITE, Some((right, 1))),
+        }
+        out
+    }
+
+    /// Decode an Interval encoded by [Interval::to_ordered_bytes]
+    ///
+    /// Rejects with [IntervalError::MalformedEncoding] any bytes which are
+    /// not exactly the encoding of some Interval (e.g. of the wrong length,
+    /// with unknown tags or padding, or with reversed bounds).
+    pub fn from_ordered_bytes(bytes: &[u8]) -> Result<Interval<T>, IntervalError> {
+        if bytes.len() != Self::ORDERED_BYTES_LEN {
+            return Err(IntervalError::MalformedEncoding);
+        }
+        let (left, right) = bytes.split_at(Self::ORDERED_BYTES_LEN / 2);
+        // A bound from its leading tag, value and openness trailer
+        let bound = |half: &[u8], finite: u8, unbounded: u8, closed: u8| {
+            let value = || T::read_ordered(&half[1..=T::WIDTH]);
+            match (half[0], half[T::WIDTH + 1]) {
+                (tag, _) if tag == unbounded => Some(Bound::Unbounded),
+                (tag, trailer) if tag == finite && trailer == closed => value().map(Bound::Closed),
+                (tag, trailer) if tag == finite && trailer == 1 - closed => {
+                    value().map(Bound::Open)
+                }
+                _ => None,
+            }
+        };
+        let interval = if left[0] == LEFT_EMPTY {
+            Some(Interval::Empty)
+        } else {
+            bound(left, LEFT_FINITE, LEFT_UNBOUNDED, 0)
+                .zip(bound(right, RIGHT_FINITE, RIGHT_UNBOUNDED, 1))
+                .map(|(left, right)| Interval::from_bounds(left, right))
+        };
+        // Only canonical encodings re-encode to the same bytes
+        match interval {
+            Some(interval) if interval.to_ordered_bytes() == bytes => Ok(interval),
+            _ => Err(IntervalError::MalformedEncoding),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(prefix: &[u8]) -> (Vec<u8>, Option<Vec<u8>>) {
+        match prefix_range(prefix) {
+            Interval::RightHalfOpen {
+                bound_pair: BoundPair { left, right },
+            } => (left, Some(right)),
+            Interval::UnboundedClosedLeft { left } => (left, None),
+            other => panic!("unexpected range {}", other.as_deref()),
+        }
+    }
+
+    fn all_variants() -> Vec<Interval<i16>> {
+        let mut intervals = vec![];
+        for (a, b) in [(i16::MIN, -1), (-1, 0), (0, 1), (0, 2), (1, i16::MAX)] {
+            intervals.extend(crate::generators::all_variant_examples(a, b).unwrap());
+        }
+        intervals
+    }
+
+    #[test]
+    fn test_ordered_bytes_roundtrip() {
+        for interval in all_variants() {
+            let bytes = interval.to_ordered_bytes();
+            assert_eq!(bytes.len(), Interval::<i16>::ORDERED_BYTES_LEN);
+            assert_eq!(Interval::from_ordered_bytes(&bytes), Ok(interval));
+        }
+        for interval in crate::generators::all_variant_examples(u64::MIN, u64::MAX).unwrap() {
+            assert_eq!(
+                Interval::from_ordered_bytes(&interval.to_ordered_bytes()),
+                Ok(interval)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ordered_bytes_order() {
+        let mut intervals = all_variants();
+        intervals.sort_by_key(|interval| interval.to_ordered_bytes());
+        for pair in intervals.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if a == Interval::Empty || b == Interval::Empty {
+                assert_eq!(a, Interval::Empty);
+                continue;
+            }
+            assert_ne!(
+                a.left_partial_cmp(&b),
+                Some(std::cmp::Ordering::Greater),
+                "{} {}",
+                a,
+                b
+            );
+            if a.left_partial_cmp(&b) == Some(std::cmp::Ordering::Equal) {
+                assert_ne!(
+                    a.right_partial_cmp(&b),
+                    Some(std::cmp::Ordering::Greater),
+                    "{} {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ordered_bytes_malformed() {
+        let valid = Interval::Closed {
+            bound_pair: BoundPair::new(1i32, 2).unwrap(),
+        }
+        .to_ordered_bytes();
+        let decode = |bytes: &[u8]| Interval::<i32>::from_ordered_bytes(bytes);
+        assert!(decode(&valid).is_ok());
+        assert_eq!(decode(&valid[1..]), Err(IntervalError::MalformedEncoding));
+        // Swap the low bytes of the left (1) and right (2) values
+        let mut reversed = valid.clone();
+        reversed.swap(4, 10);
+        assert_eq!(decode(&reversed), Err(IntervalError::MalformedEncoding));
+        let mut bad_tag = valid.clone();
+        bad_tag[0] = 7;
+        assert_eq!(decode(&bad_tag), Err(IntervalError::MalformedEncoding));
+        let mut bad_padding = Interval::<i32>::Unbounded.to_ordered_bytes();
+        bad_padding[2] = 1;
+        assert_eq!(decode(&bad_padding), Err(IntervalError::MalformedEncoding));
+        let mut open_singleton = Interval::Singleton { at: 1i32 }.to_ordered_bytes();
+        open_singleton[5] = 1;
+        assert_eq!(
+            decode(&open_singleton),
+            Err(IntervalError::MalformedEncoding)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_ordered_bytes_datetime() {
+        use chrono::{TimeZone, Utc};
+        let before = Utc.with_ymd_and_hms(1960, 1, 1, 0, 0, 0).unwrap();
+        let after = Utc.timestamp_opt(0, 1).unwrap();
+        let interval = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(before, after).unwrap(),
+        };
+        assert_eq!(
+            Interval::from_ordered_bytes(&interval.to_ordered_bytes()),
+            Ok(interval)
+        );
+        assert!(
+            Interval::Singleton { at: before }.to_ordered_bytes()
+                < Interval::Singleton { at: after }.to_ordered_bytes()
+        );
+    }
+
+    #[test]
+    fn test_simple_prefix() {
+        assert_eq!(range(b"abc"), (b"abc".to_vec(), Some(b"abd".to_vec())));
+    }
+
+    #[test]
+    fn test_trailing_max_bytes_stripped() {
+        assert_eq!(
+            range(&[0x61, 0xFF, 0xFF]),
+            (vec![0x61, 0xFF, 0xFF], Some(vec![0x62]))
+        );
+        assert_eq!(
+            range(&[0x00, 0xFE]),
+            (vec![0x00, 0xFE], Some(vec![0x00, 0xFF]))
+        );
+    }
+
+    #[test]
+    fn test_no_successor() {
+        assert_eq!(range(&[0xFF, 0xFF]), (vec![0xFF, 0xFF], None));
+        assert_eq!(range(b""), (vec![], None));
+    }
+
+    #[test]
+    fn test_prefix_membership() {
+        let owned = prefix_range(&[0x10, 0xFF]);
+        let keys = owned.as_deref();
+        let contains = |key: &[u8]| keys.contains(&Interval::Singleton { at: key });
+
+        assert!(contains(&[0x10, 0xFF]));
+        assert!(contains(&[0x10, 0xFF, 0x00]));
+        assert!(contains(&[0x10, 0xFF, 0xFF, 0xFF]));
+        assert!(!contains(&[0x10, 0xFE, 0xFF]));
+        assert!(!contains(&[0x11]));
+        assert!(!contains(&[0x10]));
+    }
+
+    #[test]
+    fn test_everything_prefix() {
+        let owned = prefix_range(b"");
+        let keys = owned.as_deref();
+        assert!(keys.contains(&Interval::Singleton { at: &b""[..] }));
+        assert!(keys.contains(&Interval::Singleton { at: &[0xFF; 8][..] }));
+    }
+}
@@ -0,0 +1,770 @@
+//! Timezone aware calendar Interval constructors
+//!
+//! A calendar day is not always 24 hours long: across a DST transition it
+//! spans 23 or 25 hours, so constructing days as `start + 24h` silently
+//! drifts.  The constructors here instead resolve the local start of each
+//! calendar period in the supplied timezone, yielding RightHalfOpen
+//! intervals of instants which tile the timeline without gaps or overlaps.
+//!
+//! Existing intervals of instants may be snapped outward or inward to local
+//! calendar unit boundaries via [Interval::expand_to] and
+//! [Interval::round_to], and formatted for humans, e.g. as
+//! `"Mar 3, 09:00–17:30"`, via [Interval::humanize].
+
+use crate::bound_pair::BoundPair;
+use crate::interval::{Bound, Interval};
+use chrono::{
+    DateTime, Datelike, Days, Duration, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+};
+
+// The first instant at or after the local wall clock time, skipping over any
+// DST gap in which the wall clock time does not exist
+fn local_start<Tz>(tz: &Tz, local: NaiveDateTime) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+{
+    (0..=24 * 60).find_map(|minutes| {
+        match tz.from_local_datetime(&(local + Duration::minutes(minutes))) {
+            LocalResult::Single(instant) => Some(instant),
+            LocalResult::Ambiguous(earliest, _) => Some(earliest),
+            LocalResult::None => None,
+        }
+    })
+}
+
+// The RightHalfOpen interval between the local starts of two dates
+fn between_dates<Tz>(start: NaiveDate, end: NaiveDate, tz: &Tz) -> Option<Interval<DateTime<Tz>>>
+where
+    Tz: TimeZone,
+{
+    let left = local_start(tz, start.and_hms_opt(0, 0, 0)?)?;
+    let right = local_start(tz, end.and_hms_opt(0, 0, 0)?)?;
+    // Each local start is strictly increasing in the date, so left < right
+    Some(Interval::RightHalfOpen {
+        bound_pair: BoundPair { left, right },
+    })
+}
+
+impl<Tz> Interval<DateTime<Tz>>
+where
+    Tz: TimeZone,
+{
+    /// The instants within the calendar day date, in timezone tz
+    ///
+    /// Yields the RightHalfOpen interval from the start of date to the start
+    /// of the following day, each resolved in tz.  Where local midnight is
+    /// ambiguous the earlier instant is used, and where it falls within a DST
+    /// gap the first instant after the gap is used.  Returns None if the day
+    /// lies outside the representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use chrono_tz::Europe::London;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// // The UK clocks went forward on 2024-03-31
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 31).ok_or("invalid date")?;
+    /// let day = Interval::calendar_day(date, &London).ok_or("invalid day")?;
+    /// assert_eq!(day.width(), Some(chrono::Duration::hours(23)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn calendar_day(date: NaiveDate, tz: &Tz) -> Option<Interval<DateTime<Tz>>> {
+        between_dates(date, date.succ_opt()?, tz)
+    }
+
+    /// The instants within the calendar month, in timezone tz
+    ///
+    /// Yields the RightHalfOpen interval from the start of the first day of
+    /// the month to the start of the first day of the following month, each
+    /// resolved as in [Interval::calendar_day].  Returns None for an invalid
+    /// month (outside 1 through 12) or a month outside the representable
+    /// date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let february = Interval::calendar_month(2024, 2, &Utc).ok_or("invalid month")?;
+    /// assert!(february.contains(&Interval::Singleton {
+    ///     at: Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap()
+    /// }));
+    /// assert_eq!(february.width(), Some(chrono::Duration::days(29)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn calendar_month(year: i32, month: u32, tz: &Tz) -> Option<Interval<DateTime<Tz>>> {
+        let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let end = match month {
+            12 => NaiveDate::from_ymd_opt(year.checked_add(1)?, 1, 1)?,
+            _ => NaiveDate::from_ymd_opt(year, month + 1, 1)?,
+        };
+        between_dates(start, end, tz)
+    }
+}
+
+/// Calendar units to which [Interval::expand_to] and [Interval::round_to]
+/// snap bounds
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CalendarUnit {
+    /// Local wall clock minutes
+    Minute,
+    /// Local wall clock hours
+    Hour,
+    /// Local calendar days, from midnight
+    Day,
+    /// ISO weeks, from midnight on Monday
+    Week,
+}
+
+impl CalendarUnit {
+    // The local start of the unit containing local
+    fn truncate(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = local.date();
+        match self {
+            CalendarUnit::Minute => date.and_hms_opt(local.hour(), local.minute(), 0),
+            CalendarUnit::Hour => date.and_hms_opt(local.hour(), 0, 0),
+            CalendarUnit::Day => date.and_hms_opt(0, 0, 0),
+            CalendarUnit::Week => date
+                .checked_sub_days(Days::new(date.weekday().num_days_from_monday().into()))?
+                .and_hms_opt(0, 0, 0),
+        }
+    }
+
+    // The local start of the unit following that starting at start
+    fn step(self, start: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self {
+            CalendarUnit::Minute => start.checked_add_signed(Duration::minutes(1)),
+            CalendarUnit::Hour => start.checked_add_signed(Duration::hours(1)),
+            CalendarUnit::Day => start.checked_add_days(Days::new(1)),
+            CalendarUnit::Week => start.checked_add_days(Days::new(7)),
+        }
+    }
+}
+
+// The last unit boundary in tz at or before instant
+fn floor_boundary<Tz, Z>(instant: &DateTime<Tz>, unit: CalendarUnit, tz: &Z) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    Z: TimeZone,
+{
+    let start = unit.truncate(instant.with_timezone(tz).naive_local())?;
+    let boundary = match tz.from_local_datetime(&start) {
+        LocalResult::Ambiguous(earliest, latest) if latest > *instant => earliest,
+        LocalResult::Ambiguous(_, latest) => latest,
+        _ => local_start(tz, start)?,
+    };
+    Some(boundary.with_timezone(&instant.timezone()))
+}
+
+// The first unit boundary in tz after instant, or at instant if inclusive
+fn ceil_boundary<Tz, Z>(
+    instant: &DateTime<Tz>,
+    unit: CalendarUnit,
+    tz: &Z,
+    inclusive: bool,
+) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    Z: TimeZone,
+{
+    if inclusive && floor_boundary(instant, unit, tz)? == *instant {
+        return Some(instant.clone());
+    }
+    let start = unit.step(unit.truncate(instant.with_timezone(tz).naive_local())?)?;
+    Some(local_start(tz, start)?.with_timezone(&instant.timezone()))
+}
+
+impl<Tz> Interval<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    DateTime<Tz>: Copy,
+{
+    /// Snap the bounds outward to calendar unit boundaries in timezone tz
+    ///
+    /// Yields the smallest RightHalfOpen interval of whole units containing
+    /// self, e.g. `[09:10..17:05)` expands to `[09:00..18:00)` in hours.
+    /// Boundaries are local wall clock times in tz, so that a day spanning a
+    /// DST transition expands to 23 or 25 hours.  Unbounded sides remain
+    /// unbounded, and the Empty interval yields Empty.  Returns None if a
+    /// boundary lies outside the representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::calendar::CalendarUnit;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let at = |h, m| Utc.with_ymd_and_hms(2024, 3, 3, h, m, 0).unwrap();
+    /// let meeting = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(at(9, 10), at(17, 5)).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     meeting.expand_to(CalendarUnit::Hour, &Utc),
+    ///     Some(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(at(9, 0), at(18, 0)).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expand_to<Z>(&self, unit: CalendarUnit, tz: &Z) -> Option<Interval<DateTime<Tz>>>
+    where
+        Z: TimeZone,
+    {
+        let left_bound = match self.left_bound() {
+            Bound::None => return Some(Interval::Empty),
+            Bound::Unbounded => Bound::Unbounded,
+            Bound::Closed(left) | Bound::Open(left) => {
+                Bound::Closed(floor_boundary(&left, unit, tz)?)
+            }
+        };
+        let right_bound = match self.right_bound() {
+            Bound::None => return Some(Interval::Empty),
+            Bound::Unbounded => Bound::Unbounded,
+            Bound::Closed(right) => Bound::Open(ceil_boundary(&right, unit, tz, false)?),
+            Bound::Open(right) => Bound::Open(ceil_boundary(&right, unit, tz, true)?),
+        };
+        Some(Interval::from_bounds(left_bound, right_bound))
+    }
+
+    /// Snap the bounds inward to calendar unit boundaries in timezone tz
+    ///
+    /// Yields the largest RightHalfOpen interval of whole units contained in
+    /// self, e.g. `[09:10..17:05)` rounds to `[10:00..17:00)` in hours, or
+    /// Empty if self contains no whole unit.  Boundaries are resolved as per
+    /// [Interval::expand_to].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::calendar::CalendarUnit;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let at = |h, m| Utc.with_ymd_and_hms(2024, 3, 3, h, m, 0).unwrap();
+    /// let meeting = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(at(9, 10), at(17, 5)).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     meeting.round_to(CalendarUnit::Hour, &Utc),
+    ///     Some(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(at(10, 0), at(17, 0)).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(meeting.round_to(CalendarUnit::Day, &Utc), Some(Interval::Empty));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn round_to<Z>(&self, unit: CalendarUnit, tz: &Z) -> Option<Interval<DateTime<Tz>>>
+    where
+        Z: TimeZone,
+    {
+        let left_bound = match self.left_bound() {
+            Bound::None => return Some(Interval::Empty),
+            Bound::Unbounded => Bound::Unbounded,
+            Bound::Closed(left) => Bound::Closed(ceil_boundary(&left, unit, tz, true)?),
+            Bound::Open(left) => Bound::Closed(ceil_boundary(&left, unit, tz, false)?),
+        };
+        let right_bound = match self.right_bound() {
+            Bound::None => return Some(Interval::Empty),
+            Bound::Unbounded => Bound::Unbounded,
+            Bound::Closed(right) | Bound::Open(right) => {
+                Bound::Open(floor_boundary(&right, unit, tz)?)
+            }
+        };
+        Some(Interval::from_bounds(left_bound, right_bound))
+    }
+}
+
+/// The layout produced by [Interval::humanize]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum HumanStyle {
+    /// The start and end times, e.g. `"Mar 3, 09:00–17:30"`
+    #[default]
+    Range,
+    /// The width and start time, e.g. `"2h window starting 14:00"`
+    Window,
+}
+
+/// The finest time unit shown by [Interval::humanize]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum TimePrecision {
+    /// Times as `09:00` and widths to the minute
+    #[default]
+    Minutes,
+    /// Times as `09:00:00` and widths to the second
+    Seconds,
+}
+
+/// Options for [Interval::humanize]
+///
+/// Precision and layout only; the output is not localized.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct HumanFormat {
+    /// The layout of the output
+    pub style: HumanStyle,
+    /// The finest time unit shown
+    pub precision: TimePrecision,
+}
+
+/// Display adapter formatting an Interval of instants for humans
+///
+/// Constructed by [Interval::humanize].
+#[derive(Debug, Clone)]
+pub struct Humanized<'a, Tz>
+where
+    Tz: TimeZone,
+{
+    interval: &'a Interval<DateTime<Tz>>,
+    format: HumanFormat,
+}
+
+impl<Tz> Humanized<'_, Tz>
+where
+    Tz: TimeZone,
+    Tz::Offset: std::fmt::Display,
+{
+    // The local time of day at the configured precision
+    fn time(&self, instant: &DateTime<Tz>) -> impl std::fmt::Display + '_ {
+        match self.format.precision {
+            TimePrecision::Minutes => instant.format("%H:%M"),
+            TimePrecision::Seconds => instant.format("%H:%M:%S"),
+        }
+    }
+
+    // The local date and time, with the year where the context needs it
+    fn date_time(&self, instant: &DateTime<Tz>, with_year: bool) -> String {
+        let date = if with_year { "%b %-d, %Y" } else { "%b %-d" };
+        format!("{}, {}", instant.format(date), self.time(instant))
+    }
+
+    // The width as e.g. "1d 2h 30m", omitting zero units
+    fn width(&self, width: Duration) -> String {
+        let seconds = width.num_seconds();
+        let mut units = vec![
+            (seconds / 86_400, "d"),
+            (seconds % 86_400 / 3_600, "h"),
+            (seconds % 3_600 / 60, "m"),
+        ];
+        let zero = match self.format.precision {
+            TimePrecision::Minutes => "0m",
+            TimePrecision::Seconds => {
+                units.push((seconds % 60, "s"));
+                "0s"
+            }
+        };
+        let parts: Vec<String> = units
+            .into_iter()
+            .filter(|(count, _)| *count != 0)
+            .map(|(count, unit)| format!("{}{}", count, unit))
+            .collect();
+        if parts.is_empty() {
+            zero.to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+impl<Tz> std::fmt::Display for Humanized<'_, Tz>
+where
+    Tz: TimeZone,
+    Tz::Offset: std::fmt::Display,
+    DateTime<Tz>: Copy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let endpoints = self.interval.finite_endpoints();
+        match (endpoints.left, endpoints.right) {
+            (Some((left, _)), Some((right, _))) if left == right => {
+                write!(f, "{}", self.date_time(&left, false))
+            }
+            (Some((left, _)), Some((right, _))) if self.format.style == HumanStyle::Window => {
+                let width = right - left;
+                write!(
+                    f,
+                    "{} window starting {}",
+                    self.width(width),
+                    self.time(&left)
+                )
+            }
+            (Some((left, _)), Some((right, _))) => {
+                if left.date_naive() == right.date_naive() {
+                    write!(f, "{}–{}", self.date_time(&left, false), self.time(&right))
+                } else {
+                    let with_year = left.year() != right.year();
+                    write!(
+                        f,
+                        "{} – {}",
+                        self.date_time(&left, with_year),
+                        self.date_time(&right, with_year)
+                    )
+                }
+            }
+            (Some((left, _)), None) => write!(f, "from {}", self.date_time(&left, false)),
+            (None, Some((right, _))) => write!(f, "until {}", self.date_time(&right, false)),
+            (None, None) if *self.interval == Interval::Unbounded => write!(f, "all time"),
+            (None, None) => write!(f, "never"),
+        }
+    }
+}
+
+impl<Tz> Interval<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    Tz::Offset: std::fmt::Display,
+    DateTime<Tz>: Copy,
+{
+    /// Format the interval for humans, e.g. in UIs and logs
+    ///
+    /// Times are shown in the timezone of the bounds.  An interval within a
+    /// single local day is shown as `"Mar 3, 09:00–17:30"`, one crossing
+    /// midnight as `"Mar 3, 22:00 – Mar 4, 02:00"`, and one crossing a year
+    /// boundary with the years included.  [HumanStyle::Window] instead shows
+    /// the width and start time, e.g. `"2h window starting 14:00"`.
+    /// Singletons show their instant, rays are shown as `"from ..."` or
+    /// `"until ..."`, the Unbounded interval as `"all time"` and the Empty
+    /// interval as `"never"`.  Bound openness is not shown.
+    ///
+    /// The output is English with 24 hour times, whatever the locale: month
+    /// names are the English abbreviations and there is no locale option.
+    /// Localized output should be built from [Interval::finite_endpoints]
+    /// with a localizing formatter (e.g. chrono's `format_localized`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::calendar::{HumanFormat, HumanStyle};
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let shift = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(
+    ///         Utc.with_ymd_and_hms(2024, 3, 3, 9, 0, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2024, 3, 3, 17, 30, 0).unwrap(),
+    ///     )
+    ///     .ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     shift.humanize(HumanFormat::default()).to_string(),
+    ///     "Mar 3, 09:00–17:30"
+    /// );
+    /// let window = HumanFormat {
+    ///     style: HumanStyle::Window,
+    ///     ..HumanFormat::default()
+    /// };
+    /// assert_eq!(
+    ///     shift.humanize(window).to_string(),
+    ///     "8h 30m window starting 09:00"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn humanize(&self, format: HumanFormat) -> Humanized<'_, Tz> {
+        Humanized {
+            interval: self,
+            format,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use chrono_tz::America::New_York;
+    use chrono_tz::America::Sao_Paulo;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_day_dst_lengths() {
+        let width = |d| Interval::calendar_day(d, &New_York).unwrap().width();
+        assert_eq!(width(date(2024, 3, 10)), Some(Duration::hours(23)));
+        assert_eq!(width(date(2024, 11, 3)), Some(Duration::hours(25)));
+        assert_eq!(width(date(2024, 6, 1)), Some(Duration::hours(24)));
+    }
+
+    #[test]
+    fn test_calendar_days_tile() {
+        let mut d = date(2024, 2, 25);
+        while d < date(2024, 4, 5) {
+            let today = Interval::calendar_day(d, &New_York).unwrap();
+            let tomorrow = Interval::calendar_day(d.succ_opt().unwrap(), &New_York).unwrap();
+            assert_eq!(today.intersect(&tomorrow), Interval::Empty);
+            match (today, tomorrow) {
+                (
+                    Interval::RightHalfOpen { bound_pair: a },
+                    Interval::RightHalfOpen { bound_pair: b },
+                ) => assert_eq!(a.right(), b.left()),
+                _ => panic!("calendar days must be RightHalfOpen"),
+            }
+            d = d.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_calendar_day_midnight_gap() {
+        // Sao Paulo clocks jumped from 00:00 to 01:00 on 2018-11-04
+        let day = Interval::calendar_day(date(2018, 11, 4), &Sao_Paulo).unwrap();
+        assert_eq!(day.width(), Some(Duration::hours(23)));
+        match day {
+            Interval::RightHalfOpen { bound_pair } => {
+                assert_eq!(
+                    bound_pair.left().naive_local(),
+                    date(2018, 11, 4).and_hms_opt(1, 0, 0).unwrap()
+                );
+            }
+            _ => panic!("calendar days must be RightHalfOpen"),
+        }
+    }
+
+    #[test]
+    fn test_calendar_month() {
+        assert_eq!(
+            Interval::calendar_month(2023, 12, &Utc).unwrap().width(),
+            Some(Duration::days(31))
+        );
+        assert_eq!(
+            Interval::calendar_month(2024, 3, &New_York)
+                .unwrap()
+                .width(),
+            Some(Duration::days(31) - Duration::hours(1))
+        );
+        assert!(Interval::calendar_month(2024, 13, &Utc).is_none());
+        assert!(Interval::calendar_month(2024, 0, &Utc).is_none());
+    }
+
+    #[test]
+    fn test_humanize_range() {
+        let at = |y, mo, d, h, mi| Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap();
+        let closed = |l, r| Interval::Closed {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
+        let human = |i: Interval<DateTime<Utc>>| i.humanize(HumanFormat::default()).to_string();
+
+        assert_eq!(
+            human(closed(at(2024, 3, 3, 22, 0), at(2024, 3, 4, 2, 0))),
+            "Mar 3, 22:00 – Mar 4, 02:00"
+        );
+        assert_eq!(
+            human(closed(at(2023, 12, 31, 22, 0), at(2024, 1, 1, 2, 0))),
+            "Dec 31, 2023, 22:00 – Jan 1, 2024, 02:00"
+        );
+        assert_eq!(
+            human(Interval::Singleton {
+                at: at(2024, 7, 14, 8, 5)
+            }),
+            "Jul 14, 08:05"
+        );
+        assert_eq!(
+            human(Interval::UnboundedClosedLeft {
+                left: at(2024, 7, 14, 8, 5)
+            }),
+            "from Jul 14, 08:05"
+        );
+        assert_eq!(
+            human(Interval::UnboundedOpenRight {
+                right: at(2024, 7, 14, 8, 5)
+            }),
+            "until Jul 14, 08:05"
+        );
+        assert_eq!(human(Interval::Unbounded), "all time");
+        assert_eq!(human(Interval::Empty), "never");
+    }
+
+    #[test]
+    fn test_humanize_local_timezone() {
+        // 03:30 UTC is still the previous evening in New York
+        let left = Utc.with_ymd_and_hms(2024, 6, 1, 1, 0, 0).unwrap();
+        let right = Utc.with_ymd_and_hms(2024, 6, 1, 3, 30, 0).unwrap();
+        let interval = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(
+                left.with_timezone(&New_York),
+                right.with_timezone(&New_York),
+            )
+            .unwrap(),
+        };
+        assert_eq!(
+            interval.humanize(HumanFormat::default()).to_string(),
+            "May 31, 21:00–23:30"
+        );
+    }
+
+    #[test]
+    fn test_humanize_window() {
+        let left = Utc.with_ymd_and_hms(2024, 3, 3, 14, 0, 0).unwrap();
+        let window = |seconds, precision| {
+            Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(left, left + Duration::seconds(seconds)).unwrap(),
+            }
+            .humanize(HumanFormat {
+                style: HumanStyle::Window,
+                precision,
+            })
+            .to_string()
+        };
+        assert_eq!(
+            window(7_200, TimePrecision::Minutes),
+            "2h window starting 14:00"
+        );
+        assert_eq!(
+            window(93_605, TimePrecision::Minutes),
+            "1d 2h window starting 14:00"
+        );
+        assert_eq!(
+            window(30, TimePrecision::Minutes),
+            "0m window starting 14:00"
+        );
+        assert_eq!(
+            window(3_630, TimePrecision::Seconds),
+            "1h 30s window starting 14:00:00"
+        );
+    }
+
+    #[test]
+    fn test_expand_to_dst() {
+        let local = |d, h, mi| {
+            New_York
+                .with_ymd_and_hms(2024, 3, d, h, mi, 0)
+                .single()
+                .unwrap()
+        };
+        // Clocks in New York jumped from 02:00 to 03:00 on 2024-03-10
+        let interval = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(local(10, 1, 30), local(10, 3, 30)).unwrap(),
+        };
+        let hours = interval.expand_to(CalendarUnit::Hour, &New_York).unwrap();
+        assert_eq!(
+            hours,
+            Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(local(10, 1, 0), local(10, 4, 0)).unwrap()
+            }
+        );
+        assert_eq!(hours.width(), Some(Duration::hours(2)));
+        let day = interval.expand_to(CalendarUnit::Day, &New_York).unwrap();
+        assert_eq!(
+            day,
+            Interval::calendar_day(date(2024, 3, 10), &New_York).unwrap()
+        );
+        assert_eq!(day.width(), Some(Duration::hours(23)));
+    }
+
+    #[test]
+    fn test_expand_to_repeated_hour() {
+        // Clocks in New York fell back from 02:00 to 01:00 on 2024-11-03, the
+        // second 01:30 is 06:30 UTC and its hour begins at 06:00 UTC
+        let second = Utc.with_ymd_and_hms(2024, 11, 3, 6, 30, 0).unwrap();
+        let interval = Interval::Singleton {
+            at: second.with_timezone(&New_York),
+        };
+        let hour = interval.expand_to(CalendarUnit::Hour, &New_York).unwrap();
+        assert_eq!(hour.width(), Some(Duration::hours(1)));
+        assert_eq!(
+            hour.finite_endpoints().left.map(|(left, _)| left),
+            Some(
+                Utc.with_ymd_and_hms(2024, 11, 3, 6, 0, 0)
+                    .unwrap()
+                    .with_timezone(&New_York)
+            )
+        );
+    }
+
+    #[test]
+    fn test_expand_to_boundaries() {
+        let at = |d, h| Utc.with_ymd_and_hms(2024, 5, d, h, 0, 0).unwrap();
+        let rho = |l, r| Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
+        // Whole units are unchanged, a closed right bound on a boundary is not
+        assert_eq!(
+            rho(at(1, 9), at(1, 17)).expand_to(CalendarUnit::Hour, &Utc),
+            Some(rho(at(1, 9), at(1, 17)))
+        );
+        assert_eq!(
+            Interval::Closed {
+                bound_pair: BoundPair::new(at(1, 9), at(1, 17)).unwrap()
+            }
+            .expand_to(CalendarUnit::Hour, &Utc),
+            Some(rho(at(1, 9), at(1, 18)))
+        );
+        // 2024-05-01 was a Wednesday
+        assert_eq!(
+            Interval::Singleton { at: at(1, 12) }.expand_to(CalendarUnit::Week, &Utc),
+            Some(rho(
+                Utc.with_ymd_and_hms(2024, 4, 29, 0, 0, 0).unwrap(),
+                at(6, 0)
+            ))
+        );
+        assert_eq!(
+            Interval::UnboundedOpenLeft { left: at(1, 12) }.expand_to(CalendarUnit::Day, &Utc),
+            Some(Interval::UnboundedClosedLeft { left: at(1, 0) })
+        );
+        assert_eq!(
+            Interval::<DateTime<Utc>>::Empty.expand_to(CalendarUnit::Day, &Utc),
+            Some(Interval::Empty)
+        );
+    }
+
+    #[test]
+    fn test_expand_to_other_timezone() {
+        // 03:30 UTC on June 1st is 23:30 on May 31st in New York
+        let utc = |d, h, mi| Utc.with_ymd_and_hms(2024, 6, d, h, mi, 0).unwrap();
+        let interval = Interval::Closed {
+            bound_pair: BoundPair::new(utc(1, 3, 30), utc(1, 5, 0)).unwrap(),
+        };
+        assert_eq!(
+            interval.expand_to(CalendarUnit::Day, &New_York),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(
+                    Utc.with_ymd_and_hms(2024, 5, 31, 4, 0, 0).unwrap(),
+                    utc(2, 4, 0)
+                )
+                .unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_to() {
+        let at = |h, mi, s| Utc.with_ymd_and_hms(2024, 5, 1, h, mi, s).unwrap();
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(at(9, 0, 0), at(12, 0, 0)).unwrap(),
+        };
+        assert_eq!(
+            open.round_to(CalendarUnit::Hour, &Utc),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(at(10, 0, 0), at(12, 0, 0)).unwrap()
+            })
+        );
+        assert_eq!(
+            open.round_to(CalendarUnit::Minute, &Utc),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(at(9, 1, 0), at(12, 0, 0)).unwrap()
+            })
+        );
+        assert_eq!(
+            Interval::Singleton { at: at(9, 0, 0) }.round_to(CalendarUnit::Minute, &Utc),
+            Some(Interval::Empty)
+        );
+        assert_eq!(
+            Interval::UnboundedClosedRight {
+                right: at(9, 30, 15)
+            }
+            .round_to(CalendarUnit::Minute, &Utc),
+            Some(Interval::UnboundedOpenRight {
+                right: at(9, 30, 0)
+            })
+        );
+    }
+}
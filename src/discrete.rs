@@ -0,0 +1,622 @@
+use crate::interval::{Bound, Interval};
+
+/// Bound data types whose values can be enumerated one step at a time
+///
+/// Integers form a discrete domain: between any two values there is a finite
+/// number of values, and every value (save the type extrema) has a unique
+/// successor and predecessor.  This enables iteration over the values
+/// contained in an Interval, and conversion between open and closed bounds.
+///
+/// Chars step over the surrogate code points, which are not chars.  Floats
+/// step between adjacent representable values (from negative to positive
+/// infinity, with -0.0 and 0.0 a single step), while NaN has neither a
+/// successor nor a predecessor.
+pub trait DiscreteDomain: Copy + PartialOrd {
+    /// The value immediately following self, or None at the domain maximum
+    fn successor(&self) -> Option<Self>;
+
+    /// The value immediately preceding self, or None at the domain minimum
+    fn predecessor(&self) -> Option<Self>;
+
+    /// The number of successor steps required to reach other from self
+    ///
+    /// Returns None if other is less than self, or if the step count does not
+    /// fit in a usize.
+    fn steps_to(&self, other: &Self) -> Option<usize>;
+}
+
+macro_rules! impl_discrete_domain_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl DiscreteDomain for $t {
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn steps_to(&self, other: &Self) -> Option<usize> {
+                    if other < self {
+                        None
+                    } else {
+                        usize::try_from(other.abs_diff(*self)).ok()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete_domain_for_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_discrete_domain_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl DiscreteDomain for $t {
+                fn successor(&self) -> Option<Self> {
+                    match *self {
+                        value if value.is_nan() || value == <$t>::INFINITY => None,
+                        value => Some(value.next_up()),
+                    }
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    match *self {
+                        value if value.is_nan() || value == <$t>::NEG_INFINITY => None,
+                        value => Some(value.next_down()),
+                    }
+                }
+
+                fn steps_to(&self, other: &Self) -> Option<usize> {
+                    if self.partial_cmp(other)? == std::cmp::Ordering::Greater {
+                        None
+                    } else {
+                        usize::try_from(crate::float::ulp_distance(*self, *other)?).ok()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete_domain_for_float!(f32, f64);
+
+// The code points reserved for UTF-16 surrogates, which are not chars
+const SURROGATES: std::ops::RangeInclusive<u32> = 0xD800..=0xDFFF;
+
+impl DiscreteDomain for char {
+    fn successor(&self) -> Option<Self> {
+        match *self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            value => char::from_u32(value as u32 + 1),
+        }
+    }
+
+    fn predecessor(&self) -> Option<Self> {
+        match *self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            value => char::from_u32((value as u32).checked_sub(1)?),
+        }
+    }
+
+    fn steps_to(&self, other: &Self) -> Option<usize> {
+        let (from, to) = (*self as u32, *other as u32);
+        let distance = to.checked_sub(from)?;
+        let skipped = if from < *SURROGATES.start() && to > *SURROGATES.end() {
+            SURROGATES.end() - SURROGATES.start() + 1
+        } else {
+            0
+        };
+        usize::try_from(distance - skipped).ok()
+    }
+}
+
+/// Iterator over the values contained in an Interval over a discrete domain
+///
+/// Constructed by [Interval::iter_values].  Values are yielded in ascending
+/// order from the front and descending order from the back, and the
+/// remaining count is always known exactly.
+#[derive(Debug, Clone)]
+pub struct Values<T> {
+    // The (front, back) values yet to be yielded, None once exhausted
+    ends: Option<(T, T)>,
+    remaining: usize,
+}
+
+impl<T> Iterator for Values<T>
+where
+    T: DiscreteDomain,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (front, back) = self.ends?;
+        self.remaining -= 1;
+        self.ends = match self.remaining {
+            0 => None,
+            _ => front.successor().map(|next| (next, back)),
+        };
+        Some(front)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Values<T>
+where
+    T: DiscreteDomain,
+{
+    fn next_back(&mut self) -> Option<T> {
+        let (front, back) = self.ends?;
+        self.remaining -= 1;
+        self.ends = match self.remaining {
+            0 => None,
+            _ => back.predecessor().map(|previous| (front, previous)),
+        };
+        Some(back)
+    }
+}
+
+impl<T> ExactSizeIterator for Values<T> where T: DiscreteDomain {}
+
+impl<T> std::iter::FusedIterator for Values<T> where T: DiscreteDomain {}
+
+impl<T> Interval<T>
+where
+    T: DiscreteDomain,
+{
+    /// Iterate over every value contained in the interval
+    ///
+    /// Open bounds are stepped inward to the nearest contained value.  The
+    /// returned iterator is double ended and exact size, so it composes with
+    /// e.g. `rev()` and `len()`.
+    ///
+    /// Returns None if the interval lacks a finite left or right bound, or if
+    /// the number of contained values does not fit in a usize.  The Empty
+    /// interval yields an empty iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::LeftHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// let values = interval.iter_values().ok_or("interval not iterable")?;
+    /// assert_eq!(values.len(), 4);
+    /// assert_eq!(values.rev().collect::<Vec<_>>(), vec![5, 4, 3, 2]);
+    /// assert!(Interval::UnboundedClosedLeft { left: 1 }.iter_values().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_values(&self) -> Option<Values<T>> {
+        let empty = Values {
+            ends: None,
+            remaining: 0,
+        };
+        // Step open bounds inward, an open bound at a domain extremum leaves no
+        // contained values
+        let first = match self.left_bound() {
+            Bound::None => return Some(empty),
+            Bound::Unbounded => return None,
+            Bound::Closed(left) => Some(left),
+            Bound::Open(left) => left.successor(),
+        };
+        let last = match self.right_bound() {
+            Bound::None => return Some(empty),
+            Bound::Unbounded => return None,
+            Bound::Closed(right) => Some(right),
+            Bound::Open(right) => right.predecessor(),
+        };
+
+        match (first, last) {
+            (Some(first), Some(last)) if first <= last => {
+                let remaining = first.steps_to(&last)?.checked_add(1)?;
+                Some(Values {
+                    ends: Some((first, last)),
+                    remaining,
+                })
+            }
+            _ => Some(empty),
+        }
+    }
+
+    /// Convert open bounds to closed bounds at the adjacent contained value
+    ///
+    /// An open left bound `(a` becomes `[a.successor()` and an open right
+    /// bound `b)` becomes `b.predecessor()]`, so that exactly the same values
+    /// remain contained, e.g. `(1..5)` becomes `[2..4]` and, for floats, an
+    /// open bound becomes closed at the adjacent representable value.  This
+    /// suits systems which only support closed bounds.  Intervals without a
+    /// contained value yield the Empty interval, and those with one yield a
+    /// Singleton.  Unbounded sides are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::Open {
+    ///     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     interval.tighten_open_bounds(),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(2, 4).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     Interval::UnboundedOpenLeft { left: 0.0 }.tighten_open_bounds(),
+    ///     Interval::UnboundedClosedLeft { left: f64::from_bits(1) }
+    /// );
+    /// assert_eq!(
+    ///     Interval::UnboundedOpenRight { right: 'b' }.tighten_open_bounds(),
+    ///     Interval::UnboundedClosedRight { right: 'a' }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tighten_open_bounds(&self) -> Interval<T> {
+        // An open bound at a domain extremum leaves no contained values
+        let left_bound = match self.left_bound() {
+            Bound::Open(left) => match left.successor() {
+                Some(left) => Bound::Closed(left),
+                None => return Interval::Empty,
+            },
+            bound => bound,
+        };
+        let right_bound = match self.right_bound() {
+            Bound::Open(right) => match right.predecessor() {
+                Some(right) => Bound::Closed(right),
+                None => return Interval::Empty,
+            },
+            bound => bound,
+        };
+        Interval::from_bounds(left_bound, right_bound)
+    }
+
+    /// Convert closed bounds to open bounds at the adjacent excluded value
+    ///
+    /// The reverse of [tighten_open_bounds](Interval::tighten_open_bounds): a
+    /// closed left bound `[a` becomes `(a.predecessor()` and a closed right
+    /// bound `b]` becomes `b.successor())`, preserving the contained values.
+    /// Closed bounds at a domain extremum (e.g. `u8::MAX` or float infinity)
+    /// have no adjacent value beyond them, and remain closed.  A Singleton
+    /// becomes the Open interval about its point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let loosened = Interval::Singleton { at: 1.0 }.loosen_closed_bounds();
+    /// assert_eq!(
+    ///     loosened,
+    ///     Interval::Open {
+    ///         bound_pair: BoundPair::new(1.0f64.next_down(), 1.0f64.next_up())
+    ///             .ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(loosened.tighten_open_bounds(), Interval::Singleton { at: 1.0 });
+    /// assert_eq!(
+    ///     Interval::UnboundedClosedLeft { left: 0u8 }.loosen_closed_bounds(),
+    ///     Interval::UnboundedClosedLeft { left: 0 }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loosen_closed_bounds(&self) -> Interval<T> {
+        let left_bound = match self.left_bound() {
+            Bound::Closed(left) => left.predecessor().map_or(Bound::Closed(left), Bound::Open),
+            bound => bound,
+        };
+        let right_bound = match self.right_bound() {
+            Bound::Closed(right) => right.successor().map_or(Bound::Closed(right), Bound::Open),
+            bound => bound,
+        };
+        Interval::from_bounds(left_bound, right_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound_pair::BoundPair;
+
+    #[test]
+    fn test_successor_predecessor() {
+        assert_eq!(1i32.successor(), Some(2));
+        assert_eq!(1i32.predecessor(), Some(0));
+        assert_eq!(u8::MAX.successor(), None);
+        assert_eq!(u8::MIN.predecessor(), None);
+        assert_eq!(i64::MIN.predecessor(), None);
+    }
+
+    #[test]
+    fn test_steps_to() {
+        assert_eq!(1u32.steps_to(&5), Some(4));
+        assert_eq!(5u32.steps_to(&5), Some(0));
+        assert_eq!(5u32.steps_to(&1), None);
+        assert_eq!((-3i8).steps_to(&3), Some(6));
+        assert_eq!(i8::MIN.steps_to(&i8::MAX), Some(255));
+        assert_eq!(i128::MIN.steps_to(&i128::MAX), None);
+    }
+
+    #[test]
+    fn test_iter_values_bounded() {
+        let bp = BoundPair::new(1, 4).unwrap();
+        let collect = |i: Interval<i32>| i.iter_values().unwrap().collect::<Vec<_>>();
+
+        assert_eq!(
+            collect(Interval::Closed { bound_pair: bp }),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(collect(Interval::Open { bound_pair: bp }), vec![2, 3]);
+        assert_eq!(
+            collect(Interval::LeftHalfOpen { bound_pair: bp }),
+            vec![2, 3, 4]
+        );
+        assert_eq!(
+            collect(Interval::RightHalfOpen { bound_pair: bp }),
+            vec![1, 2, 3]
+        );
+        assert_eq!(collect(Interval::Singleton { at: 7 }), vec![7]);
+        assert_eq!(collect(Interval::Empty), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_iter_values_open_without_interior() {
+        let i = Interval::Open {
+            bound_pair: BoundPair::new(1, 2).unwrap(),
+        };
+        assert_eq!(i.iter_values().unwrap().len(), 0);
+        assert_eq!(i.iter_values().unwrap().next(), None);
+    }
+
+    #[test]
+    fn test_iter_values_unbounded() {
+        assert!(Interval::<i32>::Unbounded.iter_values().is_none());
+        assert!(Interval::UnboundedOpenRight { right: 1 }
+            .iter_values()
+            .is_none());
+        assert!(Interval::UnboundedClosedLeft { left: 1 }
+            .iter_values()
+            .is_none());
+    }
+
+    #[test]
+    fn test_iter_values_domain_extrema() {
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(u8::MAX - 2, u8::MAX).unwrap(),
+        };
+        assert_eq!(
+            i.iter_values().unwrap().collect::<Vec<_>>(),
+            vec![253, 254, 255]
+        );
+
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(i8::MIN, i8::MAX).unwrap(),
+        };
+        assert_eq!(i.iter_values().unwrap().len(), 256);
+
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(u128::MIN, u128::MAX).unwrap(),
+        };
+        assert!(i.iter_values().is_none());
+    }
+
+    #[test]
+    fn test_iter_values_double_ended() {
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let mut values = i.iter_values().unwrap();
+        assert_eq!(values.next(), Some(1));
+        assert_eq!(values.next_back(), Some(5));
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.next_back(), Some(4));
+        assert_eq!(values.next(), Some(2));
+        assert_eq!(values.next(), Some(3));
+        assert_eq!(values.next(), None);
+        assert_eq!(values.next_back(), None);
+        assert_eq!(values.len(), 0);
+    }
+
+    #[test]
+    fn test_iter_values_zip() {
+        let i = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(10u64, 13u64).unwrap(),
+        };
+        let pairs: Vec<_> = i
+            .iter_values()
+            .unwrap()
+            .zip(i.iter_values().unwrap().rev())
+            .collect();
+        assert_eq!(pairs, vec![(10, 12), (11, 11), (12, 10)]);
+    }
+
+    #[test]
+    fn test_float_successor_predecessor() {
+        assert_eq!(1.0f64.successor(), Some(1.0f64.next_up()));
+        assert_eq!(1.0f32.predecessor(), Some(1.0f32.next_down()));
+        assert_eq!(f64::MAX.successor(), Some(f64::INFINITY));
+        assert_eq!(f64::INFINITY.successor(), None);
+        assert_eq!(f32::NEG_INFINITY.predecessor(), None);
+        assert_eq!(f64::NAN.successor(), None);
+        assert_eq!(f64::NAN.predecessor(), None);
+        assert_eq!((-0.0f64).successor(), Some(f64::from_bits(1)));
+    }
+
+    #[test]
+    fn test_float_steps_to() {
+        assert_eq!(1.0f64.steps_to(&1.0f64.next_up().next_up()), Some(2));
+        assert_eq!((-0.0f64).steps_to(&0.0), Some(0));
+        assert_eq!((-f32::from_bits(1)).steps_to(&f32::from_bits(1)), Some(2));
+        assert_eq!(1.0f64.steps_to(&0.5), None);
+        assert_eq!(f64::NAN.steps_to(&1.0), None);
+        assert_eq!(
+            f32::NEG_INFINITY.steps_to(&f32::INFINITY),
+            Some(2 * 0x7f80_0000)
+        );
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(1.0f32, 1.0f32.next_up().next_up()).unwrap(),
+        };
+        assert_eq!(i.iter_values().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_char_domain() {
+        assert_eq!('a'.successor(), Some('b'));
+        assert_eq!('\u{D7FF}'.successor(), Some('\u{E000}'));
+        assert_eq!('\u{E000}'.predecessor(), Some('\u{D7FF}'));
+        assert_eq!(char::MAX.successor(), None);
+        assert_eq!('\0'.predecessor(), None);
+        assert_eq!('a'.steps_to(&'z'), Some(25));
+        assert_eq!('\u{D7FF}'.steps_to(&'\u{E000}'), Some(1));
+        assert_eq!('\0'.steps_to(&char::MAX), Some(0x10FFFF - 0x800));
+        assert_eq!('z'.steps_to(&'a'), None);
+        let i = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new('\u{D7FE}', '\u{E001}').unwrap(),
+        };
+        assert_eq!(
+            i.iter_values().unwrap().collect::<Vec<_>>(),
+            vec!['\u{D7FE}', '\u{D7FF}', '\u{E000}']
+        );
+    }
+
+    #[test]
+    fn test_tighten_open_bounds() {
+        let bp = BoundPair::new(1, 4).unwrap();
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(2, 3).unwrap(),
+        };
+        assert_eq!(
+            Interval::Open { bound_pair: bp }.tighten_open_bounds(),
+            closed
+        );
+        assert_eq!(
+            Interval::Open {
+                bound_pair: BoundPair::new(1, 2).unwrap()
+            }
+            .tighten_open_bounds(),
+            Interval::Empty
+        );
+        assert_eq!(
+            Interval::UnboundedOpenLeft { left: u8::MAX }.tighten_open_bounds(),
+            Interval::Empty
+        );
+        assert_eq!(
+            Interval::UnboundedOpenRight { right: 5 }.tighten_open_bounds(),
+            Interval::UnboundedClosedRight { right: 4 }
+        );
+        for interval in crate::generators::all_variant_examples(1, 4).unwrap() {
+            let tightened = interval.tighten_open_bounds();
+            assert_eq!(tightened.tighten_open_bounds(), tightened);
+            assert_eq!(
+                tightened.iter_values().map(Iterator::collect::<Vec<_>>),
+                interval.iter_values().map(Iterator::collect::<Vec<_>>)
+            );
+        }
+    }
+
+    #[test]
+    fn test_loosen_closed_bounds() {
+        for interval in crate::generators::all_variant_examples(1, 4).unwrap() {
+            let loosened = interval.loosen_closed_bounds();
+            assert_eq!(
+                loosened.iter_values().map(Iterator::collect::<Vec<_>>),
+                interval.iter_values().map(Iterator::collect::<Vec<_>>)
+            );
+            assert_eq!(
+                loosened.tighten_open_bounds(),
+                interval.tighten_open_bounds()
+            );
+        }
+        let extremes = Interval::Closed {
+            bound_pair: BoundPair::new(i8::MIN, i8::MAX).unwrap(),
+        };
+        assert_eq!(extremes.loosen_closed_bounds(), extremes);
+    }
+
+    #[test]
+    fn test_tighten_open_bounds_float() {
+        let one_up = 1.0f64.next_up();
+        let open = |left, right| Interval::Open {
+            bound_pair: BoundPair::new(left, right).unwrap(),
+        };
+        assert_eq!(
+            open(1.0, 2.0).tighten_open_bounds(),
+            Interval::Closed {
+                bound_pair: BoundPair::new(one_up, 2.0f64.next_down()).unwrap()
+            }
+        );
+        assert_eq!(open(1.0, one_up).tighten_open_bounds(), Interval::Empty);
+        assert_eq!(
+            open(1.0, one_up.next_up()).tighten_open_bounds(),
+            Interval::Singleton { at: one_up }
+        );
+        assert_eq!(
+            Interval::UnboundedOpenRight {
+                right: f64::INFINITY
+            }
+            .tighten_open_bounds(),
+            Interval::UnboundedClosedRight { right: f64::MAX }
+        );
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(1.0, 2.0).unwrap(),
+        };
+        assert_eq!(closed.tighten_open_bounds(), closed);
+        assert_eq!(
+            Interval::<f64>::Empty.tighten_open_bounds(),
+            Interval::Empty
+        );
+        assert_eq!(
+            Interval::<f64>::Unbounded.tighten_open_bounds(),
+            Interval::Unbounded
+        );
+    }
+
+    #[test]
+    fn test_loosen_closed_bounds_float() {
+        let values = [-1.0, -0.0, 0.0, f64::from_bits(1), 0.5, 1.0, f64::MAX];
+        for left in values {
+            for right in values {
+                let Some(bound_pair) = BoundPair::new(left, right) else {
+                    continue;
+                };
+                for interval in [
+                    Interval::Closed { bound_pair },
+                    Interval::LeftHalfOpen { bound_pair },
+                    Interval::RightHalfOpen { bound_pair },
+                    Interval::UnboundedClosedLeft { left },
+                    Interval::Singleton { at: right },
+                ] {
+                    let loosened = interval.loosen_closed_bounds();
+                    assert!(!loosened.is_left_closed());
+                    let contained = |interval: Interval<f64>, value: f64| {
+                        interval.contains(&Interval::Singleton { at: value })
+                    };
+                    for value in values.iter().flat_map(|v| [v.next_down(), *v, v.next_up()]) {
+                        assert_eq!(contained(loosened, value), contained(interval, value));
+                    }
+                    assert_eq!(
+                        loosened.tighten_open_bounds(),
+                        interval.tighten_open_bounds()
+                    );
+                }
+            }
+        }
+        let infinite = Interval::Closed {
+            bound_pair: BoundPair::new(f64::NEG_INFINITY, f64::INFINITY).unwrap(),
+        };
+        assert_eq!(infinite.loosen_closed_bounds(), infinite);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::interval::{Bound, Interval, OneIntervalIter, TwoIntervalIter};
+use itertools::Either;
+
+/// Bound data types with a least and greatest representable value
+///
+/// Over fixed width domains (e.g. `u8`) the unbounded Interval variants
+/// describe values which cannot be represented.  This enables operations to
+/// instead saturate at the domain extrema, yielding finite Intervals.  Float
+/// domains are bounded by their greatest finite magnitude, not by infinity.
+pub trait DomainBounds: Copy + PartialOrd {
+    /// The least representable value
+    fn min_value() -> Self;
+
+    /// The greatest representable value
+    fn max_value() -> Self;
+}
+
+macro_rules! impl_domain_bounds {
+    ($($t:ty),*) => {
+        $(
+            impl DomainBounds for $t {
+                fn min_value() -> Self {
+                    <$t>::MIN
+                }
+
+                fn max_value() -> Self {
+                    <$t>::MAX
+                }
+            }
+        )*
+    };
+}
+
+impl_domain_bounds!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+// a + b, or None if the sum lies outside the domain (or is NaN)
+//
+// Compares against the domain extrema before adding, taking the Default
+// value as zero, so that no intermediate result overflows.
+pub(crate) fn checked_add<T>(a: T, b: T) -> Option<T>
+where
+    T: DomainBounds,
+    T: Default,
+    T: std::ops::Add<Output = T>,
+    T: std::ops::Sub<Output = T>,
+{
+    let fits = if b >= T::default() {
+        a <= T::max_value() - b
+    } else {
+        a >= T::min_value() - b
+    };
+    fits.then(|| a + b)
+}
+
+// a - b, or None if the difference lies outside the domain (or is NaN)
+pub(crate) fn checked_sub<T>(a: T, b: T) -> Option<T>
+where
+    T: DomainBounds,
+    T: Default,
+    T: std::ops::Add<Output = T>,
+    T: std::ops::Sub<Output = T>,
+{
+    let fits = if b >= T::default() {
+        a >= T::min_value() + b
+    } else {
+        a <= T::max_value() + b
+    };
+    fits.then(|| a - b)
+}
+
+impl<T> Interval<T>
+where
+    T: DomainBounds,
+{
+    /// The Closed interval spanning every representable value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// assert_eq!(
+    ///     Interval::<u8>::domain(),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(0, 255).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn domain() -> Interval<T> {
+        Interval::from_bounds(Bound::Closed(T::min_value()), Bound::Closed(T::max_value()))
+    }
+
+    /// Saturate unbounded sides at the domain extrema
+    ///
+    /// Yields the intersection of self with [Interval::domain], so that e.g.
+    /// `(←..x)` becomes `[MIN..x)`.  Intervals with finite bounds are
+    /// unchanged.
+    pub fn bounded_to_domain(&self) -> Interval<T> {
+        self.intersect(&Self::domain())
+    }
+
+    /// Take the complement of the Interval within the representable domain
+    ///
+    /// As [complement](Interval::complement), but with each resulting
+    /// Interval saturated at the domain extrema via
+    /// [bounded_to_domain](Interval::bounded_to_domain).  Pieces lying wholly
+    /// outside the domain are omitted: the complement of `[0..5]` over `u8`
+    /// is the single Interval `(5..255]`.  Where no representable value lies
+    /// outside self, the Empty interval is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let mut result_it = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(10u8, 20).ok_or("invalid BoundPair")?,
+    /// }
+    /// .complement_within_domain();
+    ///
+    /// assert_eq!(
+    ///     result_it.next(),
+    ///     Some(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(0, 10).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     result_it.next(),
+    ///     Some(Interval::Closed {
+    ///         bound_pair: BoundPair::new(20, 255).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(result_it.next(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn complement_within_domain(&self) -> Either<OneIntervalIter<T>, TwoIntervalIter<T>> {
+        let mut pieces = self
+            .complement()
+            .map(|piece| piece.bounded_to_domain())
+            .filter(|piece| *piece != Interval::Empty);
+        match (pieces.next(), pieces.next()) {
+            (Some(first), Some(second)) => {
+                Either::Right(std::iter::once(first).chain(std::iter::once(second)))
+            }
+            (Some(only), None) => Either::Left(std::iter::once(only)),
+            (None, _) => Either::Left(std::iter::once(Interval::Empty)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound_pair::BoundPair;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(checked_add(250u8, 5), Some(255));
+        assert_eq!(checked_add(250u8, 6), None);
+        assert_eq!(checked_sub(0u32, 1), None);
+        assert_eq!(checked_add(i8::MIN, -1), None);
+        assert_eq!(checked_add(i8::MIN, i8::MAX), Some(-1));
+        assert_eq!(checked_sub(i8::MAX, -1), None);
+        assert_eq!(checked_sub(-1i8, i8::MAX), Some(i8::MIN));
+        assert_eq!(checked_sub(i8::MIN, i8::MIN), Some(0));
+        assert_eq!(checked_add(f64::MAX, f64::MAX), None);
+        assert_eq!(checked_add(1.0, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_domain() {
+        assert_eq!(
+            Interval::<i8>::domain(),
+            Interval::Closed {
+                bound_pair: BoundPair::new(-128, 127).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::<f64>::domain(),
+            Interval::Closed {
+                bound_pair: BoundPair::new(f64::MIN, f64::MAX).unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_bounded_to_domain() {
+        assert_eq!(
+            Interval::UnboundedOpenRight { right: 5u8 }.bounded_to_domain(),
+            Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(0, 5).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::UnboundedOpenLeft { left: 5u8 }.bounded_to_domain(),
+            Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(5, 255).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::UnboundedOpenLeft { left: u8::MAX }.bounded_to_domain(),
+            Interval::Empty
+        );
+        assert_eq!(
+            Interval::<u8>::Unbounded.bounded_to_domain(),
+            Interval::<u8>::domain()
+        );
+        assert_eq!(Interval::<u8>::Empty.bounded_to_domain(), Interval::Empty);
+    }
+
+    #[test]
+    fn test_complement_within_domain() {
+        let collect = |i: Interval<u8>| i.complement_within_domain().collect::<Vec<_>>();
+        assert_eq!(
+            collect(Interval::Closed {
+                bound_pair: BoundPair::new(0, 5).unwrap()
+            }),
+            vec![Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(5, 255).unwrap()
+            }]
+        );
+        assert_eq!(
+            collect(Interval::Singleton { at: 7 }),
+            vec![
+                Interval::RightHalfOpen {
+                    bound_pair: BoundPair::new(0, 7).unwrap()
+                },
+                Interval::LeftHalfOpen {
+                    bound_pair: BoundPair::new(7, 255).unwrap()
+                }
+            ]
+        );
+        assert_eq!(
+            collect(Interval::UnboundedClosedRight { right: 9 }),
+            vec![Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(9, 255).unwrap()
+            }]
+        );
+        assert_eq!(collect(Interval::<u8>::domain()), vec![Interval::Empty]);
+        assert_eq!(collect(Interval::Unbounded), vec![Interval::Empty]);
+        assert_eq!(collect(Interval::Empty), vec![Interval::<u8>::domain()]);
+    }
+}
@@ -0,0 +1,68 @@
+/// Errors arising from BoundPair and Interval construction
+///
+/// Implements [core::error::Error] and [core::fmt::Display] without
+/// depending upon std, so errors may be propagated by embedded users and by
+/// users of error handling crates (e.g. anyhow) alike.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntervalError {
+    /// The left bound was greater than the right bound
+    ReversedBounds,
+    /// The left bound was equal to the right bound
+    EqualBounds,
+    /// The bounds could not be compared (e.g. a NaN bound)
+    IncomparableBounds,
+    /// The Interval was not of the kind required by the conversion
+    KindMismatch,
+    /// An encoded or textual Interval had an unknown kind or malformed bounds
+    MalformedEncoding,
+}
+
+impl core::fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            IntervalError::ReversedBounds => write!(f, "left bound is greater than right bound"),
+            IntervalError::EqualBounds => write!(f, "left bound is equal to right bound"),
+            IntervalError::IncomparableBounds => write!(f, "bounds are not comparable"),
+            IntervalError::KindMismatch => write!(f, "interval is not of the required kind"),
+            IntervalError::MalformedEncoding => write!(f, "interval encoding is malformed"),
+        }
+    }
+}
+
+impl core::error::Error for IntervalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", IntervalError::ReversedBounds),
+            "left bound is greater than right bound"
+        );
+        assert_eq!(
+            format!("{}", IntervalError::EqualBounds),
+            "left bound is equal to right bound"
+        );
+        assert_eq!(
+            format!("{}", IntervalError::IncomparableBounds),
+            "bounds are not comparable"
+        );
+        assert_eq!(
+            format!("{}", IntervalError::KindMismatch),
+            "interval is not of the required kind"
+        );
+        assert_eq!(
+            format!("{}", IntervalError::MalformedEncoding),
+            "interval encoding is malformed"
+        );
+    }
+
+    #[test]
+    fn test_boxed_error() {
+        let boxed: Box<dyn core::error::Error> = Box::new(IntervalError::ReversedBounds);
+        assert_eq!(boxed.to_string(), "left bound is greater than right bound");
+    }
+}
@@ -0,0 +1,379 @@
+//! Fixed-point decimal interpretation of integer Intervals
+//!
+//! Financial and metering systems commonly store decimal quantities as
+//! integers scaled by `10^decimals` (e.g. cents, with two decimals).  These
+//! helpers interpret an `Interval<i64>` of such scaled units: displaying the
+//! decimal values, measuring the width in real units, and converting to and
+//! from `Interval<f64>` with outward rounding so that the converted interval
+//! always encloses the original.
+//!
+//! Conversions support at most [MAX_DECIMALS] decimals, as `10^19` exceeds
+//! the i64 range.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::bound_pair::BoundPair;
+//! use intervals_general::interval::Interval;
+//! # fn main() -> std::result::Result<(), String> {
+//! // Prices in cents
+//! let bid_ask = Interval::Closed {
+//!     bound_pair: BoundPair::new(10_095, 10_110).ok_or("invalid BoundPair")?,
+//! };
+//! assert_eq!(bid_ask.display_scaled(2).to_string(), "[100.95..101.10]");
+//! assert_eq!(bid_ask.scaled_width(2), Some(0.15));
+//! // Outward rounding encloses the exact decimal values, here by one cent
+//! let dollars = bid_ask.to_f64_outward(2).ok_or("too many decimals")?;
+//! let cents = Interval::from_f64_outward(&dollars, 2).ok_or("out of range")?;
+//! assert_eq!(cents.display_scaled(2).to_string(), "[100.94..101.11]");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::interval::{Bound, Interval};
+
+/// The most decimals supported by the fixed-point conversions
+pub const MAX_DECIMALS: u32 = 18;
+
+// The largest magnitude exactly representable by an f64 integer
+const F64_EXACT_INTEGER: u64 = 1 << f64::MANTISSA_DIGITS;
+
+// A scaled integer bound, formatted as a decimal within Interval notation
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+struct Decimal {
+    units: i64,
+    decimals: u32,
+}
+
+impl std::fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let sign = if self.units < 0 { "-" } else { "" };
+        let digits = self.units.unsigned_abs().to_string();
+        let decimals = self.decimals as usize;
+        if decimals == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+        write!(f, "{}{}.{}", sign, whole, fraction)
+    }
+}
+
+/// Display adapter formatting an Interval of scaled integers as decimals
+///
+/// Constructed by [Interval::display_scaled].
+#[derive(Debug, Clone)]
+pub struct ScaledDisplay<'a> {
+    interval: &'a Interval<i64>,
+    decimals: u32,
+}
+
+impl std::fmt::Display for ScaledDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let decimal = |units| {
+            Some(Decimal {
+                units,
+                decimals: self.decimals,
+            })
+        };
+        match (
+            self.interval.left_bound().try_map(decimal),
+            self.interval.right_bound().try_map(decimal),
+        ) {
+            (Some(left), Some(right)) => write!(f, "{}", Interval::from_bounds(left, right)),
+            _ => write!(f, "{}", Interval::<Decimal>::Empty),
+        }
+    }
+}
+
+// 10^decimals as an f64, exact for every supported number of decimals
+fn scale(decimals: u32) -> Option<f64> {
+    if decimals > MAX_DECIMALS {
+        return None;
+    }
+    Some(10f64.powi(decimals as i32))
+}
+
+// units / scale as an f64, rounded down (when down) or up
+fn units_to_f64(units: i64, scale: f64, down: bool) -> f64 {
+    let quotient = units as f64 / scale;
+    if units.unsigned_abs() > F64_EXACT_INTEGER {
+        // units itself was rounded, for an error within two ulps in all
+        return if down {
+            quotient.next_down().next_down()
+        } else {
+            quotient.next_up().next_up()
+        };
+    }
+    // The sign of the rounding error, exact by way of the fused multiply-add
+    let error = quotient.mul_add(scale, -(units as f64));
+    if down && error > 0.0 {
+        quotient.next_down()
+    } else if !down && error < 0.0 {
+        quotient.next_up()
+    } else {
+        quotient
+    }
+}
+
+// value * scale as i64 units, rounded down (when down) or up
+fn f64_to_units(value: f64, scale: f64, down: bool) -> Option<i64> {
+    let product = value * scale;
+    let mut units = if down {
+        product.floor()
+    } else {
+        product.ceil()
+    };
+    // The sign of value * scale - units, exact by way of the fused multiply-add
+    let error = value.mul_add(scale, -units);
+    if down && error < 0.0 {
+        units -= 1.0;
+    } else if !down && error > 0.0 {
+        units += 1.0;
+    }
+    // i64::MAX is not representable, so its f64 rounding is excluded
+    if units >= i64::MIN as f64 && units < i64::MAX as f64 {
+        Some(units as i64)
+    } else {
+        None
+    }
+}
+
+impl Interval<i64> {
+    /// Format self as decimals, interpreting bounds as scaled by 10^decimals
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    ///
+    /// let floor = Interval::UnboundedClosedLeft { left: -5 };
+    /// assert_eq!(floor.display_scaled(3).to_string(), "[-0.005..→)");
+    /// assert_eq!(floor.display_scaled(0).to_string(), "[-5..→)");
+    /// ```
+    pub fn display_scaled(&self, decimals: u32) -> ScaledDisplay<'_> {
+        ScaledDisplay {
+            interval: self,
+            decimals,
+        }
+    }
+
+    /// The width in real units, interpreting bounds as scaled by 10^decimals
+    ///
+    /// None if the width is not finite (as [Interval::width]) or decimals
+    /// exceeds [MAX_DECIMALS].  The width is computed without overflow, but
+    /// rounds to the nearest f64.
+    pub fn scaled_width(&self, decimals: u32) -> Option<f64> {
+        let scale = scale(decimals)?;
+        let (left, right) = self.finite_bounds()?;
+        Some((i128::from(right) - i128::from(left)) as f64 / scale)
+    }
+
+    /// Convert to real units as f64, rounding the bounds outward
+    ///
+    /// The left bound rounds down and the right bound up, so the result
+    /// encloses every value of self.  None if decimals exceeds
+    /// [MAX_DECIMALS].
+    pub fn to_f64_outward(&self, decimals: u32) -> Option<Interval<f64>> {
+        let scale = scale(decimals)?;
+        Some(self.map_bounds_outward(
+            |units| units_to_f64(units, scale, true),
+            |units| units_to_f64(units, scale, false),
+        ))
+    }
+
+    /// Convert real units as f64 to scaled integers, rounding outward
+    ///
+    /// The left bound rounds down and the right bound up to whole units of
+    /// 10^-decimals, so the result encloses every value of interval.  None
+    /// if decimals exceeds [MAX_DECIMALS], or a bound is NaN or beyond the
+    /// i64 range when scaled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let dollars = Interval::Open {
+    ///     bound_pair: BoundPair::new(0.1, 0.205).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     Interval::from_f64_outward(&dollars, 2),
+    ///     Some(Interval::Open {
+    ///         bound_pair: BoundPair::new(10, 21).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_f64_outward(interval: &Interval<f64>, decimals: u32) -> Option<Interval<i64>> {
+        let scale = scale(decimals)?;
+        let left = match interval.left_bound() {
+            Bound::None => return Some(Interval::Empty),
+            bound => bound.try_map(|value| f64_to_units(value, scale, true))?,
+        };
+        let right = match interval.right_bound() {
+            Bound::None => return Some(Interval::Empty),
+            bound => bound.try_map(|value| f64_to_units(value, scale, false))?,
+        };
+        Some(Interval::from_bounds(left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound_pair::BoundPair;
+
+    #[test]
+    fn test_display_scaled() {
+        let interval = Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(-150, 7).unwrap(),
+        };
+        assert_eq!(interval.display_scaled(2).to_string(), "(-1.50..0.07]");
+        assert_eq!(interval.display_scaled(4).to_string(), "(-0.0150..0.0007]");
+        assert_eq!(
+            Interval::Singleton { at: i64::MIN }
+                .display_scaled(18)
+                .to_string(),
+            "[-9.223372036854775808]"
+        );
+        assert_eq!(
+            Interval::<i64>::Empty.display_scaled(2).to_string(),
+            "Empty"
+        );
+        assert_eq!(
+            Interval::<i64>::Unbounded.display_scaled(2).to_string(),
+            "(←..→)"
+        );
+    }
+
+    #[test]
+    fn test_scaled_width() {
+        let full = Interval::Closed {
+            bound_pair: BoundPair::new(i64::MIN, i64::MAX).unwrap(),
+        };
+        assert_eq!(full.scaled_width(0), Some(u64::MAX as f64));
+        assert_eq!(Interval::Singleton { at: 5 }.scaled_width(2), Some(0.0));
+        assert_eq!(
+            Interval::UnboundedOpenLeft { left: 5 }.scaled_width(2),
+            None
+        );
+        assert_eq!(full.scaled_width(MAX_DECIMALS + 1), None);
+    }
+
+    // Compare x with units / scale exactly, by way of x = mantissa * 2^exponent
+    fn cmp_exact(x: f64, units: i64, scale: i128) -> std::cmp::Ordering {
+        if x == 0.0 {
+            return 0.cmp(&units);
+        }
+        let bits = x.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = i128::from(bits & ((1 << 52) - 1));
+        let (mantissa, exponent) = if exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, exponent - 1075)
+        };
+        let mantissa = if x < 0.0 { -mantissa } else { mantissa };
+        if exponent >= 0 {
+            (mantissa << exponent)
+                .checked_mul(scale)
+                .unwrap()
+                .cmp(&i128::from(units))
+        } else {
+            let units = i128::from(units).checked_mul(1 << -exponent).unwrap();
+            mantissa.checked_mul(scale).unwrap().cmp(&units)
+        }
+    }
+
+    #[test]
+    fn test_to_f64_outward_encloses() {
+        let values = [
+            i64::MIN,
+            -(1 << 53) - 1,
+            -101,
+            -1,
+            0,
+            1,
+            3,
+            10_095,
+            (1 << 53) + 1,
+            i64::MAX,
+        ];
+        for decimals in [0, 1, 2, 7, MAX_DECIMALS] {
+            let scale = 10i128.pow(decimals);
+            for &left in &values {
+                for &right in &values {
+                    let Some(bound_pair) = BoundPair::new(left, right) else {
+                        continue;
+                    };
+                    let interval = Interval::Closed { bound_pair };
+                    let converted = interval.to_f64_outward(decimals).unwrap();
+                    let (low, high) = converted.finite_bounds().unwrap();
+                    assert!(cmp_exact(low, left, scale).is_le(), "{} {}", decimals, left);
+                    assert!(
+                        cmp_exact(high, right, scale).is_ge(),
+                        "{} {}",
+                        decimals,
+                        right
+                    );
+                }
+                let point = Interval::Singleton { at: left };
+                if let Some(back) =
+                    Interval::from_f64_outward(&point.to_f64_outward(decimals).unwrap(), decimals)
+                {
+                    assert!(back.contains(&point), "{} {}", decimals, left);
+                }
+            }
+        }
+        assert_eq!(
+            Interval::Singleton { at: 5 }.to_f64_outward(MAX_DECIMALS + 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_f64_outward() {
+        fn closed<T: Copy + PartialOrd>(left: T, right: T) -> Interval<T> {
+            Interval::Closed {
+                bound_pair: BoundPair::new(left, right).unwrap(),
+            }
+        }
+        // 0.1 and 0.7 are not exactly representable, and lie above and below
+        assert_eq!(
+            Interval::from_f64_outward(&closed(0.1, 0.7), 1),
+            Some(closed(1, 7))
+        );
+        assert_eq!(
+            Interval::from_f64_outward(&closed(0.105, 0.695), 2),
+            Some(closed(10, 70))
+        );
+        assert_eq!(
+            Interval::from_f64_outward(&closed(-0.5, 0.5), 0),
+            Some(closed(-1, 1))
+        );
+        assert_eq!(
+            Interval::from_f64_outward(&Interval::UnboundedOpenRight { right: 1.25 }, 1),
+            Some(Interval::UnboundedOpenRight { right: 13 })
+        );
+        assert_eq!(
+            Interval::from_f64_outward(&Interval::Singleton { at: 0.25 }, 1),
+            Some(closed(2, 3))
+        );
+        assert_eq!(
+            Interval::from_f64_outward(&Interval::Empty, 2),
+            Some(Interval::Empty)
+        );
+        assert_eq!(
+            Interval::from_f64_outward(&closed(0.0, f64::INFINITY), 2),
+            None
+        );
+        assert_eq!(Interval::from_f64_outward(&closed(0.0, 1e17), 2), None);
+        assert_eq!(
+            Interval::from_f64_outward(&Interval::Singleton { at: f64::NAN }, 2),
+            None
+        );
+    }
+}
@@ -0,0 +1,803 @@
+use crate::bound_pair::BoundPair;
+use crate::error::IntervalError;
+use crate::interval::{Bound, Interval, Openness};
+
+mod private {
+    pub trait Sealed {
+        // Position on a signed integer line on which consecutive floats are
+        // consecutive integers, with -0.0 and 0.0 sharing the position 0
+        fn ulp_position(self) -> i64;
+    }
+
+    macro_rules! impl_sealed {
+        ($($t:ty => $bits:ty),*) => {
+            $(
+                impl Sealed for $t {
+                    fn ulp_position(self) -> i64 {
+                        let bits = self.to_bits() as $bits;
+                        let position = if bits < 0 { <$bits>::MIN - bits } else { bits };
+                        i64::from(position)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_sealed!(f32 => i32, f64 => i64);
+}
+
+/// Floating point bound data types (f32 and f64)
+///
+/// Enables float specific Interval operations to be written once for both
+/// primitive float types.  This trait is sealed and cannot be implemented
+/// outside of intervals-general.
+pub trait FloatBound:
+    private::Sealed
+    + Copy
+    + PartialOrd
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self>
+    + std::ops::Neg<Output = Self>
+{
+    /// Positive infinity
+    const INFINITY: Self;
+
+    /// Negative infinity
+    const NEG_INFINITY: Self;
+
+    /// The absolute value of self
+    fn abs(self) -> Self;
+
+    /// The maximum of self and other, ignoring NaN
+    fn max(self, other: Self) -> Self;
+}
+
+macro_rules! impl_float_bound {
+    ($($t:ty),*) => {
+        $(
+            impl FloatBound for $t {
+                const INFINITY: Self = <$t>::INFINITY;
+                const NEG_INFINITY: Self = <$t>::NEG_INFINITY;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    <$t>::max(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_bound!(f32, f64);
+
+/// Treatment of infinite endpoints by [Interval::try_closed_floats]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InfinityPolicy {
+    /// Retain infinite endpoints as literal bound values
+    Keep,
+    /// Replace a `-inf` left or `+inf` right endpoint with an unbounded side
+    Promote,
+}
+
+// The number of representable values stepped from a to b, None for NaN
+//
+// Shared by the ULP tolerant operations and the DiscreteDomain float impls.
+pub(crate) fn ulp_distance<T>(a: T, b: T) -> Option<u64>
+where
+    T: FloatBound,
+{
+    a.partial_cmp(&b)?;
+    Some(a.ulp_position().abs_diff(b.ulp_position()))
+}
+
+// The Interval of the given openness between float endpoints, shared by
+// try_closed_floats and from_float_endpoints
+//
+// Promotion leaves no literal infinity in the result, so equal infinite
+// endpoints (an infinite Singleton) are rejected as EqualBounds.
+fn from_endpoints<T>(
+    left: T,
+    right: T,
+    openness: Openness,
+    infinities: InfinityPolicy,
+) -> Result<Interval<T>, IntervalError>
+where
+    T: FloatBound,
+{
+    let (left_bound, right_bound) = openness.bounds(left, right);
+    let promote = infinities == InfinityPolicy::Promote;
+    match BoundPair::try_new(left, right) {
+        Ok(_) => {}
+        Err(IntervalError::EqualBounds)
+            if promote && (left == T::INFINITY || left == T::NEG_INFINITY) =>
+        {
+            return Err(IntervalError::EqualBounds)
+        }
+        Err(IntervalError::EqualBounds) => {
+            return Ok(Interval::from_bounds(left_bound, right_bound))
+        }
+        Err(error) => return Err(error),
+    }
+    let left_bound = if promote && left == T::NEG_INFINITY {
+        Bound::Unbounded
+    } else {
+        left_bound
+    };
+    let right_bound = if promote && right == T::INFINITY {
+        Bound::Unbounded
+    } else {
+        right_bound
+    };
+    Ok(Interval::from_bounds(left_bound, right_bound))
+}
+
+impl<T> Interval<T>
+where
+    T: FloatBound,
+{
+    /// Construct the closed interval within a tolerance of value
+    ///
+    /// The tolerance is `max(rel_tol * |value|, abs_tol)`, yielding the
+    /// interval `[value - tolerance, value + tolerance]`, in the manner of
+    /// approximate float equality checks.  A zero tolerance yields the
+    /// Singleton at value, while a negative tolerance or NaN value yields the
+    /// Empty interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// assert_eq!(
+    ///     Interval::around(100.0, 0.1, 1.0),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(90.0, 110.0).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     Interval::around(0.0, 0.1, 1.0),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(-1.0, 1.0).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn around(value: T, rel_tol: T, abs_tol: T) -> Interval<T> {
+        let tolerance = (rel_tol * value.abs()).max(abs_tol);
+        let (left, right) = (value - tolerance, value + tolerance);
+        match BoundPair::new(left, right) {
+            Some(bound_pair) => Interval::Closed { bound_pair },
+            None if left == right => Interval::Singleton { at: value },
+            None => Interval::Empty,
+        }
+    }
+
+    /// Construct the Closed interval from float endpoints, reporting failures
+    ///
+    /// Rejects a NaN endpoint with [IntervalError::IncomparableBounds] and
+    /// left > right with [IntervalError::ReversedBounds], so that e.g.
+    /// telemetry ingestion can report why a sample was discarded.  Equal
+    /// endpoints yield the Singleton interval.  Under
+    /// [InfinityPolicy::Promote], a `-inf` left endpoint or `+inf` right
+    /// endpoint yields the corresponding unbounded variant rather than a
+    /// literal infinite bound, and equal infinite endpoints are rejected with
+    /// [IntervalError::EqualBounds].  This is
+    /// [from_float_endpoints](Interval::from_float_endpoints) with Closed
+    /// openness, bar the choice of policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::error::IntervalError;
+    /// use intervals_general::float::InfinityPolicy;
+    /// use intervals_general::interval::Interval;
+    ///
+    /// assert_eq!(
+    ///     Interval::try_closed_floats(f64::NEG_INFINITY, 2.0, InfinityPolicy::Promote),
+    ///     Ok(Interval::UnboundedClosedRight { right: 2.0 })
+    /// );
+    /// assert_eq!(
+    ///     Interval::try_closed_floats(f64::NAN, 2.0, InfinityPolicy::Keep),
+    ///     Err(IntervalError::IncomparableBounds)
+    /// );
+    /// assert_eq!(
+    ///     Interval::try_closed_floats(3.0, 2.0, InfinityPolicy::Keep),
+    ///     Err(IntervalError::ReversedBounds)
+    /// );
+    /// ```
+    pub fn try_closed_floats(
+        left: T,
+        right: T,
+        infinities: InfinityPolicy,
+    ) -> Result<Interval<T>, IntervalError> {
+        from_endpoints(left, right, Openness::Closed, infinities)
+    }
+
+    /// Construct an Interval from float endpoints, promoting infinities
+    ///
+    /// A `-inf` left endpoint or `+inf` right endpoint yields the
+    /// corresponding unbounded variant, so that e.g. `[-inf..3.0]` and
+    /// `(←..3.0]` share the single representation UnboundedClosedRight.
+    /// Finite endpoints take the given openness, with equal endpoints
+    /// yielding the Singleton (Closed) or Empty (otherwise) interval.
+    ///
+    /// Failures are reported as by
+    /// [try_closed_floats](Interval::try_closed_floats): a NaN endpoint with
+    /// [IntervalError::IncomparableBounds], left > right with
+    /// [IntervalError::ReversedBounds], and equal infinite endpoints, which
+    /// would otherwise leave an infinite Singleton, with
+    /// [IntervalError::EqualBounds].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::error::IntervalError;
+    /// use intervals_general::interval::{Interval, Openness};
+    /// # fn main() -> std::result::Result<(), IntervalError> {
+    /// assert_eq!(
+    ///     Interval::from_float_endpoints(f64::NEG_INFINITY, 3.0, Openness::Closed)?,
+    ///     Interval::UnboundedClosedRight { right: 3.0 }
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_float_endpoints(1.0, 3.0, Openness::RightHalfOpen)?,
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::try_new(1.0, 3.0)?
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_float_endpoints(f64::NEG_INFINITY, f64::INFINITY, Openness::Open)?,
+    ///     Interval::Unbounded
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_float_endpoints(f64::INFINITY, f64::INFINITY, Openness::Closed),
+    ///     Err(IntervalError::EqualBounds)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_float_endpoints(
+        left: T,
+        right: T,
+        openness: Openness,
+    ) -> Result<Interval<T>, IntervalError> {
+        from_endpoints(left, right, openness, InfinityPolicy::Promote)
+    }
+
+    /// The sub-interval between two fractions of the width
+    ///
+    /// Yields the sub-interval from `left + p_lo * width` to
+    /// `left + p_hi * width`, e.g. `quantile_subinterval(0.25, 0.75)` for the
+    /// middle 50%.  Interior cut points are closed, while a fraction of 0 or 1
+    /// retains the corresponding bound of self (openness included), so that
+    /// adjacent slices share their cut points.
+    ///
+    /// Returns None unless `0 <= p_lo <= p_hi <= 1` and self has finite
+    /// bounds.  The Empty interval yields Empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let window = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(0.0, 60.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     window.quantile_subinterval(0.25, 0.75),
+    ///     Some(Interval::Closed {
+    ///         bound_pair: BoundPair::new(15.0, 45.0).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     window.quantile_subinterval(0.9, 1.0),
+    ///     Some(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(54.0, 60.0).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(window.quantile_subinterval(0.75, 0.25), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn quantile_subinterval(&self, p_lo: T, p_hi: T) -> Option<Interval<T>>
+    where
+        T: From<u8>,
+    {
+        let (zero, one) = (T::from(0), T::from(1));
+        let valid = zero <= p_lo && p_lo <= p_hi && p_hi <= one;
+        if !valid {
+            return None;
+        }
+        if *self == Interval::Empty {
+            return Some(Interval::Empty);
+        }
+        let (left, right) = self.finite_bounds()?;
+        let width = right - left;
+        let left_bound = if p_lo == zero {
+            self.left_bound()
+        } else {
+            Bound::Closed(left + p_lo * width)
+        };
+        let right_bound = if p_hi == one {
+            self.right_bound()
+        } else {
+            Bound::Closed(left + p_hi * width)
+        };
+        Some(Interval::from_bounds(left_bound, right_bound))
+    }
+}
+
+impl Interval<f64> {
+    /// Verify whether self and other meet end to start within max_ulps
+    ///
+    /// Relaxes [touches](Interval::touches) to bound values at most max_ulps
+    /// representable doubles apart, whichever way round, so that hairline
+    /// gaps and overlaps left by rounding both count as meeting.  As with
+    /// touches, at least one of the two meeting bounds must be closed.
+    /// Unlike an absolute epsilon, the tolerance scales with the magnitude of
+    /// the bounds, remaining meaningful near zero.  A max_ulps of 0 is
+    /// equivalent to touches (with -0.0 meeting 0.0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let first = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(0.0, 0.1 + 0.2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let second = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(0.3, 1.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert!(!first.touches(&second));
+    /// assert!(first.abuts_ulps(&second, 1));
+    /// assert!(!first.abuts_ulps(&Interval::Singleton { at: 0.31 }, 1000));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn abuts_ulps(&self, other: &Interval<f64>, max_ulps: u64) -> bool {
+        // Whether right_bound and left_bound lie within max_ulps, with at
+        // least one of them closed
+        let meet = |right_bound, left_bound| match (right_bound, left_bound) {
+            (Bound::Open(_), Bound::Open(_)) => false,
+            (
+                Bound::Closed(right) | Bound::Open(right),
+                Bound::Closed(left) | Bound::Open(left),
+            ) => ulp_distance(right, left).is_some_and(|distance| distance <= max_ulps),
+            _ => false,
+        };
+        meet(self.right_bound(), other.left_bound()) || meet(other.right_bound(), self.left_bound())
+    }
+
+    /// Merge self and other if connected, or meeting within max_ulps
+    ///
+    /// Yields the [merge](Interval::merge) of intervals which overlap or
+    /// abut exactly, else their [span](Interval::span) if they
+    /// [abut within max_ulps](Interval::abuts_ulps), bridging hairline gaps
+    /// left by rounding.  Returns None for intervals lying further apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let first = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, 0.3).ok_or("invalid BoundPair")?,
+    /// };
+    /// let second = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.1 + 0.2, 1.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(first.merge(&second), None);
+    /// assert_eq!(
+    ///     first.merge_ulps(&second, 4),
+    ///     Some(Interval::Closed {
+    ///         bound_pair: BoundPair::new(0.0, 1.0).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge_ulps(&self, other: &Interval<f64>, max_ulps: u64) -> Option<Interval<f64>> {
+        match self.merge(other) {
+            Some(merged) => Some(merged),
+            None if self.abuts_ulps(other, max_ulps) => Some(self.span(other)),
+            None => None,
+        }
+    }
+
+    /// Narrow to f32 bounds while still enclosing the original interval
+    ///
+    /// The left bound is rounded down and the right bound rounded up to the
+    /// nearest representable f32, whereas a plain `as f32` cast rounds to
+    /// nearest and may exclude values at the edges.  Bounds beyond the f32
+    /// range round outward to infinity (or inward to the f32 extremum when
+    /// that still encloses the original bound).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let tenth = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.1, 0.2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let narrowed = tenth.to_f32_outer();
+    /// let widened = narrowed.map_bounds_outward(f64::from, f64::from);
+    /// assert!(widened.contains(&tenth));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_f32_outer(&self) -> Interval<f32> {
+        self.map_bounds_outward(
+            |value| {
+                let narrowed = value as f32;
+                if f64::from(narrowed) > value {
+                    narrowed.next_down()
+                } else {
+                    narrowed
+                }
+            },
+            |value| {
+                let narrowed = value as f32;
+                if f64::from(narrowed) < value {
+                    narrowed.next_up()
+                } else {
+                    narrowed
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_f32_outer_encloses() {
+        let values = [0.1, 0.2, 1.0 / 3.0, -2.0 / 3.0, 1e-40, 16_777_217.0];
+        for &left in &values {
+            for &right in &values {
+                if let Some(bound_pair) = BoundPair::new(left, right) {
+                    let original = Interval::Open { bound_pair };
+                    let widened = original
+                        .to_f32_outer()
+                        .map_bounds_outward(f64::from, f64::from);
+                    assert!(widened.contains(&original), "{} !⊇ {}", widened, original);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_f32_outer_exact() {
+        let i = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(0.5, 2.0).unwrap(),
+        };
+        assert_eq!(
+            i.to_f32_outer(),
+            Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(0.5f32, 2.0).unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_f32_outer_singleton_widens() {
+        let narrowed = Interval::Singleton { at: 0.1 }.to_f32_outer();
+        match narrowed {
+            Interval::Closed { bound_pair } => {
+                assert!(f64::from(*bound_pair.left()) < 0.1);
+                assert!(f64::from(*bound_pair.right()) > 0.1);
+                assert_eq!(bound_pair.left().next_up(), *bound_pair.right());
+            }
+            other => panic!("unexpected {}", other),
+        }
+        assert_eq!(
+            Interval::Singleton { at: 0.5 }.to_f32_outer(),
+            Interval::Singleton { at: 0.5f32 }
+        );
+    }
+
+    #[test]
+    fn test_to_f32_outer_out_of_range() {
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 1e300 }.to_f32_outer(),
+            Interval::UnboundedClosedLeft { left: f32::MAX }
+        );
+        assert_eq!(
+            Interval::UnboundedClosedRight { right: 1e300 }.to_f32_outer(),
+            Interval::UnboundedClosedRight {
+                right: f32::INFINITY
+            }
+        );
+        assert_eq!(
+            Interval::<f64>::Unbounded.to_f32_outer(),
+            Interval::Unbounded
+        );
+    }
+
+    #[test]
+    fn test_ulp_distance() {
+        assert_eq!(ulp_distance(1.0, 1.0), Some(0));
+        assert_eq!(ulp_distance(1.0, 1.0f64.next_up()), Some(1));
+        assert_eq!(ulp_distance(1.0f64.next_down(), 1.0f64.next_up()), Some(2));
+        assert_eq!(ulp_distance(-0.0, 0.0), Some(0));
+        assert_eq!(
+            ulp_distance(-f64::MIN_POSITIVE, f64::MIN_POSITIVE),
+            Some(2 << 52)
+        );
+        assert_eq!(ulp_distance(f64::from_bits(1), -f64::from_bits(1)), Some(2));
+        assert_eq!(ulp_distance(f64::MAX, f64::INFINITY), Some(1));
+        assert_eq!(
+            ulp_distance(f64::NEG_INFINITY, f64::INFINITY),
+            Some(2 * 0x7ff0_0000_0000_0000)
+        );
+        assert_eq!(ulp_distance(f64::NAN, 0.0), None);
+        assert_eq!(ulp_distance(-0.0f32, f32::from_bits(2)), Some(2));
+        assert_eq!(
+            ulp_distance(f32::NEG_INFINITY, f32::INFINITY),
+            Some(2 * 0x7f80_0000)
+        );
+    }
+
+    #[test]
+    fn test_abuts_ulps() {
+        let below = Interval::UnboundedOpenRight { right: 0.0 };
+        let tiny = f64::from_bits(3);
+        let above = Interval::UnboundedClosedLeft { left: tiny };
+        assert!(!below.abuts_ulps(&above, 2));
+        assert!(below.abuts_ulps(&above, 3));
+        assert!(above.abuts_ulps(&below, 3));
+        assert!(below.abuts_ulps(&Interval::Singleton { at: -0.0 }, 0));
+        let overlapping = Interval::Closed {
+            bound_pair: BoundPair::new(-1.0, 1.0f64.next_up()).unwrap(),
+        };
+        let next = Interval::Closed {
+            bound_pair: BoundPair::new(1.0, 2.0).unwrap(),
+        };
+        assert!(overlapping.abuts_ulps(&next, 1));
+        assert!(!overlapping.abuts_ulps(&next, 0));
+        let open_below = Interval::UnboundedOpenRight { right: 1.0 };
+        let open_above = Interval::UnboundedOpenLeft { left: 1.0 };
+        assert!(!open_below.abuts_ulps(&open_above, 0));
+        assert!(!open_below.abuts_ulps(&open_above, 4));
+        assert_eq!(
+            open_below.abuts_ulps(&open_above, 0),
+            open_below.touches(&open_above)
+        );
+        assert!(!Interval::<f64>::Unbounded.abuts_ulps(&next, u64::MAX));
+        assert!(!Interval::<f64>::Empty.abuts_ulps(&next, u64::MAX));
+        assert!(!Interval::Singleton { at: f64::NAN }.abuts_ulps(&next, u64::MAX));
+    }
+
+    #[test]
+    fn test_merge_ulps() {
+        let first = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(0.0, 1.0).unwrap(),
+        };
+        let second = Interval::Open {
+            bound_pair: BoundPair::new(1.0, 2.0).unwrap(),
+        };
+        assert_eq!(first.merge(&second), None);
+        assert_eq!(first.merge_ulps(&second, 0), None);
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(1.0f64.next_up(), 2.0).unwrap(),
+        };
+        assert_eq!(
+            first.merge_ulps(&closed, 1),
+            Some(Interval::Closed {
+                bound_pair: BoundPair::new(0.0, 2.0).unwrap()
+            })
+        );
+        let later = Interval::Closed {
+            bound_pair: BoundPair::new(1.0f64.next_up().next_up(), 2.0).unwrap(),
+        };
+        assert_eq!(first.merge_ulps(&later, 1), None);
+        assert_eq!(
+            first.merge_ulps(&later, 2),
+            Some(Interval::Closed {
+                bound_pair: BoundPair::new(0.0, 2.0).unwrap()
+            })
+        );
+        let connected = Interval::Closed {
+            bound_pair: BoundPair::new(0.5, 1.5).unwrap(),
+        };
+        assert_eq!(first.merge_ulps(&connected, 0), first.merge(&connected));
+    }
+
+    #[test]
+    fn test_around_relative_dominates() {
+        assert_eq!(
+            Interval::around(-200.0, 0.5, 1.0),
+            Interval::Closed {
+                bound_pair: BoundPair::new(-300.0, -100.0).unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_around_absolute_dominates() {
+        assert_eq!(
+            Interval::around(1.0f32, 0.01, 0.5),
+            Interval::Closed {
+                bound_pair: BoundPair::new(0.5, 1.5).unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_around_degenerate() {
+        assert_eq!(
+            Interval::around(3.0, 0.0, 0.0),
+            Interval::Singleton { at: 3.0 }
+        );
+        assert_eq!(Interval::around(3.0, -1.0, -1.0), Interval::Empty);
+        assert_eq!(Interval::around(f64::NAN, 0.1, 1.0), Interval::Empty);
+    }
+
+    #[test]
+    fn test_around_contains_value() {
+        let i = Interval::around(1e9, 1e-9, 0.0);
+        assert!(i.contains(&Interval::Singleton { at: 1e9 }));
+        assert!(!i.contains(&Interval::Singleton { at: 1e9 + 2.0 }));
+    }
+
+    #[test]
+    fn test_quantile_subinterval() {
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(10.0, 20.0).unwrap(),
+        };
+        assert_eq!(open.quantile_subinterval(0.0, 1.0), Some(open));
+        assert_eq!(
+            open.quantile_subinterval(0.0, 0.5),
+            Some(Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(10.0, 15.0).unwrap()
+            })
+        );
+        assert_eq!(
+            open.quantile_subinterval(0.5, 0.5),
+            Some(Interval::Singleton { at: 15.0 })
+        );
+        assert_eq!(open.quantile_subinterval(1.0, 1.0), Some(Interval::Empty));
+        assert_eq!(
+            Interval::Singleton { at: 2.0f32 }.quantile_subinterval(0.1, 0.9),
+            Some(Interval::Singleton { at: 2.0 })
+        );
+    }
+
+    #[test]
+    fn test_quantile_subinterval_invalid() {
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(0.0, 1.0).unwrap(),
+        };
+        assert_eq!(i.quantile_subinterval(-0.1, 0.5), None);
+        assert_eq!(i.quantile_subinterval(0.5, 1.1), None);
+        assert_eq!(i.quantile_subinterval(f64::NAN, 0.5), None);
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 0.0 }.quantile_subinterval(0.0, 0.5),
+            None
+        );
+        assert_eq!(
+            Interval::<f64>::Empty.quantile_subinterval(0.0, 0.5),
+            Some(Interval::Empty)
+        );
+    }
+
+    #[test]
+    fn test_try_closed_floats() {
+        let keep = InfinityPolicy::Keep;
+        let promote = InfinityPolicy::Promote;
+        assert_eq!(
+            Interval::try_closed_floats(1.0, 2.0, promote),
+            Ok(Interval::Closed {
+                bound_pair: BoundPair::new(1.0, 2.0).unwrap()
+            })
+        );
+        assert_eq!(
+            Interval::try_closed_floats(1.0f32, 1.0, keep),
+            Ok(Interval::Singleton { at: 1.0 })
+        );
+        assert_eq!(
+            Interval::try_closed_floats(1.0, f64::NAN, promote),
+            Err(IntervalError::IncomparableBounds)
+        );
+        assert_eq!(
+            Interval::try_closed_floats(2.0, 1.0, promote),
+            Err(IntervalError::ReversedBounds)
+        );
+    }
+
+    #[test]
+    fn test_try_closed_floats_infinities() {
+        let (neg_inf, inf) = (f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(
+            Interval::try_closed_floats(neg_inf, inf, InfinityPolicy::Keep),
+            Ok(Interval::Closed {
+                bound_pair: BoundPair::new(neg_inf, inf).unwrap()
+            })
+        );
+        assert_eq!(
+            Interval::try_closed_floats(neg_inf, inf, InfinityPolicy::Promote),
+            Ok(Interval::Unbounded)
+        );
+        assert_eq!(
+            Interval::try_closed_floats(0.0, inf, InfinityPolicy::Promote),
+            Ok(Interval::UnboundedClosedLeft { left: 0.0 })
+        );
+        assert_eq!(
+            Interval::try_closed_floats(inf, inf, InfinityPolicy::Promote),
+            Err(IntervalError::EqualBounds)
+        );
+        assert_eq!(
+            Interval::try_closed_floats(neg_inf, neg_inf, InfinityPolicy::Promote),
+            Err(IntervalError::EqualBounds)
+        );
+        assert_eq!(
+            Interval::try_closed_floats(inf, inf, InfinityPolicy::Keep),
+            Ok(Interval::Singleton { at: inf })
+        );
+    }
+
+    #[test]
+    fn test_from_float_endpoints() {
+        let (neg_inf, inf) = (f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(
+            Interval::from_float_endpoints(neg_inf, 0.0, Openness::Open),
+            Ok(Interval::UnboundedOpenRight { right: 0.0 })
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(0.0, inf, Openness::LeftHalfOpen),
+            Ok(Interval::UnboundedOpenLeft { left: 0.0 })
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(0.0, inf, Openness::RightHalfOpen),
+            Ok(Interval::UnboundedClosedLeft { left: 0.0 })
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(-1.0, 1.0, Openness::LeftHalfOpen),
+            Ok(Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(-1.0, 1.0).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_float_endpoints_degenerate() {
+        assert_eq!(
+            Interval::from_float_endpoints(2.0, 2.0, Openness::Closed),
+            Ok(Interval::Singleton { at: 2.0 })
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(2.0, 2.0, Openness::RightHalfOpen),
+            Ok(Interval::Empty)
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(3.0, 2.0, Openness::Closed),
+            Err(IntervalError::ReversedBounds)
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(f64::NAN, f64::INFINITY, Openness::Open),
+            Err(IntervalError::IncomparableBounds)
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(f32::INFINITY, f32::INFINITY, Openness::Closed),
+            Err(IntervalError::EqualBounds)
+        );
+        assert_eq!(
+            Interval::from_float_endpoints(f32::NEG_INFINITY, 0.0, Openness::Closed),
+            Ok(Interval::UnboundedClosedRight { right: 0.0 })
+        );
+    }
+}
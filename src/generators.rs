@@ -0,0 +1,130 @@
+//! Framework agnostic Interval test-data generators
+//!
+//! Table driven tests over Intervals commonly want one example of every
+//! Interval variant, or every pairing of variants.  The functions here
+//! produce those exhaustively (and stay exhaustive as variants are added),
+//! without depending upon any particular property testing framework.
+
+use crate::bound_pair::BoundPair;
+use crate::interval::Interval;
+
+/// The number of Interval variants
+pub const VARIANT_COUNT: usize = 11;
+
+/// One example Interval of every variant, built from bounds a < b
+///
+/// Two-bound variants span `a` to `b`, left-bounded rays start at `a`,
+/// right-bounded rays end at `b`, and the Singleton sits at `a`.  Returns None
+/// unless a < b.
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::generators::{all_variant_examples, VARIANT_COUNT};
+/// use intervals_general::interval::Interval;
+/// # fn main() -> std::result::Result<(), String> {
+/// let examples = all_variant_examples(1, 5).ok_or("invalid bounds")?;
+/// assert_eq!(examples.len(), VARIANT_COUNT);
+/// assert!(examples.contains(&Interval::UnboundedOpenRight { right: 5 }));
+/// assert!(all_variant_examples(5, 1).is_none());
+/// # Ok(())
+/// # }
+/// ```
+pub fn all_variant_examples<T>(a: T, b: T) -> Option<[Interval<T>; VARIANT_COUNT]>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    let bound_pair = BoundPair::new(a, b)?;
+    Some([
+        Interval::Closed { bound_pair },
+        Interval::Open { bound_pair },
+        Interval::LeftHalfOpen { bound_pair },
+        Interval::RightHalfOpen { bound_pair },
+        Interval::UnboundedClosedRight { right: b },
+        Interval::UnboundedOpenRight { right: b },
+        Interval::UnboundedClosedLeft { left: a },
+        Interval::UnboundedOpenLeft { left: a },
+        Interval::Singleton { at: a },
+        Interval::Unbounded,
+        Interval::Empty,
+    ])
+}
+
+/// Every ordered pairing of variant examples drawn from two bound ranges
+///
+/// The first member of each pair is drawn from `all_variant_examples` over
+/// `first`, and the second from `all_variant_examples` over `second`,
+/// yielding `VARIANT_COUNT * VARIANT_COUNT` pairs.  Choosing overlapping,
+/// touching, or disjoint ranges exercises the corresponding relationships.
+/// Returns None unless both ranges are ordered (left < right).
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::generators::{all_variant_pairs, VARIANT_COUNT};
+/// # fn main() -> std::result::Result<(), String> {
+/// let pairs = all_variant_pairs((0, 2), (1, 3)).ok_or("invalid bounds")?;
+/// assert_eq!(pairs.len(), VARIANT_COUNT * VARIANT_COUNT);
+/// for (i1, i2) in pairs {
+///     assert_eq!(i1.intersect(&i2), i2.intersect(&i1));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn all_variant_pairs<T>(
+    first: (T, T),
+    second: (T, T),
+) -> Option<Vec<(Interval<T>, Interval<T>)>>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    let first = all_variant_examples(first.0, first.1)?;
+    let second = all_variant_examples(second.0, second.1)?;
+    Some(itertools::iproduct!(first.iter().copied(), second.iter().copied()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples_are_distinct_variants() {
+        let examples = all_variant_examples(1.0, 2.0).unwrap();
+        for (idx, i1) in examples.iter().enumerate() {
+            for i2 in examples.iter().skip(idx + 1) {
+                assert_ne!(std::mem::discriminant(i1), std::mem::discriminant(i2));
+            }
+        }
+    }
+
+    #[test]
+    fn test_examples_require_ordered_bounds() {
+        assert!(all_variant_examples(1, 1).is_none());
+        assert!(all_variant_examples(2, 1).is_none());
+        assert!(all_variant_examples(f64::NAN, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_pairs() {
+        let pairs = all_variant_pairs((0, 1), (5, 6)).unwrap();
+        assert_eq!(pairs.len(), VARIANT_COUNT * VARIANT_COUNT);
+        assert_eq!(
+            pairs[0],
+            (
+                Interval::Closed {
+                    bound_pair: BoundPair::new(0, 1).unwrap()
+                },
+                Interval::Closed {
+                    bound_pair: BoundPair::new(5, 6).unwrap()
+                }
+            )
+        );
+        assert_eq!(
+            pairs[VARIANT_COUNT * VARIANT_COUNT - 1],
+            (Interval::Empty, Interval::Empty)
+        );
+        assert!(all_variant_pairs((0, 1), (6, 5)).is_none());
+    }
+}
@@ -0,0 +1,120 @@
+//! GraphQL scalars for Intervals
+//!
+//! Implements async-graphql scalars named `IntervalF64` and `IntervalDateTime`
+//! for `Interval<f64>` and `Interval<DateTime<Utc>>`, represented as strings
+//! in the Display notation (e.g. `"[1.0..2.5)"`).  Input strings which fail to
+//! parse are surfaced as GraphQL input errors.
+//!
+//! # Examples
+//!
+//! ```
+//! use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+//! use intervals_general::interval::Interval;
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn clamp(&self, window: Interval<f64>) -> Interval<f64> {
+//!         window.intersect(&(0.0..10.0))
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! assert!(schema.sdl().contains("scalar IntervalF64"));
+//! assert!(schema.sdl().contains("clamp(window: IntervalF64!): IntervalF64!"));
+//! ```
+
+use crate::interval::Interval;
+use async_graphql::{InputType, InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use chrono::{DateTime, Utc};
+
+// Parse the Display notation of an Interval from a GraphQL string value
+fn parse<T>(value: Value) -> InputValueResult<Interval<T>>
+where
+    T: std::str::FromStr,
+    T: Copy,
+    T: PartialOrd,
+    Interval<T>: InputType,
+{
+    match &value {
+        Value::String(notation) => notation.parse().map_err(InputValueError::custom),
+        _ => Err(InputValueError::expected_type(value)),
+    }
+}
+
+/// `Interval<f64>` in Display notation, e.g. `"(←..2.5]"`
+#[Scalar(name = "IntervalF64")]
+impl ScalarType for Interval<f64> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse(value)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// `Interval<DateTime<Utc>>` in Display notation with RFC 3339 bounds, e.g.
+/// `"[2024-01-01T00:00:00Z..2024-01-02T00:00:00Z)"`
+#[Scalar(name = "IntervalDateTime")]
+impl ScalarType for Interval<DateTime<Utc>> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse(value)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound_pair::BoundPair;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_f64_scalar_roundtrip() {
+        let interval = Interval::Open {
+            bound_pair: BoundPair::new(-1.5, 2.0).unwrap(),
+        };
+        let value = ScalarType::to_value(&interval);
+        assert_eq!(value, Value::String("(-1.5..2.0)".to_string()));
+        assert_eq!(
+            <Interval<f64> as ScalarType>::parse(value).unwrap(),
+            interval
+        );
+    }
+
+    #[test]
+    fn test_datetime_scalar_roundtrip() {
+        let interval = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(
+                Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+            )
+            .unwrap(),
+        };
+        let value = ScalarType::to_value(&interval);
+        assert_eq!(
+            value,
+            Value::String("[2024-01-01T00:00:00Z..2024-01-02T00:00:00Z)".to_string())
+        );
+        assert_eq!(
+            <Interval<DateTime<Utc>> as ScalarType>::parse(value).unwrap(),
+            interval
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(<Interval<f64> as ScalarType>::parse(Value::from(1)).is_err());
+        let error = <Interval<f64> as ScalarType>::parse(Value::String("[2.0..1.0]".to_string()))
+            .unwrap_err();
+        assert_eq!(
+            error.into_server_error(Default::default()).message,
+            r#"Failed to parse "IntervalF64": left bound is greater than right bound"#
+        );
+    }
+}
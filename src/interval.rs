@@ -1,4 +1,6 @@
 use crate::bound_pair::BoundPair;
+use crate::error::IntervalError;
+use crate::interval_like::IntervalLike;
 use itertools::Either;
 use std::cmp::Ordering;
 
@@ -103,16 +105,44 @@ pub use without_serde::Interval;
 
 // Internally used to simplify matching functions on Intervals
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum Bound<T> {
+pub(crate) enum Bound<T> {
     None,
     Unbounded,
     Open(T),
     Closed(T),
 }
 
-type TwoIntervalIter<T> =
+impl<T> Bound<T> {
+    // Transform the bound value (if any), preserving the bound type
+    pub(crate) fn try_map<U, F>(self, f: F) -> Option<Bound<U>>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        match self {
+            Bound::None => Some(Bound::None),
+            Bound::Unbounded => Some(Bound::Unbounded),
+            Bound::Open(value) => f(value).map(Bound::Open),
+            Bound::Closed(value) => f(value).map(Bound::Closed),
+        }
+    }
+
+    // Infallibly transform the bound value (if any), preserving the bound type
+    pub(crate) fn map<U, F>(self, f: F) -> Bound<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Bound::None => Bound::None,
+            Bound::Unbounded => Bound::Unbounded,
+            Bound::Open(value) => Bound::Open(f(value)),
+            Bound::Closed(value) => Bound::Closed(f(value)),
+        }
+    }
+}
+
+pub(crate) type TwoIntervalIter<T> =
     std::iter::Chain<std::iter::Once<Interval<T>>, std::iter::Once<Interval<T>>>;
-type OneIntervalIter<T> = std::iter::Once<Interval<T>>;
+pub(crate) type OneIntervalIter<T> = std::iter::Once<Interval<T>>;
 
 impl<T> Interval<T>
 where
@@ -139,6 +169,9 @@ where
     ///
     /// * The Empty interval does not contain the Empty interval
     ///
+    /// The specified interval may be anything [IntervalLike] (e.g. a std
+    /// Range).
+    ///
     /// # Examples
     ///
     /// ```
@@ -159,7 +192,11 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    pub fn contains(&self, other: &Interval<T>) -> bool {
+    pub fn contains<I>(&self, other: &I) -> bool
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
         let self_right_bound = self.right_bound();
         let other_right_bound = other.right_bound();
         let self_left_bound = self.left_bound();
@@ -199,9 +236,55 @@ where
         left_contained && right_contained
     }
 
+    /// Verify whether self contains value, distinguishing "could not evaluate"
+    ///
+    /// Returns [Containment::Unknown] rather than false when value or a bound
+    /// of self is incomparable (e.g. NaN), so that "out of range" and "not
+    /// evaluable" can be told apart.  The Empty interval contains no value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{Containment, Interval};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(0.0, 1.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(interval.contains_point_tristate(0.5), Containment::Yes);
+    /// assert_eq!(interval.contains_point_tristate(1.0), Containment::No);
+    /// assert_eq!(interval.contains_point_tristate(f64::NAN), Containment::Unknown);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn contains_point_tristate(&self, value: T) -> Containment {
+        if *self == Interval::Empty {
+            return Containment::No;
+        }
+        if value.partial_cmp(&value).is_none() {
+            return Containment::Unknown;
+        }
+        // Whether value lies on the inside of a bound, None if incomparable
+        let within = |bound, inside: fn(Ordering) -> bool| match bound {
+            Bound::Closed(limit) => value
+                .partial_cmp(&limit)
+                .map(|order| order.is_eq() || inside(order)),
+            Bound::Open(limit) => value.partial_cmp(&limit).map(inside),
+            Bound::Unbounded | Bound::None => Some(true),
+        };
+        let left = within(self.left_bound(), Ordering::is_gt);
+        let right = within(self.right_bound(), Ordering::is_lt);
+        match (left, right) {
+            (Some(true), Some(true)) => Containment::Yes,
+            (Some(_), Some(_)) => Containment::No,
+            _ => Containment::Unknown,
+        }
+    }
+
     /// Intersect an with the specified Interval
     ///
-    /// Take the intersection of self with the specified Interval.
+    /// Take the intersection of self with the specified Interval, or with
+    /// anything [IntervalLike] (e.g. a std Range).
     ///
     /// # Examples
     ///
@@ -226,7 +309,11 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    pub fn intersect(&self, other: &Interval<T>) -> Interval<T> {
+    pub fn intersect<I>(&self, other: &I) -> Interval<T>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = &other.to_interval();
         let left_cmp_partial = self.left_partial_cmp(other);
         let right_cmp_partial = self.right_partial_cmp(other);
         if left_cmp_partial.is_none() || right_cmp_partial.is_none() {
@@ -244,6 +331,12 @@ where
             other.right_bound()
         };
 
+        Interval::from_bounds(left_bound, right_bound)
+    }
+
+    // Build the Interval described by a left and right Bound, yielding Empty
+    // or Singleton where the bounds leave no room for a wider Interval
+    pub(crate) fn from_bounds(left_bound: Bound<T>, right_bound: Bound<T>) -> Interval<T> {
         match (left_bound, right_bound) {
             (Bound::None, _) => Interval::Empty,
             (_, Bound::None) => Interval::Empty,
@@ -293,7 +386,7 @@ where
         }
     }
 
-    fn left_bound(&self) -> Bound<T> {
+    pub(crate) fn left_bound(&self) -> Bound<T> {
         match self {
             Interval::Empty => Bound::None,
             Interval::Singleton { ref at } => Bound::Closed(*at),
@@ -320,7 +413,7 @@ where
         }
     }
 
-    fn right_bound(&self) -> Bound<T> {
+    pub(crate) fn right_bound(&self) -> Bound<T> {
         match self {
             Interval::Empty => Bound::None,
             Interval::Singleton { ref at } => Bound::Closed(*at),
@@ -542,6 +635,380 @@ where
         }
     }
 
+    // The finite left and right bound values, if both exist
+    pub(crate) fn finite_bounds(&self) -> Option<(T, T)> {
+        match (self.left_bound(), self.right_bound()) {
+            (
+                Bound::Closed(left) | Bound::Open(left),
+                Bound::Closed(right) | Bound::Open(right),
+            ) => Some((left, right)),
+            _ => None,
+        }
+    }
+
+    /// Linearly map a value from the coordinates of self to those of target
+    ///
+    /// The left bound of self maps to the left bound of target, and the right
+    /// bound of self to the right bound of target.  Values outside of self are
+    /// extrapolated.  Bound openness is disregarded.
+    ///
+    /// Returns None unless self has distinct finite bounds and target has
+    /// finite bounds.  Integer bound types are subject to truncating division
+    /// and to the overflow caveats described for [Interval::width].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let adc_counts = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, 4096.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let volts = Interval::Closed {
+    ///     bound_pair: BoundPair::new(-5.0, 5.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(adc_counts.remap_to(&volts, 1024.0), Some(-2.5));
+    /// assert_eq!(volts.remap_to(&adc_counts, -2.5), Some(1024.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remap_to(&self, target: &Interval<T>, value: T) -> Option<T>
+    where
+        T: std::ops::Add<Output = T>,
+        T: std::ops::Sub<Output = T>,
+        T: std::ops::Mul<Output = T>,
+        T: std::ops::Div<Output = T>,
+    {
+        let (from_left, from_right) = self.finite_bounds()?;
+        let (to_left, to_right) = target.finite_bounds()?;
+        if from_left.partial_cmp(&from_right) != Some(Ordering::Less) {
+            return None;
+        }
+        Some(to_left + (value - from_left) * (to_right - to_left) / (from_right - from_left))
+    }
+
+    /// Interpolate linearly between the bounds, from left at 0 to right at 1
+    ///
+    /// Computes `left + (right - left) * t`, where the width type scales by
+    /// t (e.g. f64 for f64 bounds).  Values of t outside of `[0, 1]` are
+    /// extrapolated, and bound openness is disregarded.  Floating point
+    /// results are subject to rounding, such that t of 1 may not yield
+    /// exactly the right bound.  Returns None unless self has finite bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let sweep = Interval::Closed {
+    ///     bound_pair: BoundPair::new(-1.0, 3.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(sweep.lerp(0.0), Some(-1.0));
+    /// assert_eq!(sweep.lerp(0.25), Some(0.0));
+    /// assert_eq!(sweep.lerp(1.5), Some(5.0));
+    /// assert_eq!(Interval::UnboundedClosedLeft { left: 0.0 }.lerp(0.5), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lerp<W, S>(&self, t: S) -> Option<T>
+    where
+        T: std::ops::Sub<Output = W>,
+        T: std::ops::Add<W, Output = T>,
+        W: std::ops::Mul<S, Output = W>,
+    {
+        let (left, right) = self.finite_bounds()?;
+        Some(left + (right - left) * t)
+    }
+
+    /// The normalized position of value between the bounds, the inverse of lerp
+    ///
+    /// Computes `(value - left) / (right - left)`, yielding 0 at the left
+    /// bound and 1 at the right bound (e.g. an f64 ratio for f64 bounds).
+    /// Values outside of self yield ratios outside of `[0, 1]`, and bound
+    /// openness is disregarded.  Returns None unless self has finite and
+    /// distinct bounds, so that Singleton and Empty intervals yield None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let celsius = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, 100.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let fahrenheit = Interval::Closed {
+    ///     bound_pair: BoundPair::new(32.0, 212.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(celsius.inverse_lerp(25.0), Some(0.25));
+    /// let ratio: f64 = celsius.inverse_lerp(37.0).ok_or("degenerate interval")?;
+    /// assert_eq!(fahrenheit.lerp(ratio).map(f64::round), Some(99.0));
+    /// assert_eq!(Interval::Singleton { at: 1.0 }.inverse_lerp(1.0), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn inverse_lerp<W, R>(&self, value: T) -> Option<R>
+    where
+        T: std::ops::Sub<Output = W>,
+        W: std::ops::Div<Output = R>,
+    {
+        let (left, right) = self.finite_bounds()?;
+        if left < right {
+            Some((value - left) / (right - left))
+        } else {
+            None
+        }
+    }
+
+    /// Shift both bounds by offset, preserving the variant
+    ///
+    /// The offset may be of another type than the bounds (e.g. a Duration
+    /// shifting Instant bounds).  Overflow behaves as the Add implementation
+    /// does; see `checked_translate` (with the num-traits feature) for a
+    /// checked variant over numeric bounds.  Floating point rounding which
+    /// collapses the bounds yields the Singleton or Empty interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// use std::time::{Duration, Instant};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let start = Instant::now();
+    /// let period = Duration::from_secs(60);
+    /// let window = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(start, start + period).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     window.translate(period),
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(start + period, start + 2 * period)
+    ///             .ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn translate<U>(&self, offset: U) -> Interval<T>
+    where
+        T: std::ops::Add<U, Output = T>,
+        U: Copy,
+    {
+        let shift = |bound| match bound {
+            Bound::Closed(value) => Bound::Closed(value + offset),
+            Bound::Open(value) => Bound::Open(value + offset),
+            bound => bound,
+        };
+        Interval::from_bounds(shift(self.left_bound()), shift(self.right_bound()))
+    }
+
+    /// Grow both bounds outward by delta, or shrink them for a negative delta
+    ///
+    /// The left bound moves down and the right bound moves up by delta, in
+    /// the width type of the bounds (e.g. a Duration for Instant bounds),
+    /// preserving bound openness.  Shrinking collapses the interval to the
+    /// Empty interval once its bounds cross, or to a Singleton where closed
+    /// bounds meet.  Unbounded sides are unaffected.  Overflow behaves as the
+    /// Add and Sub implementations do; see `checked_expand` (with the
+    /// num-traits feature) for a checked variant over numeric bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let nominal = Interval::Closed {
+    ///     bound_pair: BoundPair::new(49.5, 50.5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     nominal.pad(0.25),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(49.25, 50.75).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(nominal.pad(-0.5), Interval::Singleton { at: 50.0 });
+    /// assert_eq!(nominal.pad(-1.0), Interval::Empty);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pad<W>(&self, delta: W) -> Interval<T>
+    where
+        T: std::ops::Add<W, Output = T>,
+        T: std::ops::Sub<W, Output = T>,
+        W: Copy,
+    {
+        let lower = |bound: Bound<T>| match bound {
+            Bound::Closed(value) => Bound::Closed(value - delta),
+            Bound::Open(value) => Bound::Open(value - delta),
+            bound => bound,
+        };
+        let raise = |bound: Bound<T>| match bound {
+            Bound::Closed(value) => Bound::Closed(value + delta),
+            Bound::Open(value) => Bound::Open(value + delta),
+            bound => bound,
+        };
+        Interval::from_bounds(lower(self.left_bound()), raise(self.right_bound()))
+    }
+
+    /// Stretch or shrink self about anchor by factor
+    ///
+    /// Each bound moves to `anchor + (bound - anchor) * factor`, where the
+    /// width type scales by factor (e.g. f64 for f64 bounds).  A negative
+    /// factor mirrors self through anchor, its bounds trading places along
+    /// with their openness.  A zero factor collapses self to the Singleton at
+    /// anchor.  Scale about the midpoint by passing `lerp(0.5)` as anchor.
+    ///
+    /// The Empty interval scales to itself.  Returns None for unbounded
+    /// intervals, or if a bound is incomparable with anchor (e.g. NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let axis = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(0.0, 10.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let center = axis.lerp(0.5).ok_or("unbounded axis")?;
+    /// assert_eq!(
+    ///     axis.scale(0.5, center),
+    ///     Some(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(2.5, 7.5).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     axis.scale(-2.0, 0.0),
+    ///     Some(Interval::LeftHalfOpen {
+    ///         bound_pair: BoundPair::new(-20.0, 0.0).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scale<W, S>(&self, factor: S, anchor: T) -> Option<Interval<T>>
+    where
+        T: std::ops::Sub<Output = W>,
+        T: std::ops::Add<W, Output = T>,
+        T: std::ops::Sub<W, Output = T>,
+        W: std::ops::Mul<S, Output = W>,
+        S: Copy,
+    {
+        // Measure from the lesser value, keeping unsigned widths in range
+        let stretch = |value: T| match value.partial_cmp(&anchor)? {
+            Ordering::Less => Some(anchor - (anchor - value) * factor),
+            _ => Some(anchor + (value - anchor) * factor),
+        };
+        // The stretched value of a finite bound, and whether it is closed
+        let stretched = |bound| match bound {
+            Bound::Closed(value) => Some((stretch(value)?, true)),
+            Bound::Open(value) => Some((stretch(value)?, false)),
+            Bound::Unbounded | Bound::None => None,
+        };
+        let (left, right) = match (self.left_bound(), self.right_bound()) {
+            (Bound::None, _) | (_, Bound::None) => return Some(Interval::Empty),
+            (left, right) => (stretched(left)?, stretched(right)?),
+        };
+        let bound = |(value, closed)| {
+            if closed {
+                Bound::Closed(value)
+            } else {
+                Bound::Open(value)
+            }
+        };
+        Some(match left.0.partial_cmp(&right.0)? {
+            Ordering::Less => Interval::from_bounds(bound(left), bound(right)),
+            Ordering::Greater => Interval::from_bounds(bound(right), bound(left)),
+            Ordering::Equal => Interval::Singleton { at: left.0 },
+        })
+    }
+
+    /// Mirror self through zero, mapping `[a..b]` to `[-b..-a]`
+    ///
+    /// Each bound is negated and trades places with the other, carrying its
+    /// openness along, so that e.g. `[1..3)` reflects to `(-3..-1]` and the
+    /// UnboundedClosedLeft interval `[2..→)` to the UnboundedClosedRight
+    /// `(←..-2]`.  The Empty and Unbounded intervals reflect to themselves.
+    /// Also available as the unary `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 3).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     interval.reflect(),
+    ///     Interval::LeftHalfOpen {
+    ///         bound_pair: BoundPair::new(-3, -1).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     Interval::UnboundedOpenLeft { left: 2 }.reflect(),
+    ///     Interval::UnboundedOpenRight { right: -2 }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reflect(&self) -> Interval<T>
+    where
+        T: std::ops::Neg<Output = T>,
+    {
+        let negate = |bound: Bound<T>| match bound {
+            Bound::Closed(value) => Bound::Closed(-value),
+            Bound::Open(value) => Bound::Open(-value),
+            bound => bound,
+        };
+        Interval::from_bounds(negate(self.right_bound()), negate(self.left_bound()))
+    }
+
+    /// Convert the bound type, rounding the left bound down and right bound up
+    ///
+    /// Intended for lossy conversions (e.g. narrowing precision) where the
+    /// converted interval must enclose the original.  round_down is applied to
+    /// the left bound and must not increase its value, while round_up is
+    /// applied to the right bound and must not decrease its value.  Bound
+    /// openness is preserved, and a Singleton may widen to a Closed interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let millis = Interval::Closed {
+    ///     bound_pair: BoundPair::new(1500, 2500).ok_or("invalid BoundPair")?,
+    /// };
+    /// let seconds = millis.map_bounds_outward(|ms| ms / 1000, |ms| (ms + 999) / 1000);
+    /// assert_eq!(
+    ///     seconds,
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(1, 3).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_bounds_outward<U, D, P>(&self, round_down: D, round_up: P) -> Interval<U>
+    where
+        U: Copy,
+        U: std::cmp::PartialOrd,
+        D: FnOnce(T) -> U,
+        P: FnOnce(T) -> U,
+    {
+        Interval::from_bounds(
+            self.left_bound().map(round_down),
+            self.right_bound().map(round_up),
+        )
+    }
+
     /// Take the complement of the Interval, return one or two Intervals
     ///
     /// The return value is iterable and contains exclusively one or two
@@ -635,56 +1102,2476 @@ where
             Interval::Empty => Either::Left(std::iter::once(Interval::Unbounded)),
         }
     }
-}
 
-/// Implement the Display trait for Intervals
-///
-/// Here I uses [Wirth Interval Notation](https://proofwiki.org/wiki/Mathematician:Niklaus_Emil_Wirth).
-///
-/// # Examples
-///
-/// ```
-/// use intervals_general::bound_pair::BoundPair;
-/// use intervals_general::interval::Interval;
-///
-/// # fn main() -> std::result::Result<(), String> {
-/// let bp = BoundPair::new(1, 5).ok_or("invalid BoundPair")?;
-///
-/// assert_eq!(format!("{}", Interval::Closed { bound_pair: bp }), "[1..5]");
-/// assert_eq!(
-///     format!("{}", Interval::UnboundedOpenRight { right: 5 }),
-///     "(←..5)"
-/// );
-/// # Ok(())
-/// # }
-/// ```
-impl<T> std::fmt::Display for Interval<T>
-where
-    T: std::fmt::Debug,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Interval::Closed {
-                bound_pair:
-                    BoundPair {
-                        ref left,
-                        ref right,
-                    },
-            } => write!(f, "[{:?}..{:?}]", left, right),
-            Interval::Open {
-                bound_pair:
-                    BoundPair {
-                        ref left,
-                        ref right,
-                    },
-            } => write!(f, "({:?}..{:?})", left, right),
-            Interval::LeftHalfOpen {
-                bound_pair:
-                    BoundPair {
-                        ref left,
-                        ref right,
-                    },
-            } => write!(f, "({:?}..{:?}]", left, right),
+    /// Take the complement of the Interval, writing results into a buffer
+    ///
+    /// An allocation and iterator free alternative to
+    /// [complement](Interval::complement), for use in e.g. array based
+    /// pipelines.  Writes the one or two resulting Intervals into the leading
+    /// elements of out, returning the number written.  Trailing elements are
+    /// left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let mut out = [Interval::Empty; 2];
+    /// let count = Interval::Closed {
+    ///     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+    /// }
+    /// .complement_into(&mut out);
+    ///
+    /// assert_eq!(count, 2);
+    /// assert_eq!(
+    ///     out,
+    ///     [
+    ///         Interval::UnboundedOpenRight { right: 1 },
+    ///         Interval::UnboundedOpenLeft { left: 5 }
+    ///     ]
+    /// );
+    /// assert_eq!(Interval::Unbounded.complement_into(&mut out), 1);
+    /// assert_eq!(out[0], Interval::Empty);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn complement_into(&self, out: &mut [Interval<T>; 2]) -> usize {
+        let mut count = 0;
+        for (slot, interval) in out.iter_mut().zip(self.complement()) {
+            *slot = interval;
+            count += 1;
+        }
+        count
+    }
+
+    /// Decompose the interval into consecutive sub-intervals of a fixed width
+    ///
+    /// Chunks are right-half-open, save that the first chunk inherits the left
+    /// bound of self and the final chunk inherits the right bound of self, so
+    /// that the chunks exactly partition self.  The trailing chunk narrower
+    /// than width is handled per the [RemainderPolicy].
+    ///
+    /// The Empty interval, intervals unbounded on the left, and non-positive
+    /// widths yield no chunks.  Intervals unbounded on the right yield an
+    /// endless sequence of chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{Interval, RemainderPolicy};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0, 10).ok_or("invalid BoundPair")?,
+    /// };
+    /// let chunks: Vec<_> = interval.chunks(4, RemainderPolicy::Keep).collect();
+    ///
+    /// assert_eq!(
+    ///     chunks,
+    ///     vec![
+    ///         Interval::RightHalfOpen {
+    ///             bound_pair: BoundPair::new(0, 4).ok_or("invalid BoundPair")?
+    ///         },
+    ///         Interval::RightHalfOpen {
+    ///             bound_pair: BoundPair::new(4, 8).ok_or("invalid BoundPair")?
+    ///         },
+    ///         Interval::Closed {
+    ///             bound_pair: BoundPair::new(8, 10).ok_or("invalid BoundPair")?
+    ///         },
+    ///     ]
+    /// );
+    /// assert_eq!(interval.chunks(4, RemainderPolicy::Drop).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chunks<W>(&self, width: W, remainder: RemainderPolicy) -> Chunks<T, W>
+    where
+        T: std::ops::Add<W, Output = T>,
+        W: Copy,
+    {
+        let next_left = match self.left_bound() {
+            bound @ (Bound::Closed(_) | Bound::Open(_)) => bound,
+            _ => Bound::None,
+        };
+        Chunks {
+            next_left,
+            right: self.right_bound(),
+            width,
+            remainder,
+        }
+    }
+
+    /// Grow the interval until it is at least min_width wide
+    ///
+    /// The [PadAnchor] selects which bound(s) move: a left anchor fixes the
+    /// left bound and extends the right, a right anchor the converse, and a
+    /// center anchor extends both bounds by half the shortfall (the right
+    /// bound taking any indivisible remainder).  Where a bound would move
+    /// beyond the representable [domain](Interval::domain) of T, the padding
+    /// shifts onto the other side instead, yielding the min_width wide
+    /// interval starting or ending at the domain extremum, e.g. `[0..1]` of
+    /// u32 padded to 5 about its center yields `[0..5]`.  Bound openness is
+    /// preserved, and a Singleton grows into a Closed interval.  Intervals
+    /// already at least min_width wide (or with an incomparable min_width),
+    /// unbounded intervals, and the Empty interval are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{Interval, PadAnchor};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let slot = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(10, 12).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     slot.padded_to_min_width(5, PadAnchor::Center),
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(9, 14).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     slot.padded_to_min_width(5, PadAnchor::Left),
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(10, 15).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(slot.padded_to_min_width(1, PadAnchor::Right), slot);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn padded_to_min_width(&self, min_width: T, anchor: PadAnchor) -> Interval<T>
+    where
+        T: crate::domain::DomainBounds,
+        T: Default,
+        T: std::ops::Add<Output = T>,
+        T: std::ops::Sub<Output = T>,
+        T: std::ops::Div<Output = T>,
+        T: From<u8>,
+    {
+        use crate::domain::{checked_add, checked_sub};
+        let Some((left, right)) = self.finite_bounds() else {
+            return *self;
+        };
+        // A width beyond the domain exceeds any representable min_width
+        let Some(width) = checked_sub(right, left) else {
+            return *self;
+        };
+        if min_width.partial_cmp(&width) != Some(Ordering::Greater) {
+            return *self;
+        }
+        let shortfall = min_width - width;
+        let (lower, raise) = match anchor {
+            PadAnchor::Left => (T::from(0), shortfall),
+            PadAnchor::Right => (shortfall, T::from(0)),
+            PadAnchor::Center => {
+                let half = shortfall / T::from(2);
+                (half, shortfall - half)
+            }
+        };
+        let (left, right) = match (checked_sub(left, lower), checked_add(right, raise)) {
+            (Some(left), Some(right)) => (left, right),
+            (None, _) => (T::min_value(), T::min_value() + min_width),
+            (_, None) => (T::max_value() - min_width, T::max_value()),
+        };
+        Interval::from_bounds(
+            self.left_bound().map(|_| left),
+            self.right_bound().map(|_| right),
+        )
+    }
+
+    /// Iterate over the finite endpoints of the interval, left then right
+    ///
+    /// Yields up to two `(value, EndpointKind)` pairs: none for the Empty and
+    /// Unbounded intervals, one for rays, and two for bounded intervals
+    /// (Singletons included, as a closed left and a closed right endpoint at
+    /// the same value).  Suits sweep-line and plotting code which needs the
+    /// endpoints without matching on variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{EndpointKind, Interval};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     interval.endpoints().collect::<Vec<_>>(),
+    ///     vec![(1, EndpointKind::LeftClosed), (5, EndpointKind::RightOpen)]
+    /// );
+    /// assert_eq!(
+    ///     Interval::UnboundedOpenLeft { left: 3 }.endpoints().collect::<Vec<_>>(),
+    ///     vec![(3, EndpointKind::LeftOpen)]
+    /// );
+    /// assert_eq!(Interval::<i32>::Unbounded.endpoints().count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn endpoints(&self) -> Endpoints<T> {
+        self.finite_endpoints().into_iter()
+    }
+
+    /// The finite endpoint values of the interval and their openness
+    ///
+    /// A fixed size alternative to [endpoints](Interval::endpoints), holding
+    /// the left and right endpoint values (where finite) along with whether
+    /// each is closed.  Suits e.g. plot tick generation, which needs only the
+    /// numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::LeftHalfOpen {
+    ///     bound_pair: BoundPair::new(1.0, 5.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let endpoints = interval.finite_endpoints();
+    /// assert_eq!(endpoints.left, Some((1.0, false)));
+    /// assert_eq!(endpoints.right, Some((5.0, true)));
+    /// assert_eq!(endpoints.len(), 2);
+    /// assert!(Interval::<f64>::Unbounded.finite_endpoints().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn finite_endpoints(&self) -> FiniteEndpoints<T> {
+        let endpoint = |bound| match bound {
+            Bound::Closed(value) => Some((value, true)),
+            Bound::Open(value) => Some((value, false)),
+            Bound::None | Bound::Unbounded => None,
+        };
+        FiniteEndpoints {
+            left: endpoint(self.left_bound()),
+            right: endpoint(self.right_bound()),
+        }
+    }
+
+    /// Intersect with the specified Interval, reporting how the operands relate
+    ///
+    /// Yields the same intersection as [Interval::intersect], together with
+    /// the operand which contributed each resulting bound and a summary of
+    /// the operands' [IntersectRelation], from a single pass of bound
+    /// comparisons.
+    ///
+    /// Where an operand is Empty the operands are reported as Disjoint.  Adjacency is
+    /// continuous: the operands share a boundary value which exactly one of
+    /// them includes, so that their union has no gap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{BoundSource, IntersectRelation, Interval};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let i1 = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// let i2 = Interval::Open {
+    ///     bound_pair: BoundPair::new(-1, 2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let info = i1.intersect_info(&i2);
+    /// assert_eq!(info.intersection, i1.intersect(&i2));
+    /// assert_eq!(info.left_source, Some(BoundSource::SelfOperand));
+    /// assert_eq!(info.right_source, Some(BoundSource::Other));
+    /// assert_eq!(info.relation, IntersectRelation::Overlapping);
+    ///
+    /// let i3 = Interval::UnboundedClosedLeft { left: 5 };
+    /// assert_eq!(i1.intersect_info(&i3).relation, IntersectRelation::Adjacent);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn intersect_info<I>(&self, other: &I) -> IntersectInfo<T>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = &other.to_interval();
+        let (left_cmp, right_cmp) =
+            match (self.left_partial_cmp(other), self.right_partial_cmp(other)) {
+                (Some(left_cmp), Some(right_cmp)) => (left_cmp, right_cmp),
+                _ => return IntersectInfo::disjoint(IntersectRelation::Disjoint),
+            };
+
+        let (left_bound, left_source) = match left_cmp {
+            Ordering::Greater => (self.left_bound(), BoundSource::SelfOperand),
+            Ordering::Equal => (self.left_bound(), BoundSource::Both),
+            Ordering::Less => (other.left_bound(), BoundSource::Other),
+        };
+        let (right_bound, right_source) = match right_cmp {
+            Ordering::Less => (self.right_bound(), BoundSource::SelfOperand),
+            Ordering::Equal => (self.right_bound(), BoundSource::Both),
+            Ordering::Greater => (other.right_bound(), BoundSource::Other),
+        };
+
+        let intersection = Interval::from_bounds(left_bound, right_bound);
+        if intersection == Interval::Empty {
+            let relation = if Self::abut(self.right_bound(), other.left_bound())
+                || Self::abut(other.right_bound(), self.left_bound())
+            {
+                IntersectRelation::Adjacent
+            } else {
+                IntersectRelation::Disjoint
+            };
+            return IntersectInfo::disjoint(relation);
+        }
+
+        let relation = match (left_source, right_source) {
+            (BoundSource::Both, BoundSource::Both) => IntersectRelation::Equal,
+            (BoundSource::SelfOperand, BoundSource::Other)
+            | (BoundSource::Other, BoundSource::SelfOperand) => IntersectRelation::Overlapping,
+            (BoundSource::SelfOperand, _) | (_, BoundSource::SelfOperand) => {
+                IntersectRelation::SelfWithinOther
+            }
+            (BoundSource::Other, _) | (_, BoundSource::Other) => IntersectRelation::OtherWithinSelf,
+        };
+        IntersectInfo {
+            intersection,
+            left_source: Some(left_source),
+            right_source: Some(right_source),
+            relation,
+        }
+    }
+
+    // Whether a right bound meets a left bound at a value exactly one includes
+    fn abut(right_bound: Bound<T>, left_bound: Bound<T>) -> bool {
+        match (right_bound, left_bound) {
+            (Bound::Closed(right), Bound::Open(left))
+            | (Bound::Open(right), Bound::Closed(left)) => right == left,
+            _ => false,
+        }
+    }
+
+    /// Verify whether a sequence of Intervals exactly tiles a single Interval
+    ///
+    /// Each Interval must abut the next, sharing a boundary value which
+    /// exactly one of the pair includes, such that the sequence has neither
+    /// gaps nor overlaps (e.g. `[0..10)`, `[10..20)`, `[20..→)`).  An empty
+    /// sequence, or one containing the Empty interval, is not a chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let shards = [
+    ///     Interval::UnboundedOpenRight { right: 10 },
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(10, 20).ok_or("invalid BoundPair")?,
+    ///     },
+    ///     Interval::UnboundedClosedLeft { left: 20 },
+    /// ];
+    /// assert!(Interval::is_contiguous_chain(&shards));
+    /// assert!(!Interval::is_contiguous_chain(&[shards[0], shards[2]]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_contiguous_chain(intervals: &[Interval<T>]) -> bool {
+        !intervals.is_empty()
+            && !intervals.contains(&Interval::Empty)
+            && intervals
+                .windows(2)
+                .all(|pair| Self::abut(pair[0].right_bound(), pair[1].left_bound()))
+    }
+
+    /// Join a sequence of Intervals which exactly tiles a single Interval
+    ///
+    /// Yields the Interval spanning the left bound of the first through the
+    /// right bound of the last, or None if the sequence is not a contiguous
+    /// chain as per [is_contiguous_chain](Interval::is_contiguous_chain).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let shards = [
+    ///     Interval::UnboundedOpenRight { right: 10 },
+    ///     Interval::UnboundedClosedLeft { left: 10 },
+    /// ];
+    /// assert_eq!(Interval::join_chain(&shards), Some(Interval::Unbounded));
+    /// assert_eq!(Interval::join_chain(&[shards[1], shards[0]]), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn join_chain(intervals: &[Interval<T>]) -> Option<Interval<T>> {
+        if !Self::is_contiguous_chain(intervals) {
+            return None;
+        }
+        let (first, last) = (intervals.first()?, intervals.last()?);
+        Some(Interval::from_bounds(
+            first.left_bound(),
+            last.right_bound(),
+        ))
+    }
+
+    /// Group Intervals into connected components
+    ///
+    /// Intervals overlapping or abutting one another, directly or by way of
+    /// other members, form one component, whose hull is the union of its
+    /// members (see [merge](Interval::merge)).  Components are yielded in
+    /// ascending order, each listing the indices of its members in
+    /// intervals in ascending order.  Empty intervals belong to no
+    /// component, and bounds must be comparable (e.g. not NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let event = |start, end| -> Result<Interval<i32>, String> {
+    ///     Ok(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(start, end).ok_or("invalid BoundPair")?,
+    ///     })
+    /// };
+    /// // Sessionize events into continuous bursts
+    /// let events = [event(20, 25)?, event(0, 5)?, event(5, 9)?, event(22, 30)?];
+    /// let sessions = Interval::connected_components(&events);
+    /// assert_eq!(sessions.len(), 2);
+    /// assert_eq!(sessions[0].hull, event(0, 9)?);
+    /// assert_eq!(sessions[0].members, vec![1, 2]);
+    /// assert_eq!(sessions[1].hull, event(20, 30)?);
+    /// assert_eq!(sessions[1].members, vec![0, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connected_components(intervals: &[Interval<T>]) -> Vec<Component<T>> {
+        let mut order: Vec<usize> = (0..intervals.len())
+            .filter(|&index| !intervals[index].is_empty())
+            .collect();
+        order.sort_by(|&a, &b| {
+            intervals[a]
+                .left_partial_cmp(&intervals[b])
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let mut components: Vec<Component<T>> = Vec::new();
+        for index in order {
+            let interval = intervals[index];
+            let merged = components
+                .last()
+                .and_then(|component| component.hull.merge(&interval));
+            match (components.last_mut(), merged) {
+                (Some(component), Some(hull)) => {
+                    component.hull = hull;
+                    component.members.push(index);
+                }
+                _ => components.push(Component {
+                    hull: interval,
+                    members: vec![index],
+                }),
+            }
+        }
+        for component in &mut components {
+            component.members.sort_unstable();
+        }
+        components
+    }
+
+    /// Verify whether self and the specified Interval share any point
+    ///
+    /// Equivalent to testing the [intersect](Interval::intersect) result for
+    /// Empty, without constructing it.  Touching endpoints overlap only when
+    /// both are closed: `[1..2]` and `[2..3]` overlap, whereas `[1..2)` and
+    /// `[2..3]` do not.  The other operand may be anything [IntervalLike]
+    /// (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let closed = Interval::Closed {
+    ///     bound_pair: BoundPair::new(1, 2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let right_half_open = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let next = Interval::Closed {
+    ///     bound_pair: BoundPair::new(2, 3).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert!(closed.overlaps(&next));
+    /// assert!(!right_half_open.overlaps(&next));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn overlaps<I>(&self, other: &I) -> bool
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        // Whether some point lies at or right of left_bound and at or left of
+        // right_bound
+        let meet = |left_bound, right_bound| match (left_bound, right_bound) {
+            (Bound::None, _) | (_, Bound::None) => false,
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => true,
+            (Bound::Closed(left), Bound::Closed(right)) => left <= right,
+            (
+                Bound::Closed(left) | Bound::Open(left),
+                Bound::Closed(right) | Bound::Open(right),
+            ) => left < right,
+        };
+        meet(self.left_bound(), other.right_bound()) && meet(other.left_bound(), self.right_bound())
+    }
+
+    /// Verify whether self and the specified Interval meet end to start
+    ///
+    /// Adjacent intervals meet at a single value with no gap between them,
+    /// i.e. the right bound of one equals the left bound of the other and
+    /// at least one of those two bounds is closed, so that the pair
+    /// coalesces into one interval: `[1..2)` touches `[2..3]`, as does
+    /// `[1..2]`, but `[1..2)` does not touch `(2..3]`, which leaves 2
+    /// uncovered.  The other operand may be anything [IntervalLike] (e.g. a
+    /// std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let booking = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let next = Interval::Closed {
+    ///     bound_pair: BoundPair::new(2, 3).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert!(booking.touches(&next));
+    /// assert!(next.touches(&booking));
+    /// assert!(!booking.touches(&(3..4)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn touches<I>(&self, other: &I) -> bool
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        // Whether right_bound and left_bound lie at the same finite value,
+        // with at least one of them containing it
+        let meet = |right_bound, left_bound| match (right_bound, left_bound) {
+            (Bound::Open(_), Bound::Open(_)) => false,
+            (
+                Bound::Closed(right) | Bound::Open(right),
+                Bound::Closed(left) | Bound::Open(left),
+            ) => right == left,
+            _ => false,
+        };
+        meet(self.right_bound(), other.left_bound()) || meet(other.right_bound(), self.left_bound())
+    }
+
+    /// Verify whether every point of self is less than every point of other
+    ///
+    /// The interval arithmetic "certainly less than": self must end before
+    /// other begins, with a shared endpoint permitted if either side leaves
+    /// it out, e.g. `[1..2)` is certainly less than `[2..3]`.  Vacuously true
+    /// if either operand is Empty.  The other operand may be anything
+    /// [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let low = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1.0, 2.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let high = Interval::Closed {
+    ///     bound_pair: BoundPair::new(2.0, 3.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert!(low.certainly_lt(&high));
+    /// assert!(!high.certainly_lt(&low));
+    /// assert!(!low.certainly_lt(&Interval::Singleton { at: 1.5 }));
+    /// assert!(low.possibly_lt(&Interval::Singleton { at: 1.5 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn certainly_lt<I>(&self, other: &I) -> bool
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        match (self.right_bound(), other.left_bound()) {
+            (Bound::None, _) | (_, Bound::None) => true,
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+            (Bound::Closed(right), Bound::Closed(left)) => right < left,
+            (
+                Bound::Closed(right) | Bound::Open(right),
+                Bound::Closed(left) | Bound::Open(left),
+            ) => right <= left,
+        }
+    }
+
+    /// Verify whether some point of self is less than some point of other
+    ///
+    /// The interval arithmetic "possibly less than": self must begin before
+    /// other ends, whatever the openness of those bounds (e.g. `(1..2)` is
+    /// possibly less than `(0..1.5)`).  False if either operand is Empty.
+    /// The other operand may be anything [IntervalLike] (e.g. a std Range).
+    pub fn possibly_lt<I>(&self, other: &I) -> bool
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        match (self.left_bound(), other.right_bound()) {
+            (Bound::None, _) | (_, Bound::None) => false,
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => true,
+            (
+                Bound::Closed(left) | Bound::Open(left),
+                Bound::Closed(right) | Bound::Open(right),
+            ) => left < right,
+        }
+    }
+
+    /// Classify self against the specified Interval by Allen's interval algebra
+    ///
+    /// Bounds are compared with their openness: intervals sharing no point
+    /// but leaving no gap (e.g. `[1..2)` and `[2..3]`) meet, whereas `[1..2]`
+    /// and `[2..3]` share the point 2 and so overlap.  Unbounded sides are
+    /// equal to one another and beyond any finite bound, so e.g. `[1..→)`
+    /// finishes `(←..→)`.  Singletons take part as zero width intervals, and
+    /// a Singleton at an endpoint of a wider interval starts or finishes it.
+    /// Returns None if either operand is Empty, or a bound is incomparable
+    /// (e.g. NaN).  The other operand may be anything [IntervalLike] (e.g. a
+    /// std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{AllenRelation, Interval};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let morning = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(8, 12).ok_or("invalid BoundPair")?,
+    /// };
+    /// let afternoon = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(12, 17).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(morning.relate(&afternoon), Some(AllenRelation::Meets));
+    /// assert_eq!(afternoon.relate(&morning), Some(AllenRelation::MetBy));
+    /// assert_eq!(morning.relate(&(9..10)), Some(AllenRelation::Contains));
+    /// assert_eq!(morning.relate(&Interval::Empty), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn relate<I>(&self, other: &I) -> Option<AllenRelation>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let comparable = |(value, _): (T, EndpointKind)| value.partial_cmp(&value).is_some();
+        if !self.endpoints().chain(other.endpoints()).all(comparable) {
+            return None;
+        }
+
+        let adjacent = self.intersect_info(&other).relation == IntersectRelation::Adjacent;
+        if self.certainly_lt(&other) {
+            return Some(if adjacent {
+                AllenRelation::Meets
+            } else {
+                AllenRelation::Precedes
+            });
+        }
+        if other.certainly_lt(self) {
+            return Some(if adjacent {
+                AllenRelation::MetBy
+            } else {
+                AllenRelation::PrecededBy
+            });
+        }
+
+        let left = self.left_partial_cmp(&other)?;
+        let right = self.right_partial_cmp(&other)?;
+        Some(match (left, right) {
+            (Ordering::Less, Ordering::Less) => AllenRelation::Overlaps,
+            (Ordering::Less, Ordering::Equal) => AllenRelation::FinishedBy,
+            (Ordering::Less, Ordering::Greater) => AllenRelation::Contains,
+            (Ordering::Equal, Ordering::Less) => AllenRelation::Starts,
+            (Ordering::Equal, Ordering::Equal) => AllenRelation::Equals,
+            (Ordering::Equal, Ordering::Greater) => AllenRelation::StartedBy,
+            (Ordering::Greater, Ordering::Less) => AllenRelation::During,
+            (Ordering::Greater, Ordering::Equal) => AllenRelation::Finishes,
+            (Ordering::Greater, Ordering::Greater) => AllenRelation::OverlappedBy,
+        })
+    }
+
+    /// The width of the intersection of self with the specified Interval
+    ///
+    /// Equivalent to `self.intersect(other).width()` without constructing
+    /// the intersection: None if the operands share no point or their
+    /// intersection is unbounded.  The other operand may be anything
+    /// [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let slot = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(9, 12).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(slot.overlap_width(&(10..14)), Some(2));
+    /// assert_eq!(slot.overlap_width(&(12..14)), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn overlap_width<I>(&self, other: &I) -> Option<<T as std::ops::Sub>::Output>
+    where
+        I: IntervalLike<T> + ?Sized,
+        T: std::ops::Sub,
+    {
+        let other = other.to_interval();
+        if !self.overlaps(&other) {
+            return None;
+        }
+        let value = |bound| match bound {
+            Bound::Closed(value) | Bound::Open(value) => Some(value),
+            Bound::Unbounded | Bound::None => None,
+        };
+        // The inner of two bounds, with an unbounded side yielding to the other
+        let inner = |a: Option<T>, b: Option<T>, take_b: fn(&T, &T) -> bool| match (a, b) {
+            (Some(a), Some(b)) => Some(if take_b(&a, &b) { b } else { a }),
+            (a, b) => a.or(b),
+        };
+        let left = inner(value(self.left_bound()), value(other.left_bound()), T::lt)?;
+        let right = inner(value(self.right_bound()), value(other.right_bound()), T::gt)?;
+        Some(right - left)
+    }
+
+    /// The distance from value to the nearest point of self
+    ///
+    /// Zero (computed as `value - value`) when value lies within the bounds,
+    /// otherwise the distance to the nearer bound.  An open bound is treated
+    /// as its bound value, its infimum distance.  Returns None for the Empty
+    /// interval, which has no points to measure to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let nominal = Interval::Closed {
+    ///     bound_pair: BoundPair::new(10, 20).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(nominal.point_distance(15), Some(0));
+    /// assert_eq!(nominal.point_distance(7), Some(3));
+    /// assert_eq!(nominal.point_distance(26), Some(6));
+    /// assert_eq!(Interval::UnboundedOpenRight { right: 0 }.point_distance(-100), Some(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn point_distance(&self, value: T) -> Option<<T as std::ops::Sub>::Output>
+    where
+        T: std::ops::Sub,
+    {
+        if *self == Interval::Empty {
+            return None;
+        }
+        let bound_value = |bound| match bound {
+            Bound::Closed(value) | Bound::Open(value) => Some(value),
+            Bound::Unbounded | Bound::None => None,
+        };
+        match (
+            bound_value(self.left_bound()),
+            bound_value(self.right_bound()),
+        ) {
+            (Some(left), _) if value < left => Some(left - value),
+            (_, Some(right)) if value > right => Some(value - right),
+            // The zero of the width type, absent a Zero trait bound
+            #[allow(clippy::eq_op)]
+            _ => Some(value - value),
+        }
+    }
+
+    /// The smallest Interval containing both self and the specified Interval
+    ///
+    /// Takes the lesser left bound and the greater right bound of the
+    /// operands (the convex hull), preserving the openness of those extreme
+    /// bounds.  Points between disjoint operands are included.  The Empty
+    /// interval is the identity, and the other operand may be anything
+    /// [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let i1 = Interval::Open {
+    ///     bound_pair: BoundPair::new(1, 3).ok_or("invalid BoundPair")?,
+    /// };
+    /// let i2 = Interval::Closed {
+    ///     bound_pair: BoundPair::new(6, 8).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     i1.span(&i2),
+    ///     Interval::LeftHalfOpen {
+    ///         bound_pair: BoundPair::new(1, 8).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(i1.span(&Interval::Empty), i1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn span<I>(&self, other: &I) -> Interval<T>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        if *self == Interval::Empty {
+            return other;
+        }
+        if other == Interval::Empty {
+            return *self;
+        }
+
+        let left_bound = if self.left_partial_cmp(&other) != Some(Ordering::Greater) {
+            self.left_bound()
+        } else {
+            other.left_bound()
+        };
+        let right_bound = if self.right_partial_cmp(&other) != Some(Ordering::Less) {
+            self.right_bound()
+        } else {
+            other.right_bound()
+        };
+        Interval::from_bounds(left_bound, right_bound)
+    }
+
+    /// Take the union of self with the specified Interval
+    ///
+    /// Yields the single merged Interval where the operands overlap or are
+    /// adjacent (e.g. `[1..2)` and `[2..3]` merge into `[1..3]`), and
+    /// otherwise the two disjoint operands in ascending order.  The union
+    /// with the Empty interval yields the other operand alone.  The other
+    /// operand may be anything [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let i1 = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let i2 = Interval::Closed {
+    ///     bound_pair: BoundPair::new(2, 3).ok_or("invalid BoundPair")?,
+    /// };
+    /// let mut result_it = i1.union(&i2);
+    /// assert_eq!(
+    ///     result_it.next(),
+    ///     Some(Interval::Closed {
+    ///         bound_pair: BoundPair::new(1, 3).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(result_it.next(), None);
+    ///
+    /// let i3 = Interval::UnboundedOpenLeft { left: 3 };
+    /// assert_eq!(i1.union(&i3).collect::<Vec<_>>(), vec![i1, i3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn union<I>(&self, other: &I) -> itertools::Either<OneIntervalIter<T>, TwoIntervalIter<T>>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        if *self == Interval::Empty {
+            return Either::Left(std::iter::once(other));
+        }
+        if other == Interval::Empty {
+            return Either::Left(std::iter::once(*self));
+        }
+
+        let self_first = self.left_partial_cmp(&other) != Some(Ordering::Greater);
+        if self.intersect_info(&other).relation == IntersectRelation::Disjoint {
+            let (first, second) = if self_first {
+                (*self, other)
+            } else {
+                (other, *self)
+            };
+            return Either::Right(std::iter::once(first).chain(std::iter::once(second)));
+        }
+
+        Either::Left(std::iter::once(self.span(&other)))
+    }
+
+    /// The single Interval union of self and the specified Interval, if any
+    ///
+    /// Some when the operands overlap or abut (e.g. `[1..2)` and `[2..3]`),
+    /// so that their [union](Interval::union) has no gap, and None otherwise.
+    /// Operands touching at a point neither contains, such as `(1..2)` and
+    /// `(2..3)`, do not merge.  The Empty interval is the identity, and the
+    /// other operand may be anything [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let booking = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 2).ok_or("invalid BoundPair")?,
+    /// };
+    /// let next = Interval::Closed {
+    ///     bound_pair: BoundPair::new(2, 3).ok_or("invalid BoundPair")?,
+    /// };
+    /// let merged = Interval::Closed {
+    ///     bound_pair: BoundPair::new(1, 3).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(booking.merge(&next), Some(merged));
+    /// assert_eq!(booking.merge(&(5..6)), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge<I>(&self, other: &I) -> Option<Interval<T>>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let mut union = self.union(other);
+        match (union.next(), union.next()) {
+            (Some(merged), None) => Some(merged),
+            _ => None,
+        }
+    }
+
+    /// Take the set difference of self minus the specified Interval
+    ///
+    /// Yields the zero, one or two Intervals of points in self but not in
+    /// other, in ascending order.  Bounds are inverted at each cut, so that
+    /// e.g. `[0..10] \ [3..5)` yields `[0..3)` and `[5..10]`.  The other
+    /// operand may be anything [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0, 10).ok_or("invalid BoundPair")?,
+    /// };
+    /// let mut result_it = interval.difference(&(3..5));
+    /// assert_eq!(
+    ///     result_it.next(),
+    ///     Some(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(0, 3).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     result_it.next(),
+    ///     Some(Interval::Closed {
+    ///         bound_pair: BoundPair::new(5, 10).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(result_it.next(), None);
+    /// assert_eq!(interval.difference(&Interval::Unbounded).next(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn difference<I>(&self, other: &I) -> Difference<T>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let mut pieces = [None, None];
+        let remaining = other
+            .to_interval()
+            .complement()
+            .map(|piece| self.intersect(&piece))
+            .filter(|piece| *piece != Interval::Empty);
+        for (slot, piece) in pieces.iter_mut().zip(remaining) {
+            *slot = Some(piece);
+        }
+        Difference {
+            inner: pieces.into_iter().flatten(),
+        }
+    }
+
+    /// The Interval of points separating self from the specified Interval
+    ///
+    /// For operands lying apart, the gap runs between the nearer bounds with
+    /// their openness inverted, e.g. `[1..2]` and `[4..5)` leave `(2..4)`,
+    /// and `[1..2)` and `(2..3]` leave the Singleton `[2]`.  Yields Empty if
+    /// the operands overlap or abut, or either is Empty.  The width of the
+    /// gap (e.g. the pause between consecutive windows) follows from
+    /// [width](Interval::width).  The other operand may be anything
+    /// [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let first = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(0, 10).ok_or("invalid BoundPair")?,
+    /// };
+    /// let second = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(15, 25).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     second.gap(&first),
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(10, 15).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(first.gap(&second).width(), Some(5));
+    /// assert_eq!(first.gap(&(5..20)), Interval::Empty);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gap<I>(&self, other: &I) -> Interval<T>
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let other = other.to_interval();
+        if self.is_empty() || other.is_empty() {
+            return Interval::Empty;
+        }
+        let (first, second) = if self.certainly_lt(&other) {
+            (*self, other)
+        } else if other.certainly_lt(self) {
+            (other, *self)
+        } else {
+            return Interval::Empty;
+        };
+        let invert = |bound| match bound {
+            Bound::Closed(value) => Bound::Open(value),
+            Bound::Open(value) => Bound::Closed(value),
+            bound => bound,
+        };
+        Interval::from_bounds(invert(first.right_bound()), invert(second.left_bound()))
+    }
+
+    /// Clip self to the specified container, reporting what was cut
+    ///
+    /// Yields the intersection of self with container, along with the
+    /// [Overflow] parts of self lying below and above the container.  The
+    /// amount cut from each side follows from the [width](Interval::width)
+    /// of those parts.  Should the container be Empty, all of self is
+    /// reported as left overflow.  The container may be anything
+    /// [IntervalLike] (e.g. a std Range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let content = Interval::Closed {
+    ///     bound_pair: BoundPair::new(-20.0, 90.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let viewport = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, 100.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let (visible, overflow) = content.fit_within(&viewport);
+    /// assert_eq!(
+    ///     visible,
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(0.0, 90.0).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(overflow.left.width(), Some(20.0));
+    /// assert_eq!(overflow.right, Interval::Empty);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit_within<I>(&self, container: &I) -> (Interval<T>, Overflow<T>)
+    where
+        I: IntervalLike<T> + ?Sized,
+    {
+        let container = container.to_interval();
+        let below = match container.left_bound() {
+            Bound::Closed(left) => Interval::UnboundedOpenRight { right: left },
+            Bound::Open(left) => Interval::UnboundedClosedRight { right: left },
+            Bound::Unbounded => Interval::Empty,
+            Bound::None => Interval::Unbounded,
+        };
+        let above = match container.right_bound() {
+            Bound::Closed(right) => Interval::UnboundedOpenLeft { left: right },
+            Bound::Open(right) => Interval::UnboundedClosedLeft { left: right },
+            Bound::Unbounded | Bound::None => Interval::Empty,
+        };
+        (
+            self.intersect(&container),
+            Overflow {
+                left: self.intersect(&below),
+                right: self.intersect(&above),
+            },
+        )
+    }
+
+    /// Cut self in two at the specified point
+    ///
+    /// Yields the parts of self below and above at, in that order, with the
+    /// point itself (if contained) kept by the side selected by keep: e.g.
+    /// splitting `[0..10]` at 4 yields `[0..4]` and `(4..10]` when the left
+    /// side keeps the point.  A part is Empty where self lies entirely on
+    /// the other side of at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{Interval, SplitSide};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let schedule = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(9, 17).ok_or("invalid BoundPair")?,
+    /// };
+    /// let (before, after) = schedule.split_at(12, SplitSide::Right);
+    /// assert_eq!(
+    ///     before,
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(9, 12).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     after,
+    ///     Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(12, 17).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(schedule.split_at(20, SplitSide::Right), (schedule, Interval::Empty));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split_at(&self, at: T, keep: SplitSide) -> (Interval<T>, Interval<T>) {
+        let (below, above) = match keep {
+            SplitSide::Left => (
+                Interval::UnboundedClosedRight { right: at },
+                Interval::UnboundedOpenLeft { left: at },
+            ),
+            SplitSide::Right => (
+                Interval::UnboundedOpenRight { right: at },
+                Interval::UnboundedClosedLeft { left: at },
+            ),
+        };
+        (self.intersect(&below), self.intersect(&above))
+    }
+
+    /// The smallest Interval symmetric about center which contains self
+    ///
+    /// Both bounds lie at the greater distance of self's bounds from center;
+    /// they are closed if a bound at that distance is closed, else open.
+    /// Empty and Unbounded are already symmetric, and other right- or
+    /// left-unbounded intervals grow to Unbounded.  Returns None if a bound is
+    /// incomparable with center (e.g. NaN), or if the symmetric hull extends
+    /// beyond the representable [domain](Interval::domain) of T (e.g. below
+    /// zero for unsigned bounds).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// // A measured tolerance band of -0.25..0.5 around a nominal 10.0
+    /// let band = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(9.75, 10.5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     band.symmetrize(10.0),
+    ///     Some(Interval::Open {
+    ///         bound_pair: BoundPair::new(9.5, 10.5).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert!(!band.is_symmetric_about(10.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn symmetrize(&self, center: T) -> Option<Interval<T>>
+    where
+        T: crate::domain::DomainBounds,
+        T: Default,
+        T: std::ops::Add<Output = T>,
+        T: std::ops::Sub<Output = T>,
+    {
+        use crate::domain::{checked_add, checked_sub};
+        let distance = |value: T| match value.partial_cmp(&center)? {
+            Ordering::Greater => checked_sub(value, center),
+            _ => checked_sub(center, value),
+        };
+        // The distance of a finite bound from center, and whether it is closed
+        let reach = |bound| match bound {
+            Bound::Closed(value) => Some((distance(value)?, true)),
+            Bound::Open(value) => Some((distance(value)?, false)),
+            Bound::Unbounded | Bound::None => None,
+        };
+        let (left, right) = match (self.left_bound(), self.right_bound()) {
+            (Bound::None, _) | (_, Bound::None) => return Some(Interval::Empty),
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => return Some(Interval::Unbounded),
+            (left, right) => (reach(left)?, reach(right)?),
+        };
+        let (radius, closed) = match left.0.partial_cmp(&right.0)? {
+            Ordering::Less => right,
+            Ordering::Greater => left,
+            Ordering::Equal => (left.0, left.1 || right.1),
+        };
+        let bound = |value| {
+            if closed {
+                Bound::Closed(value)
+            } else {
+                Bound::Open(value)
+            }
+        };
+        Some(Interval::from_bounds(
+            bound(checked_sub(center, radius)?),
+            bound(checked_add(center, radius)?),
+        ))
+    }
+
+    /// Verify whether self is symmetric about center
+    ///
+    /// Exactly when self is its own [symmetrize](Interval::symmetrize)
+    /// result: both bounds lie at the same distance from center with the
+    /// same openness, or self is Empty or Unbounded.  Distances are compared
+    /// exactly, so floating point bounds are subject to rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let band = Interval::Closed {
+    ///     bound_pair: BoundPair::new(8, 12).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert!(band.is_symmetric_about(10));
+    /// assert!(!band.is_symmetric_about(9));
+    /// assert!(Interval::Singleton { at: 3 }.is_symmetric_about(3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_symmetric_about(&self, center: T) -> bool
+    where
+        T: crate::domain::DomainBounds,
+        T: Default,
+        T: std::ops::Add<Output = T>,
+        T: std::ops::Sub<Output = T>,
+    {
+        self.symmetrize(center) == Some(*self)
+    }
+
+    // Order two endpoints, None if they are incomparable
+    fn ordered(a: T, b: T) -> Option<(T, T)> {
+        match a.partial_cmp(&b)? {
+            Ordering::Greater => Some((b, a)),
+            _ => Some((a, b)),
+        }
+    }
+
+    /// Construct the Closed interval between two endpoints in either order
+    ///
+    /// Equal endpoints yield the Singleton interval.  Returns None if the
+    /// endpoints are incomparable (e.g. NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// assert_eq!(
+    ///     Interval::closed_unordered(5, 1),
+    ///     Some(Interval::Closed {
+    ///         bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(Interval::closed_unordered(3, 3), Some(Interval::Singleton { at: 3 }));
+    /// assert_eq!(Interval::closed_unordered(f64::NAN, 1.0), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn closed_unordered(a: T, b: T) -> Option<Interval<T>> {
+        let (left, right) = Self::ordered(a, b)?;
+        Some(Interval::from_bounds(
+            Bound::Closed(left),
+            Bound::Closed(right),
+        ))
+    }
+
+    /// Construct the Open interval between two endpoints in either order
+    ///
+    /// Equal endpoints yield the Empty interval, see
+    /// [closed_unordered](Interval::closed_unordered).
+    pub fn open_unordered(a: T, b: T) -> Option<Interval<T>> {
+        let (left, right) = Self::ordered(a, b)?;
+        Some(Interval::from_bounds(Bound::Open(left), Bound::Open(right)))
+    }
+
+    /// Construct the LeftHalfOpen interval between two endpoints in either
+    /// order
+    ///
+    /// The lesser endpoint is excluded.  Equal endpoints yield the Empty
+    /// interval, see [closed_unordered](Interval::closed_unordered).
+    pub fn left_half_open_unordered(a: T, b: T) -> Option<Interval<T>> {
+        let (left, right) = Self::ordered(a, b)?;
+        Some(Interval::from_bounds(
+            Bound::Open(left),
+            Bound::Closed(right),
+        ))
+    }
+
+    /// Construct the RightHalfOpen interval between two endpoints in either
+    /// order
+    ///
+    /// The greater endpoint is excluded.  Equal endpoints yield the Empty
+    /// interval, see [closed_unordered](Interval::closed_unordered).
+    pub fn right_half_open_unordered(a: T, b: T) -> Option<Interval<T>> {
+        let (left, right) = Self::ordered(a, b)?;
+        Some(Interval::from_bounds(
+            Bound::Closed(left),
+            Bound::Open(right),
+        ))
+    }
+
+    /// Construct the Closed interval from left and right, allowing left ==
+    /// right
+    ///
+    /// Unlike [BoundPair::new], which rejects equal bounds, a degenerate
+    /// `[a..a]` yields the Singleton interval.  Returns None if left > right
+    /// or the bounds are incomparable (e.g. NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// assert_eq!(
+    ///     Interval::closed_allowing_degenerate(1, 5),
+    ///     Some(Interval::Closed {
+    ///         bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     Interval::closed_allowing_degenerate(3, 3),
+    ///     Some(Interval::Singleton { at: 3 })
+    /// );
+    /// assert_eq!(Interval::closed_allowing_degenerate(5, 1), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn closed_allowing_degenerate(left: T, right: T) -> Option<Interval<T>> {
+        match left.partial_cmp(&right)? {
+            Ordering::Greater => None,
+            _ => Some(Interval::from_bounds(
+                Bound::Closed(left),
+                Bound::Closed(right),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Interval<T>
+where
+    T: Copy,
+    T: std::cmp::PartialOrd,
+    T: num_traits::Zero,
+{
+    /// Construct the unit interval `[0, 1]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// assert_eq!(
+    ///     Interval::unit(),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(0.0, 1.0).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unit() -> Interval<T>
+    where
+        T: num_traits::One,
+    {
+        Interval::from_bounds(Bound::Closed(T::zero()), Bound::Closed(T::one()))
+    }
+
+    /// Construct the nonnegative interval `[0, inf)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// assert_eq!(
+    ///     Interval::nonnegative(),
+    ///     Interval::UnboundedClosedLeft { left: 0 }
+    /// );
+    /// ```
+    pub fn nonnegative() -> Interval<T> {
+        Interval::UnboundedClosedLeft { left: T::zero() }
+    }
+
+    /// Construct the positive interval `(0, inf)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// assert_eq!(Interval::positive(), Interval::UnboundedOpenLeft { left: 0 });
+    /// ```
+    pub fn positive() -> Interval<T> {
+        Interval::UnboundedOpenLeft { left: T::zero() }
+    }
+
+    /// Construct the closed interval `[-radius, radius]`
+    ///
+    /// A zero radius yields the Singleton at zero, and a negative (or
+    /// incomparable, e.g. NaN) radius yields the Empty interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// assert_eq!(
+    ///     Interval::symmetric(2),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(-2, 2).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(Interval::symmetric(0), Interval::Singleton { at: 0 });
+    /// assert_eq!(Interval::symmetric(-1), Interval::Empty);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn symmetric(radius: T) -> Interval<T>
+    where
+        T: std::ops::Neg<Output = T>,
+    {
+        match BoundPair::new(-radius, radius) {
+            Some(bound_pair) => Interval::Closed { bound_pair },
+            None if radius == T::zero() => Interval::Singleton { at: T::zero() },
+            None => Interval::Empty,
+        }
+    }
+}
+
+impl<T> Interval<T>
+where
+    T: Copy,
+    T: std::cmp::PartialOrd,
+{
+    /// The center of a bounded interval
+    ///
+    /// Computed as `left + (right - left) / 2` by way of the [Halve] width,
+    /// so for integer bound types the center rounds down toward the left
+    /// bound (e.g. the midpoint of `[1..4]` is 2), and for rational bound
+    /// types it is exact.  Time intervals (e.g. `Interval<Instant>` or
+    /// `Interval<Duration>`) halve their Duration width.  Bound openness does
+    /// not affect the result.  Returns None for unbounded intervals and
+    /// Empty, and the point itself for a Singleton.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::Open {
+    ///     bound_pair: BoundPair::new(1.0, 4.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(interval.midpoint(), Some(2.5));
+    /// let integers = Interval::Closed {
+    ///     bound_pair: BoundPair::new(-4, -1).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(integers.midpoint(), Some(-3));
+    /// assert_eq!(Interval::UnboundedOpenLeft { left: 1.0 }.midpoint(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn midpoint<W>(&self) -> Option<T>
+    where
+        T: std::ops::Sub<Output = W>,
+        T: std::ops::Add<W, Output = T>,
+        W: Halve,
+    {
+        let (left, right) = self.finite_bounds()?;
+        Some(left + (right - left).halve())
+    }
+}
+
+/// Width data types which can be halved, enabling [Interval::midpoint]
+///
+/// Implemented for the primitive numeric types (integers rounding toward
+/// zero), Duration, and with the num-rational feature the Ratio types.
+pub trait Halve {
+    /// Half of self
+    fn halve(self) -> Self;
+}
+
+macro_rules! impl_halve {
+    ($two:expr => $($t:ty),*) => {
+        $(
+            impl Halve for $t {
+                fn halve(self) -> Self {
+                    self / $two
+                }
+            }
+        )*
+    };
+}
+
+impl_halve!(2 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_halve!(2.0 => f32, f64);
+impl_halve!(2 => std::time::Duration);
+
+#[cfg(feature = "num-rational")]
+impl<T> Halve for num_rational::Ratio<T>
+where
+    T: Clone,
+    T: num_traits::One,
+    T: std::ops::Add<Output = T>,
+    num_rational::Ratio<T>: std::ops::Div<T, Output = num_rational::Ratio<T>>,
+{
+    fn halve(self) -> Self {
+        self / (T::one() + T::one())
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Interval<T>
+where
+    T: Copy,
+    T: std::cmp::PartialOrd,
+{
+    /// Shift both bounds by offset, returning None on overflow
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1i64, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     interval.checked_translate(-3),
+    ///     Some(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(-2, 2).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(interval.checked_translate(i64::MAX), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_translate(&self, offset: T) -> Option<Interval<T>>
+    where
+        T: num_traits::CheckedAdd,
+    {
+        let shift = |value: T| value.checked_add(&offset);
+        Some(Interval::from_bounds(
+            self.left_bound().try_map(shift)?,
+            self.right_bound().try_map(shift)?,
+        ))
+    }
+
+    /// Shift both bounds by offset, saturating at the bound type extrema
+    ///
+    /// Saturation may collapse the interval: bounds driven onto the same
+    /// extremum yield the Singleton at that value if both are closed, else the
+    /// Empty interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::Closed {
+    ///     bound_pair: BoundPair::new(u8::MAX - 10, u8::MAX - 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     interval.saturating_translate(7),
+    ///     Interval::Closed {
+    ///         bound_pair: BoundPair::new(u8::MAX - 3, u8::MAX).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     interval.saturating_translate(20),
+    ///     Interval::Singleton { at: u8::MAX }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn saturating_translate(&self, offset: T) -> Interval<T>
+    where
+        T: num_traits::SaturatingAdd,
+    {
+        let shift = |value: T| value.saturating_add(&offset);
+        Interval::from_bounds(self.left_bound().map(shift), self.right_bound().map(shift))
+    }
+
+    /// Grow both bounds outward by delta, returning None on overflow
+    ///
+    /// The left bound moves down and the right bound moves up by delta, so a
+    /// negative delta shrinks the interval (possibly to Singleton or Empty).
+    /// Unbounded sides are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::Open {
+    ///     bound_pair: BoundPair::new(1i32, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     interval.checked_expand(2),
+    ///     Some(Interval::Open {
+    ///         bound_pair: BoundPair::new(-1, 7).ok_or("invalid BoundPair")?
+    ///     })
+    /// );
+    /// assert_eq!(interval.checked_expand(-2), Some(Interval::Empty));
+    /// assert_eq!(interval.checked_expand(i32::MAX), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_expand(&self, delta: T) -> Option<Interval<T>>
+    where
+        T: num_traits::CheckedAdd,
+        T: num_traits::CheckedSub,
+    {
+        Some(Interval::from_bounds(
+            self.left_bound()
+                .try_map(|value| value.checked_sub(&delta))?,
+            self.right_bound()
+                .try_map(|value| value.checked_add(&delta))?,
+        ))
+    }
+
+    /// Grow both bounds outward by delta, saturating at the bound type extrema
+    ///
+    /// As [checked_expand](Interval::checked_expand), save that bounds
+    /// saturate rather than overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::LeftHalfOpen {
+    ///     bound_pair: BoundPair::new(2u32, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(
+    ///     interval.saturating_expand(3),
+    ///     Interval::LeftHalfOpen {
+    ///         bound_pair: BoundPair::new(0, 8).ok_or("invalid BoundPair")?
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn saturating_expand(&self, delta: T) -> Interval<T>
+    where
+        T: num_traits::SaturatingAdd,
+        T: num_traits::SaturatingSub,
+    {
+        Interval::from_bounds(
+            self.left_bound().map(|value| value.saturating_sub(&delta)),
+            self.right_bound().map(|value| value.saturating_add(&delta)),
+        )
+    }
+
+    /// The Jaccard index of self and the specified Interval
+    ///
+    /// The width of the intersection over the width of the union, from 0.0
+    /// for operands sharing no width to 1.0 for equal operands, as used to
+    /// score detected ranges against ground truth.  The union of disjoint
+    /// operands is as wide as both together.  None if either operand is
+    /// unbounded or Empty, or the union has zero width (e.g. Singletons).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let truth = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(10, 20).ok_or("invalid BoundPair")?,
+    /// };
+    /// let detected = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(15, 25).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(truth.jaccard_index(&detected), Some(5.0 / 15.0));
+    /// assert_eq!(truth.jaccard_index(&truth), Some(1.0));
+    /// assert_eq!(truth.jaccard_index(&(30..40)), Some(0.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn jaccard_index<I, W>(&self, other: &I) -> Option<f64>
+    where
+        I: IntervalLike<T> + ?Sized,
+        T: std::ops::Sub<Output = W>,
+        W: num_traits::ToPrimitive,
+    {
+        let other = other.to_interval();
+        let self_width = self.width()?.to_f64()?;
+        let other_width = other.width()?.to_f64()?;
+        let union = match self.merge(&other) {
+            Some(hull) => hull.width()?.to_f64()?,
+            None => self_width + other_width,
+        };
+        let intersection = match self.overlap_width(&other) {
+            Some(width) => width.to_f64()?,
+            None => 0.0,
+        };
+        if union > 0.0 {
+            Some(intersection / union)
+        } else {
+            None
+        }
+    }
+
+    /// The weighted width of self covered by each of the weighted Intervals
+    ///
+    /// Sums, over every member, the width of its overlap with self (the
+    /// probe) times its weight, e.g. the revenue-weighted downtime within a
+    /// reporting window.  Weights add where members overlap one another.
+    /// None if an overlap is unbounded, or a width is not representable as
+    /// f64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let outage = |start, end| -> Result<Interval<i64>, String> {
+    ///     Ok(Interval::RightHalfOpen {
+    ///         bound_pair: BoundPair::new(start, end).ok_or("invalid BoundPair")?,
+    ///     })
+    /// };
+    /// // Outages with their revenue per minute
+    /// let outages = [(outage(0, 30)?, 2.0), (outage(50, 70)?, 10.0)];
+    /// let month = outage(10, 60)?;
+    /// assert_eq!(month.weighted_overlap(&outages), Some(20.0 * 2.0 + 10.0 * 10.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn weighted_overlap<W>(&self, weighted: &[(Interval<T>, f64)]) -> Option<f64>
+    where
+        T: std::ops::Sub<Output = W>,
+        W: num_traits::ToPrimitive,
+    {
+        weighted
+            .iter()
+            .filter(|(member, _)| self.overlaps(member))
+            .map(|(member, weight)| Some(self.overlap_width(member)?.to_f64()? * weight))
+            .sum()
+    }
+}
+
+/// Treatment of the trailing chunk produced by [Interval::chunks]
+///
+/// The trailing chunk is narrower than the requested width whenever the
+/// interval width is not a multiple of the chunk width.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RemainderPolicy {
+    /// Yield the narrower trailing chunk, ending at the right bound of self
+    Keep,
+    /// Omit the narrower trailing chunk
+    Drop,
+    /// Grow the trailing chunk to full width, beyond the right bound of self
+    Extend,
+}
+
+/// The bound(s) held fixed by [Interval::padded_to_min_width]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PadAnchor {
+    /// Hold the left bound, extending the right bound
+    Left,
+    /// Extend both bounds equally about the midpoint
+    Center,
+    /// Hold the right bound, extending the left bound
+    Right,
+}
+
+/// The part keeping the cut point in [Interval::split_at]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SplitSide {
+    /// The part below the cut point ends at it, closed
+    Left,
+    /// The part above the cut point starts at it, closed
+    Right,
+}
+
+/// The openness of the bounds of a finite Interval
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Openness {
+    /// Both bounds included, `[a..b]`
+    Closed,
+    /// Both bounds excluded, `(a..b)`
+    Open,
+    /// The left bound excluded, `(a..b]`
+    LeftHalfOpen,
+    /// The right bound excluded, `[a..b)`
+    RightHalfOpen,
+}
+
+impl Openness {
+    // The left and right Bounds of this openness at the given values
+    pub(crate) fn bounds<T>(self, left: T, right: T) -> (Bound<T>, Bound<T>) {
+        match self {
+            Openness::Closed => (Bound::Closed(left), Bound::Closed(right)),
+            Openness::Open => (Bound::Open(left), Bound::Open(right)),
+            Openness::LeftHalfOpen => (Bound::Open(left), Bound::Closed(right)),
+            Openness::RightHalfOpen => (Bound::Closed(left), Bound::Open(right)),
+        }
+    }
+}
+
+/// The side and openness of an endpoint yielded by [Interval::endpoints]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EndpointKind {
+    /// A closed left bound, `[a`
+    LeftClosed,
+    /// An open left bound, `(a`
+    LeftOpen,
+    /// A closed right bound, `b]`
+    RightClosed,
+    /// An open right bound, `b)`
+    RightOpen,
+}
+
+impl EndpointKind {
+    /// Verify whether the endpoint is a left bound
+    pub fn is_left(&self) -> bool {
+        matches!(self, EndpointKind::LeftClosed | EndpointKind::LeftOpen)
+    }
+
+    /// Verify whether the endpoint value is included in the interval
+    pub fn is_closed(&self) -> bool {
+        matches!(self, EndpointKind::LeftClosed | EndpointKind::RightClosed)
+    }
+}
+
+/// The shape of an Interval, i.e. its variant without bound values
+///
+/// Returned by [Interval::classify].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IntervalClass {
+    /// `[a..b]`
+    Closed,
+    /// `(a..b)`
+    Open,
+    /// `(a..b]`
+    LeftHalfOpen,
+    /// `[a..b)`
+    RightHalfOpen,
+    /// `(←..b]`
+    UnboundedClosedRight,
+    /// `(←..b)`
+    UnboundedOpenRight,
+    /// `[a..→)`
+    UnboundedClosedLeft,
+    /// `(a..→)`
+    UnboundedOpenLeft,
+    /// `[a]`
+    Singleton,
+    /// `(←..→)`
+    Unbounded,
+    /// `Empty`
+    Empty,
+}
+
+/// Three-valued result of [Interval::contains_point_tristate]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// The value lies within the interval
+    Yes,
+    /// The value lies outside the interval
+    No,
+    /// The value or a bound is incomparable (e.g. NaN)
+    Unknown,
+}
+
+/// The operand(s) contributing a bound of an [IntersectInfo] intersection
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BoundSource {
+    /// The bound was taken from self
+    SelfOperand,
+    /// The bound was taken from the other operand
+    Other,
+    /// Both operands share the bound, value and openness alike
+    Both,
+}
+
+/// How two operands of [Interval::intersect_info] relate
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IntersectRelation {
+    /// The operands share no points and leave a gap between them
+    Disjoint,
+    /// The operands share no points but meet with no gap between them
+    Adjacent,
+    /// The operands share points, but neither contains the other
+    Overlapping,
+    /// Self lies within (and differs from) the other operand
+    SelfWithinOther,
+    /// The other operand lies within (and differs from) self
+    OtherWithinSelf,
+    /// The operands are the same interval
+    Equal,
+}
+
+/// The thirteen relations of Allen's interval algebra, from [Interval::relate]
+///
+/// Each variant describes self relative to the other operand, and has an
+/// inverse describing the other operand relative to self (see
+/// [AllenRelation::inverse]).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AllenRelation {
+    /// Self lies before other, with a gap between them
+    Precedes,
+    /// Self lies before other, with no gap between them
+    Meets,
+    /// Self begins before other, and they share points
+    Overlaps,
+    /// Self begins before other, and they end together
+    FinishedBy,
+    /// Self begins before and ends after other
+    Contains,
+    /// Self begins with other and ends before it
+    Starts,
+    /// Self and other are the same interval
+    Equals,
+    /// Self begins with other and ends after it
+    StartedBy,
+    /// Self begins after and ends before other
+    During,
+    /// Self begins after other, and they end together
+    Finishes,
+    /// Self ends after other, and they share points
+    OverlappedBy,
+    /// Self lies after other, with no gap between them
+    MetBy,
+    /// Self lies after other, with a gap between them
+    PrecededBy,
+}
+
+impl AllenRelation {
+    /// The relation of the other operand to self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::AllenRelation;
+    /// assert_eq!(AllenRelation::During.inverse(), AllenRelation::Contains);
+    /// assert_eq!(AllenRelation::Equals.inverse(), AllenRelation::Equals);
+    /// ```
+    pub fn inverse(&self) -> AllenRelation {
+        match self {
+            AllenRelation::Precedes => AllenRelation::PrecededBy,
+            AllenRelation::Meets => AllenRelation::MetBy,
+            AllenRelation::Overlaps => AllenRelation::OverlappedBy,
+            AllenRelation::FinishedBy => AllenRelation::Finishes,
+            AllenRelation::Contains => AllenRelation::During,
+            AllenRelation::Starts => AllenRelation::StartedBy,
+            AllenRelation::Equals => AllenRelation::Equals,
+            AllenRelation::StartedBy => AllenRelation::Starts,
+            AllenRelation::During => AllenRelation::Contains,
+            AllenRelation::Finishes => AllenRelation::FinishedBy,
+            AllenRelation::OverlappedBy => AllenRelation::Overlaps,
+            AllenRelation::MetBy => AllenRelation::Meets,
+            AllenRelation::PrecededBy => AllenRelation::Precedes,
+        }
+    }
+}
+
+/// A connected component of Intervals, from [Interval::connected_components]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Component<T> {
+    /// The union of the members
+    pub hull: Interval<T>,
+    /// The indices of the members, in ascending order
+    pub members: Vec<usize>,
+}
+
+/// The intersection of two Intervals along with its provenance
+///
+/// Constructed by [Interval::intersect_info].  The bound sources are None
+/// when the intersection is Empty.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IntersectInfo<T> {
+    /// The intersection, as yielded by [Interval::intersect]
+    pub intersection: Interval<T>,
+    /// The operand(s) contributing the left bound of the intersection
+    pub left_source: Option<BoundSource>,
+    /// The operand(s) contributing the right bound of the intersection
+    pub right_source: Option<BoundSource>,
+    /// How the operands relate
+    pub relation: IntersectRelation,
+}
+
+impl<T> IntersectInfo<T> {
+    // The info for operands with an Empty intersection
+    fn disjoint(relation: IntersectRelation) -> IntersectInfo<T> {
+        IntersectInfo {
+            intersection: Interval::Empty,
+            left_source: None,
+            right_source: None,
+            relation,
+        }
+    }
+}
+
+/// The parts of an Interval cut away by [Interval::fit_within]
+///
+/// Each part is Empty when nothing was cut from that side.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Overflow<T> {
+    /// The part lying below the container
+    pub left: Interval<T>,
+    /// The part lying above the container
+    pub right: Interval<T>,
+}
+
+/// The zero to two finite endpoints of an Interval
+///
+/// Constructed by [Interval::finite_endpoints].  Iterating yields the same
+/// `(value, EndpointKind)` pairs as [Interval::endpoints].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FiniteEndpoints<T> {
+    /// The left endpoint value and whether it is closed, if finite
+    pub left: Option<(T, bool)>,
+    /// The right endpoint value and whether it is closed, if finite
+    pub right: Option<(T, bool)>,
+}
+
+impl<T> FiniteEndpoints<T> {
+    /// The number of finite endpoints
+    pub fn len(&self) -> usize {
+        usize::from(self.left.is_some()) + usize::from(self.right.is_some())
+    }
+
+    /// Verify whether there are no finite endpoints
+    pub fn is_empty(&self) -> bool {
+        self.left.is_none() && self.right.is_none()
+    }
+}
+
+impl<T> IntoIterator for FiniteEndpoints<T> {
+    type Item = (T, EndpointKind);
+    type IntoIter = Endpoints<T>;
+
+    fn into_iter(self) -> Endpoints<T> {
+        let kind = |closed, closed_kind, open_kind| if closed { closed_kind } else { open_kind };
+        let left = self.left.map(|(value, closed)| {
+            (
+                value,
+                kind(closed, EndpointKind::LeftClosed, EndpointKind::LeftOpen),
+            )
+        });
+        let right = self.right.map(|(value, closed)| {
+            (
+                value,
+                kind(closed, EndpointKind::RightClosed, EndpointKind::RightOpen),
+            )
+        });
+        Endpoints {
+            inner: [left, right].into_iter().flatten(),
+        }
+    }
+}
+
+/// Iterator over the finite endpoints of an Interval
+///
+/// Constructed by [Interval::endpoints].
+#[derive(Debug, Clone)]
+pub struct Endpoints<T> {
+    inner: std::iter::Flatten<std::array::IntoIter<Option<(T, EndpointKind)>, 2>>,
+}
+
+impl<T> Iterator for Endpoints<T> {
+    type Item = (T, EndpointKind);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Endpoints<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> std::iter::FusedIterator for Endpoints<T> {}
+
+/// Iterator over the Intervals of a set difference
+///
+/// Constructed by [Interval::difference].
+#[derive(Debug, Clone)]
+pub struct Difference<T> {
+    inner: std::iter::Flatten<std::array::IntoIter<Option<Interval<T>>, 2>>,
+}
+
+impl<T> Iterator for Difference<T> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Difference<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> std::iter::FusedIterator for Difference<T> {}
+
+/// Iterator over the fixed width chunks of an Interval
+///
+/// Constructed by [Interval::chunks].
+#[derive(Debug, Clone)]
+pub struct Chunks<T, W> {
+    // Left bound of the next chunk, Bound::None once exhausted
+    next_left: Bound<T>,
+    right: Bound<T>,
+    width: W,
+    remainder: RemainderPolicy,
+}
+
+impl<T, W> Iterator for Chunks<T, W>
+where
+    T: Copy,
+    T: std::cmp::PartialOrd,
+    T: std::ops::Add<W, Output = T>,
+    W: Copy,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Interval<T>> {
+        let left_bound = self.next_left;
+        let left = match left_bound {
+            Bound::Closed(left) | Bound::Open(left) => left,
+            _ => return None,
+        };
+        self.next_left = Bound::None;
+
+        let end = left + self.width;
+        // Guard against non-positive widths, which would never advance
+        if end.partial_cmp(&left) != Some(Ordering::Greater) {
+            return None;
+        }
+        let end_cmp = match self.right {
+            Bound::Closed(right) | Bound::Open(right) => end.partial_cmp(&right),
+            Bound::Unbounded => Some(Ordering::Less),
+            Bound::None => None,
+        };
+
+        match end_cmp {
+            Some(Ordering::Less) => {
+                self.next_left = Bound::Closed(end);
+                Some(Interval::from_bounds(left_bound, Bound::Open(end)))
+            }
+            Some(Ordering::Equal) => Some(Interval::from_bounds(left_bound, self.right)),
+            Some(Ordering::Greater) => match self.remainder {
+                RemainderPolicy::Keep => Some(Interval::from_bounds(left_bound, self.right)),
+                RemainderPolicy::Drop => None,
+                RemainderPolicy::Extend => {
+                    Some(Interval::from_bounds(left_bound, Bound::Open(end)))
+                }
+            },
+            None => None,
+        }
+    }
+}
+
+impl<T> Interval<T> {
+    /// Verify whether self is the Empty interval
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Interval::Empty)
+    }
+
+    /// Verify whether self has finite bounds on both sides
+    ///
+    /// True for the BoundPair variants and Singleton, and vacuously for
+    /// Empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    ///
+    /// assert!(Interval::Singleton { at: 1 }.is_bounded());
+    /// assert!(Interval::<i32>::Empty.is_bounded());
+    /// assert!(Interval::UnboundedOpenLeft { left: 1 }.is_unbounded());
+    /// assert!(Interval::<i32>::Unbounded.is_unbounded());
+    /// ```
+    pub fn is_bounded(&self) -> bool {
+        match self {
+            Interval::Closed { .. }
+            | Interval::Open { .. }
+            | Interval::LeftHalfOpen { .. }
+            | Interval::RightHalfOpen { .. }
+            | Interval::Singleton { .. }
+            | Interval::Empty => true,
+            Interval::UnboundedClosedRight { .. }
+            | Interval::UnboundedOpenRight { .. }
+            | Interval::UnboundedClosedLeft { .. }
+            | Interval::UnboundedOpenLeft { .. }
+            | Interval::Unbounded => false,
+        }
+    }
+
+    /// Verify whether self is unbounded on at least one side
+    pub fn is_unbounded(&self) -> bool {
+        !self.is_bounded()
+    }
+
+    /// Verify whether self contains at most one point (Singleton or Empty)
+    pub fn is_degenerate(&self) -> bool {
+        matches!(self, Interval::Singleton { .. } | Interval::Empty)
+    }
+
+    /// Verify whether self has a closed left bound
+    ///
+    /// False for left-unbounded intervals, which have no left endpoint, and
+    /// for Empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let interval = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert!(interval.is_left_closed());
+    /// assert!(!interval.is_right_closed());
+    /// assert!(!interval.is_closed() && !interval.is_open());
+    /// assert!(!Interval::UnboundedClosedRight { right: 5 }.is_left_closed());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_left_closed(&self) -> bool {
+        matches!(
+            self,
+            Interval::Closed { .. }
+                | Interval::RightHalfOpen { .. }
+                | Interval::UnboundedClosedLeft { .. }
+                | Interval::Singleton { .. }
+        )
+    }
+
+    /// Verify whether self has a closed right bound
+    ///
+    /// False for right-unbounded intervals, which have no right endpoint, and
+    /// for Empty.
+    pub fn is_right_closed(&self) -> bool {
+        matches!(
+            self,
+            Interval::Closed { .. }
+                | Interval::LeftHalfOpen { .. }
+                | Interval::UnboundedClosedRight { .. }
+                | Interval::Singleton { .. }
+        )
+    }
+
+    /// Verify whether self contains all of its endpoints (a closed set)
+    ///
+    /// True for Closed, Singleton and the closed rays, and also for Unbounded
+    /// and Empty, which have no endpoints.
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self,
+            Interval::Closed { .. }
+                | Interval::UnboundedClosedRight { .. }
+                | Interval::UnboundedClosedLeft { .. }
+                | Interval::Singleton { .. }
+                | Interval::Unbounded
+                | Interval::Empty
+        )
+    }
+
+    /// Verify whether self contains none of its endpoints (an open set)
+    ///
+    /// True for Open and the open rays, and also for Unbounded and Empty,
+    /// which have no endpoints.
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self,
+            Interval::Open { .. }
+                | Interval::UnboundedOpenRight { .. }
+                | Interval::UnboundedOpenLeft { .. }
+                | Interval::Unbounded
+                | Interval::Empty
+        )
+    }
+
+    /// The variant of self, without its bound values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::{Interval, IntervalClass};
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let a = Interval::LeftHalfOpen {
+    ///     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+    /// };
+    /// let b = Interval::LeftHalfOpen {
+    ///     bound_pair: BoundPair::new(-3, 0).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(a.classify(), IntervalClass::LeftHalfOpen);
+    /// assert_eq!(a.classify(), b.classify());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn classify(&self) -> IntervalClass {
+        match self {
+            Interval::Closed { .. } => IntervalClass::Closed,
+            Interval::Open { .. } => IntervalClass::Open,
+            Interval::LeftHalfOpen { .. } => IntervalClass::LeftHalfOpen,
+            Interval::RightHalfOpen { .. } => IntervalClass::RightHalfOpen,
+            Interval::UnboundedClosedRight { .. } => IntervalClass::UnboundedClosedRight,
+            Interval::UnboundedOpenRight { .. } => IntervalClass::UnboundedOpenRight,
+            Interval::UnboundedClosedLeft { .. } => IntervalClass::UnboundedClosedLeft,
+            Interval::UnboundedOpenLeft { .. } => IntervalClass::UnboundedOpenLeft,
+            Interval::Singleton { .. } => IntervalClass::Singleton,
+            Interval::Unbounded => IntervalClass::Unbounded,
+            Interval::Empty => IntervalClass::Empty,
+        }
+    }
+
+    /// Borrow the bounds of an Interval over owning bound types
+    ///
+    /// Interval operations require Copy bounds, which owning types such as
+    /// `Vec<u8>` or `String` are not.  In the manner of `Option::as_deref`,
+    /// this produces an Interval over borrowed (and Copy) bounds, e.g.
+    /// `Interval<&[u8]>` from `Interval<Vec<u8>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// let owned = Interval::UnboundedClosedLeft {
+    ///     left: String::from("m"),
+    /// };
+    /// let borrowed: Interval<&str> = owned.as_deref();
+    /// assert!(borrowed.contains(&Interval::Singleton { at: "pear" }));
+    /// assert!(!borrowed.contains(&Interval::Singleton { at: "apple" }));
+    /// ```
+    pub fn as_deref(&self) -> Interval<&T::Target>
+    where
+        T: std::ops::Deref,
+    {
+        match self {
+            Interval::Closed { bound_pair } => Interval::Closed {
+                bound_pair: bound_pair.as_deref(),
+            },
+            Interval::Open { bound_pair } => Interval::Open {
+                bound_pair: bound_pair.as_deref(),
+            },
+            Interval::LeftHalfOpen { bound_pair } => Interval::LeftHalfOpen {
+                bound_pair: bound_pair.as_deref(),
+            },
+            Interval::RightHalfOpen { bound_pair } => Interval::RightHalfOpen {
+                bound_pair: bound_pair.as_deref(),
+            },
+            Interval::UnboundedClosedRight { right } => Interval::UnboundedClosedRight {
+                right: right.deref(),
+            },
+            Interval::UnboundedOpenRight { right } => Interval::UnboundedOpenRight {
+                right: right.deref(),
+            },
+            Interval::UnboundedClosedLeft { left } => {
+                Interval::UnboundedClosedLeft { left: left.deref() }
+            }
+            Interval::UnboundedOpenLeft { left } => {
+                Interval::UnboundedOpenLeft { left: left.deref() }
+            }
+            Interval::Singleton { at } => Interval::Singleton { at: at.deref() },
+            Interval::Unbounded => Interval::Unbounded,
+            Interval::Empty => Interval::Empty,
+        }
+    }
+}
+
+/// Implement the Display trait for Intervals
+///
+/// Here I uses [Wirth Interval Notation](https://proofwiki.org/wiki/Mathematician:Niklaus_Emil_Wirth).
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::bound_pair::BoundPair;
+/// use intervals_general::interval::Interval;
+///
+/// # fn main() -> std::result::Result<(), String> {
+/// let bp = BoundPair::new(1, 5).ok_or("invalid BoundPair")?;
+///
+/// assert_eq!(format!("{}", Interval::Closed { bound_pair: bp }), "[1..5]");
+/// assert_eq!(
+///     format!("{}", Interval::UnboundedOpenRight { right: 5 }),
+///     "(←..5)"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+impl<T> std::fmt::Display for Interval<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Interval::Closed {
+                bound_pair:
+                    BoundPair {
+                        ref left,
+                        ref right,
+                    },
+            } => write!(f, "[{:?}..{:?}]", left, right),
+            Interval::Open {
+                bound_pair:
+                    BoundPair {
+                        ref left,
+                        ref right,
+                    },
+            } => write!(f, "({:?}..{:?})", left, right),
+            Interval::LeftHalfOpen {
+                bound_pair:
+                    BoundPair {
+                        ref left,
+                        ref right,
+                    },
+            } => write!(f, "({:?}..{:?}]", left, right),
             Interval::RightHalfOpen {
                 bound_pair:
                     BoundPair {
@@ -701,397 +3588,2015 @@ where
             Interval::Empty => write!(f, "Empty"),
         }
     }
-}
+}
+
+/// Implement the FromStr trait for Intervals, parsing the Display notation
+///
+/// Bound values are parsed with the FromStr implementation of T, so parsing
+/// inverts Display for bound types whose Debug output is parseable (e.g. the
+/// primitive numeric types).  Whitespace around bound values is ignored.
+/// Malformed notation yields [IntervalError::MalformedEncoding], and bounds
+/// violating the BoundPair invariant yield the corresponding error.
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::bound_pair::BoundPair;
+/// use intervals_general::interval::Interval;
+///
+/// # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(
+///     "[1..5)".parse::<Interval<i32>>()?,
+///     Interval::RightHalfOpen {
+///         bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?
+///     }
+/// );
+/// assert_eq!(
+///     "(←..2.5]".parse::<Interval<f64>>()?,
+///     Interval::UnboundedClosedRight { right: 2.5 }
+/// );
+/// assert!("[5..1]".parse::<Interval<i32>>().is_err());
+/// # Ok(())
+/// # }
+/// ```
+impl<T> std::str::FromStr for Interval<T>
+where
+    T: std::str::FromStr,
+    T: Copy,
+    T: PartialOrd,
+{
+    type Err = IntervalError;
+
+    fn from_str(s: &str) -> Result<Interval<T>, IntervalError> {
+        let s = s.trim();
+        if s == "Empty" {
+            return Ok(Interval::Empty);
+        }
+        let value = |text: &str| {
+            text.trim()
+                .parse::<T>()
+                .map_err(|_| IntervalError::MalformedEncoding)
+        };
+
+        let mut chars = s.chars();
+        let (open, close) = (chars.next(), chars.next_back());
+        let inner = chars.as_str();
+        let Some((left, right)) = inner.split_once("..") else {
+            return match (open, close) {
+                (Some('['), Some(']')) => Ok(Interval::Singleton { at: value(inner)? }),
+                _ => Err(IntervalError::MalformedEncoding),
+            };
+        };
+
+        let left_bound = match (open, left.trim()) {
+            (Some('('), "←") => Bound::Unbounded,
+            (Some('('), left) => Bound::Open(value(left)?),
+            (Some('['), left) => Bound::Closed(value(left)?),
+            _ => return Err(IntervalError::MalformedEncoding),
+        };
+        let right_bound = match (close, right.trim()) {
+            (Some(')'), "→") => Bound::Unbounded,
+            (Some(')'), right) => Bound::Open(value(right)?),
+            (Some(']'), right) => Bound::Closed(value(right)?),
+            _ => return Err(IntervalError::MalformedEncoding),
+        };
+        if let (
+            Bound::Open(left) | Bound::Closed(left),
+            Bound::Open(right) | Bound::Closed(right),
+        ) = (left_bound, right_bound)
+        {
+            BoundPair::try_new(left, right)?;
+        }
+        Ok(Interval::from_bounds(left_bound, right_bound))
+    }
+}
+
+/// Reflect an Interval through zero, see [Interval::reflect]
+///
+/// # Examples
+///
+/// ```
+/// use intervals_general::interval::Interval;
+///
+/// assert_eq!(
+///     -Interval::UnboundedClosedRight { right: 1.5 },
+///     Interval::UnboundedClosedLeft { left: -1.5 }
+/// );
+/// ```
+impl<T> std::ops::Neg for Interval<T>
+where
+    T: std::ops::Neg<Output = T>,
+    T: Copy,
+    T: PartialOrd,
+{
+    type Output = Interval<T>;
+
+    fn neg(self) -> Interval<T> {
+        self.reflect()
+    }
+}
+
+/// Generate Intervals of every variant from fuzzer input
+///
+/// A variant is drawn uniformly, followed by its bounds; bounded variants
+/// maintain the BoundPair invariant (see the BoundPair implementation).
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for Interval<T>
+where
+    T: arbitrary::Arbitrary<'a>,
+    T: Copy,
+    T: PartialOrd,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // A bounded variant, or the Singleton for equal values (such as
+        // exhausted input yields), rather than rejecting the input
+        let bounded = |u: &mut arbitrary::Unstructured<'a>,
+                       variant: fn(BoundPair<T>) -> Interval<T>| {
+            let (a, b): (T, T) = (u.arbitrary()?, u.arbitrary()?);
+            match a.partial_cmp(&b) {
+                Some(Ordering::Less) => Ok(variant(BoundPair { left: a, right: b })),
+                Some(Ordering::Greater) => Ok(variant(BoundPair { left: b, right: a })),
+                Some(Ordering::Equal) => Ok(Interval::Singleton { at: a }),
+                None => Err(arbitrary::Error::IncorrectFormat),
+            }
+        };
+        Ok(match u.int_in_range(0..=10u8)? {
+            0 => bounded(u, |bound_pair| Interval::Closed { bound_pair })?,
+            1 => bounded(u, |bound_pair| Interval::Open { bound_pair })?,
+            2 => bounded(u, |bound_pair| Interval::LeftHalfOpen { bound_pair })?,
+            3 => bounded(u, |bound_pair| Interval::RightHalfOpen { bound_pair })?,
+            4 => Interval::UnboundedClosedRight {
+                right: u.arbitrary()?,
+            },
+            5 => Interval::UnboundedOpenRight {
+                right: u.arbitrary()?,
+            },
+            6 => Interval::UnboundedClosedLeft {
+                left: u.arbitrary()?,
+            },
+            7 => Interval::UnboundedOpenLeft {
+                left: u.arbitrary()?,
+            },
+            8 => Interval::Singleton { at: u.arbitrary()? },
+            9 => Interval::Unbounded,
+            _ => Interval::Empty,
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(
+            <u8 as arbitrary::Arbitrary>::size_hint(depth),
+            arbitrary::size_hint::or(
+                <BoundPair<T> as arbitrary::Arbitrary>::size_hint(depth),
+                (0, Some(0)),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod bound_tests {
+    use super::*;
+
+    #[test]
+    fn test_left_bound() {
+        // Test bounded intervals
+        let bp = BoundPair::new(1, 5).unwrap();
+
+        // Closed interval should have closed left bound
+        assert!(matches!(
+            Interval::Closed { bound_pair: bp }.left_bound(),
+            Bound::Closed(1)
+        ));
+
+        // Open interval should have open left bound
+        assert!(matches!(
+            Interval::Open { bound_pair: bp }.left_bound(),
+            Bound::Open(1)
+        ));
+
+        // Test unbounded intervals
+        assert!(matches!(
+            Interval::Unbounded::<i32>.left_bound(),
+            Bound::Unbounded
+        ));
+
+        // Test empty interval
+        assert!(matches!(Interval::Empty::<i32>.left_bound(), Bound::None));
+
+        // Test singleton
+        assert!(matches!(
+            Interval::Singleton { at: 3 }.left_bound(),
+            Bound::Closed(3)
+        ));
+
+        // Test half-open intervals
+        assert!(matches!(
+            Interval::LeftHalfOpen { bound_pair: bp }.left_bound(),
+            Bound::Open(1)
+        ));
+        assert!(matches!(
+            Interval::RightHalfOpen { bound_pair: bp }.left_bound(),
+            Bound::Closed(1)
+        ));
+    }
+
+    #[test]
+    fn test_right_bound() {
+        let bp = BoundPair::new(1, 5).unwrap();
+
+        // Test bounded intervals
+        assert!(matches!(
+            Interval::Closed { bound_pair: bp }.right_bound(),
+            Bound::Closed(5)
+        ));
+        assert!(matches!(
+            Interval::Open { bound_pair: bp }.right_bound(),
+            Bound::Open(5)
+        ));
+
+        // Test special cases
+        assert!(matches!(
+            Interval::Unbounded::<i32>.right_bound(),
+            Bound::Unbounded
+        ));
+        assert!(matches!(Interval::Empty::<i32>.right_bound(), Bound::None));
+        assert!(matches!(
+            Interval::Singleton { at: 3 }.right_bound(),
+            Bound::Closed(3)
+        ));
+
+        // Test unbounded variants
+        assert!(matches!(
+            Interval::UnboundedClosedLeft { left: 1 }.right_bound(),
+            Bound::Unbounded
+        ));
+        assert!(matches!(
+            Interval::UnboundedOpenLeft { left: 1 }.right_bound(),
+            Bound::Unbounded
+        ));
+
+        // Test half-open intervals
+        assert!(matches!(
+            Interval::LeftHalfOpen { bound_pair: bp }.right_bound(),
+            Bound::Closed(5)
+        ));
+        assert!(matches!(
+            Interval::RightHalfOpen { bound_pair: bp }.right_bound(),
+            Bound::Open(5)
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use super::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary_covers_all_variants() {
+        let mut seen = std::collections::HashSet::new();
+        let data: Vec<u8> = (0..4096u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect();
+        let mut u = Unstructured::new(&data);
+        while let Ok(interval) = Interval::<i16>::arbitrary(&mut u) {
+            if let Interval::Closed { bound_pair }
+            | Interval::Open { bound_pair }
+            | Interval::LeftHalfOpen { bound_pair }
+            | Interval::RightHalfOpen { bound_pair } = interval
+            {
+                assert!(bound_pair.left() < bound_pair.right());
+            }
+            seen.insert(std::mem::discriminant(&interval));
+            if u.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(seen.len(), crate::generators::VARIANT_COUNT);
+    }
+
+    #[test]
+    fn test_arbitrary_exhausted_input() {
+        for variant in 0..=10u8 {
+            let data = [variant];
+            let mut u = Unstructured::new(&data);
+            assert!(Interval::<u32>::arbitrary(&mut u).is_ok(), "{}", variant);
+        }
+        let mut u = Unstructured::new(&[0]);
+        assert_eq!(
+            Interval::<u32>::arbitrary(&mut u),
+            Ok(Interval::Singleton { at: 0 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod comparison_tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_left_partial_cmp_basic() {
+        let i1 = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let i2 = Interval::Closed {
+            bound_pair: BoundPair::new(2, 6).unwrap(),
+        };
+        assert_eq!(i1.left_partial_cmp(&i2), Some(Ordering::Less));
+        assert_eq!(i2.left_partial_cmp(&i1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_left_partial_cmp_equal_bounds() {
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        // Open bound is considered "greater" than closed bound at same value
+        assert_eq!(closed.left_partial_cmp(&open), Some(Ordering::Less));
+        assert_eq!(open.left_partial_cmp(&closed), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_left_partial_cmp_unbounded() {
+        let finite = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let unbounded = Interval::Unbounded;
+        assert_eq!(finite.left_partial_cmp(&unbounded), Some(Ordering::Greater));
+        assert_eq!(unbounded.left_partial_cmp(&finite), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_left_partial_cmp_empty() {
+        let interval = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let empty = Interval::Empty;
+        assert_eq!(interval.left_partial_cmp(&empty), None);
+        assert_eq!(empty.left_partial_cmp(&interval), None);
+    }
+
+    #[test]
+    fn test_right_partial_cmp_basic() {
+        let i1 = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let i2 = Interval::Closed {
+            bound_pair: BoundPair::new(2, 6).unwrap(),
+        };
+        assert_eq!(i1.right_partial_cmp(&i2), Some(Ordering::Less));
+        assert_eq!(i2.right_partial_cmp(&i1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_right_partial_cmp_mixed_bounds() {
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let half_open = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        // Open bound is considered "less" than closed bound at same value
+        assert_eq!(
+            closed.right_partial_cmp(&half_open),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(half_open.right_partial_cmp(&closed), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_right_partial_cmp_unbounded() {
+        let finite = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let unbounded_left = Interval::UnboundedClosedLeft { left: 1 };
+        assert_eq!(
+            finite.right_partial_cmp(&unbounded_left),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            unbounded_left.right_partial_cmp(&finite),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn test_right_partial_cmp_empty() {
+        let interval = Interval::Closed {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        let empty = Interval::Empty;
+        assert_eq!(interval.right_partial_cmp(&empty), None);
+        assert_eq!(empty.right_partial_cmp(&interval), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bound_pair::BoundPair;
+    use crate::error::IntervalError;
+    use crate::interval::Bound;
+    use crate::interval::Interval;
+    use crate::interval::PadAnchor;
+    use crate::interval::RemainderPolicy;
+    use crate::interval::{
+        AllenRelation, BoundSource, Component, Containment, EndpointKind, IntersectRelation,
+        IntervalClass, Openness, SplitSide,
+    };
+    use itertools::Either;
+    use quickcheck::Arbitrary;
+    use quickcheck::Gen;
+    use quickcheck::TestResult;
+    use quickcheck_macros::quickcheck;
+    use std::cmp::Ordering;
+
+    impl<T> Arbitrary for Interval<T>
+    where
+        T: Arbitrary + Copy + Clone + PartialOrd + Send + 'static,
+    {
+        fn arbitrary(g: &mut Gen) -> Interval<T> {
+            const VARIANT_COUNT: usize = 12;
+            let variant_idx = g.size() % VARIANT_COUNT;
+
+            match variant_idx {
+                0 => {
+                    let bound_pair = loop {
+                        let left = T::arbitrary(g);
+                        let right = T::arbitrary(g);
+                        if let Some(bp) = BoundPair::new(left, right) {
+                            break bp;
+                        }
+                    };
+                    Interval::Closed { bound_pair }
+                }
+                1 => {
+                    let bound_pair = loop {
+                        let left = T::arbitrary(g);
+                        let right = T::arbitrary(g);
+                        if let Some(bp) = BoundPair::new(left, right) {
+                            break bp;
+                        }
+                    };
+                    Interval::Open { bound_pair }
+                }
+                2 => {
+                    let bound_pair = loop {
+                        let left = T::arbitrary(g);
+                        let right = T::arbitrary(g);
+                        if let Some(bp) = BoundPair::new(left, right) {
+                            break bp;
+                        }
+                    };
+                    Interval::LeftHalfOpen { bound_pair }
+                }
+                3 => {
+                    let bound_pair = loop {
+                        let left = T::arbitrary(g);
+                        let right = T::arbitrary(g);
+                        if let Some(bp) = BoundPair::new(left, right) {
+                            break bp;
+                        }
+                    };
+                    Interval::LeftHalfOpen { bound_pair }
+                }
+                4 => {
+                    let bound_pair = loop {
+                        let left = T::arbitrary(g);
+                        let right = T::arbitrary(g);
+                        if let Some(bp) = BoundPair::new(left, right) {
+                            break bp;
+                        }
+                    };
+                    Interval::RightHalfOpen { bound_pair }
+                }
+                5 => Interval::UnboundedClosedRight {
+                    right: T::arbitrary(g),
+                },
+                6 => Interval::UnboundedOpenRight {
+                    right: T::arbitrary(g),
+                },
+                7 => Interval::UnboundedClosedLeft {
+                    left: T::arbitrary(g),
+                },
+                8 => Interval::UnboundedOpenLeft {
+                    left: T::arbitrary(g),
+                },
+                9 => Interval::Singleton {
+                    at: T::arbitrary(g),
+                },
+                10 => Interval::Unbounded,
+                11 => Interval::Empty,
+                _ => unreachable!("variant_idx is always < VARIANT_COUNT"),
+            }
+        }
+
+        // Shrink toward simpler variants (Empty, then a Singleton), then toward
+        // smaller bounds, only ever yielding valid Intervals
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let empty = std::iter::once(Interval::Empty);
+            match *self {
+                Interval::Empty => quickcheck::empty_shrinker(),
+                Interval::Unbounded => Box::new(empty),
+                Interval::Singleton { at } => {
+                    Box::new(empty.chain(at.shrink().map(|at| Interval::Singleton { at })))
+                }
+                Interval::UnboundedClosedRight { right } => Box::new(
+                    empty
+                        .chain(std::iter::once(Interval::Singleton { at: right }))
+                        .chain(
+                            right
+                                .shrink()
+                                .map(|right| Interval::UnboundedClosedRight { right }),
+                        ),
+                ),
+                Interval::UnboundedOpenRight { right } => Box::new(
+                    empty
+                        .chain(std::iter::once(Interval::Singleton { at: right }))
+                        .chain(
+                            right
+                                .shrink()
+                                .map(|right| Interval::UnboundedOpenRight { right }),
+                        ),
+                ),
+                Interval::UnboundedClosedLeft { left } => Box::new(
+                    empty
+                        .chain(std::iter::once(Interval::Singleton { at: left }))
+                        .chain(
+                            left.shrink()
+                                .map(|left| Interval::UnboundedClosedLeft { left }),
+                        ),
+                ),
+                Interval::UnboundedOpenLeft { left } => Box::new(
+                    empty
+                        .chain(std::iter::once(Interval::Singleton { at: left }))
+                        .chain(
+                            left.shrink()
+                                .map(|left| Interval::UnboundedOpenLeft { left }),
+                        ),
+                ),
+                Interval::Closed { bound_pair }
+                | Interval::Open { bound_pair }
+                | Interval::LeftHalfOpen { bound_pair }
+                | Interval::RightHalfOpen { bound_pair } => {
+                    let rebuild: fn(BoundPair<T>) -> Interval<T> = match self {
+                        Interval::Closed { .. } => |bound_pair| Interval::Closed { bound_pair },
+                        Interval::Open { .. } => |bound_pair| Interval::Open { bound_pair },
+                        Interval::LeftHalfOpen { .. } => {
+                            |bound_pair| Interval::LeftHalfOpen { bound_pair }
+                        }
+                        _ => |bound_pair| Interval::RightHalfOpen { bound_pair },
+                    };
+                    let (left, right) = (*bound_pair.left(), *bound_pair.right());
+                    let shrunk_left = left
+                        .shrink()
+                        .filter_map(move |left| BoundPair::new(left, right));
+                    let shrunk_right = right
+                        .shrink()
+                        .filter_map(move |right| BoundPair::new(left, right));
+                    Box::new(
+                        empty
+                            .chain(std::iter::once(Interval::Singleton { at: left }))
+                            .chain(shrunk_left.chain(shrunk_right).map(rebuild)),
+                    )
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounded_complements() {
+        let bp = BoundPair::new(1, 5).unwrap();
+        let mut it = Interval::Closed { bound_pair: bp }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 1 }));
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 5 }));
+        assert_eq!(it.next(), None);
+
+        it = Interval::Open { bound_pair: bp }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedClosedRight { right: 1 }));
+        assert_eq!(it.next(), Some(Interval::UnboundedClosedLeft { left: 5 }));
+        assert_eq!(it.next(), None);
+
+        it = Interval::LeftHalfOpen { bound_pair: bp }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedClosedRight { right: 1 }));
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 5 }));
+        assert_eq!(it.next(), None);
+
+        it = Interval::RightHalfOpen { bound_pair: bp }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 1 }));
+        assert_eq!(it.next(), Some(Interval::UnboundedClosedLeft { left: 5 }));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_unbounded_complements() {
+        let mut it = Interval::UnboundedClosedRight { right: 5 }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 5 }));
+        assert_eq!(it.next(), None);
+
+        it = Interval::UnboundedOpenRight { right: 5 }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedClosedLeft { left: 5 }));
+        assert_eq!(it.next(), None);
+
+        it = Interval::UnboundedClosedLeft { left: 1 }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 1 }));
+        assert_eq!(it.next(), None);
+
+        it = Interval::UnboundedOpenLeft { left: 1 }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedClosedRight { right: 1 }));
+        assert_eq!(it.next(), None);
+
+        let mut it = Interval::Singleton { at: 2.0 }.complement();
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 2.0 }));
+        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 2.0 }));
+        assert_eq!(it.next(), None);
+
+        it = Interval::Unbounded.complement();
+        assert_eq!(it.next(), Some(Interval::Empty));
+        assert_eq!(it.next(), None);
+
+        it = Interval::Empty.complement();
+        assert_eq!(it.next(), Some(Interval::Unbounded));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_complement_into_matches_complement() {
+        let sentinel = Interval::Singleton { at: -1 };
+        for interval in crate::generators::all_variant_examples(1, 5).unwrap() {
+            let mut out = [sentinel; 2];
+            let count = interval.complement_into(&mut out);
+            let expected: Vec<_> = interval.complement().collect();
+            assert_eq!(count, expected.len());
+            assert_eq!(&out[..count], &expected[..]);
+            assert!(out[count..].iter().all(|i| *i == sentinel));
+        }
+    }
+
+    #[test]
+    fn interval_display() {
+        let bp = BoundPair::new(1, 5).ok_or("invalid BoundPair").unwrap();
+
+        assert_eq!(format!("{}", Interval::Closed { bound_pair: bp }), "[1..5]");
+        assert_eq!(format!("{}", Interval::Open { bound_pair: bp }), "(1..5)");
+        assert_eq!(
+            format!("{}", Interval::LeftHalfOpen { bound_pair: bp }),
+            "(1..5]"
+        );
+        assert_eq!(
+            format!("{}", Interval::RightHalfOpen { bound_pair: bp }),
+            "[1..5)"
+        );
+        assert_eq!(
+            format!("{}", Interval::UnboundedClosedRight { right: 5 }),
+            "(←..5]"
+        );
+        assert_eq!(
+            format!("{}", Interval::UnboundedOpenRight { right: 5 }),
+            "(←..5)"
+        );
+        assert_eq!(
+            format!("{}", Interval::UnboundedClosedLeft { left: 1 }),
+            "[1..→)"
+        );
+        assert_eq!(
+            format!("{}", Interval::UnboundedOpenLeft { left: 1 }),
+            "(1..→)"
+        );
+        assert_eq!(format!("{}", Interval::Singleton { at: 3.0 }), "[3.0]");
+        assert_eq!(format!("{}", Interval::Unbounded::<u32> {}), "(←..→)");
+        assert_eq!(format!("{}", Interval::Empty::<u32> {}), "Empty");
+    }
+
+    #[quickcheck]
+    fn parse_inverts_display_i32(i: Interval<i32>) -> bool {
+        i.to_string().parse::<Interval<i32>>() == Ok(i)
+    }
+
+    #[test]
+    fn test_intersect_info() {
+        let closed = |l, r| Interval::Closed {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
+        let outer = closed(0, 10);
+        let inner = Interval::Open {
+            bound_pair: BoundPair::new(0, 4).unwrap(),
+        };
+
+        let info = outer.intersect_info(&inner);
+        assert_eq!(info.intersection, inner);
+        assert_eq!(info.left_source, Some(BoundSource::Other));
+        assert_eq!(info.right_source, Some(BoundSource::Other));
+        assert_eq!(info.relation, IntersectRelation::OtherWithinSelf);
+
+        let info = inner.intersect_info(&outer);
+        assert_eq!(info.left_source, Some(BoundSource::SelfOperand));
+        assert_eq!(info.relation, IntersectRelation::SelfWithinOther);
+
+        let info = outer.intersect_info(&closed(0, 4));
+        assert_eq!(info.left_source, Some(BoundSource::Both));
+        assert_eq!(info.relation, IntersectRelation::OtherWithinSelf);
+
+        let info = outer.intersect_info(&outer);
+        assert_eq!(info.intersection, outer);
+        assert_eq!(info.relation, IntersectRelation::Equal);
+
+        let info = outer.intersect_info(&closed(10, 12));
+        assert_eq!(info.intersection, Interval::Singleton { at: 10 });
+        assert_eq!(info.left_source, Some(BoundSource::Other));
+        assert_eq!(info.right_source, Some(BoundSource::SelfOperand));
+        assert_eq!(info.relation, IntersectRelation::Overlapping);
+
+        let info = outer.intersect_info(&Interval::UnboundedOpenLeft { left: 10 });
+        assert_eq!(info.intersection, Interval::Empty);
+        assert_eq!(info.left_source, None);
+        assert_eq!(info.relation, IntersectRelation::Adjacent);
+        assert_eq!(
+            Interval::UnboundedOpenRight { right: 0 }
+                .intersect_info(&outer)
+                .relation,
+            IntersectRelation::Adjacent
+        );
+        assert_eq!(
+            inner
+                .intersect_info(&Interval::UnboundedOpenLeft { left: 4 })
+                .relation,
+            IntersectRelation::Disjoint
+        );
+        assert_eq!(
+            outer.intersect_info(&closed(11, 12)).relation,
+            IntersectRelation::Disjoint
+        );
+        assert_eq!(
+            outer.intersect_info(&Interval::Empty).relation,
+            IntersectRelation::Disjoint
+        );
+
+        let info = outer.intersect_info(&(5..));
+        assert_eq!(info.intersection, closed(5, 10));
+        assert_eq!(info.relation, IntersectRelation::Overlapping);
+    }
+
+    #[test]
+    fn test_unordered_constructors() {
+        let bp = BoundPair::new(1, 4).unwrap();
+        for (a, b) in [(1, 4), (4, 1)] {
+            assert_eq!(
+                Interval::closed_unordered(a, b),
+                Some(Interval::Closed { bound_pair: bp })
+            );
+            assert_eq!(
+                Interval::open_unordered(a, b),
+                Some(Interval::Open { bound_pair: bp })
+            );
+            assert_eq!(
+                Interval::left_half_open_unordered(a, b),
+                Some(Interval::LeftHalfOpen { bound_pair: bp })
+            );
+            assert_eq!(
+                Interval::right_half_open_unordered(a, b),
+                Some(Interval::RightHalfOpen { bound_pair: bp })
+            );
+        }
+        assert_eq!(
+            Interval::closed_unordered(2, 2),
+            Some(Interval::Singleton { at: 2 })
+        );
+        assert_eq!(Interval::open_unordered(2, 2), Some(Interval::Empty));
+        assert_eq!(
+            Interval::left_half_open_unordered(2, 2),
+            Some(Interval::Empty)
+        );
+        assert_eq!(
+            Interval::right_half_open_unordered(2, 2),
+            Some(Interval::Empty)
+        );
+        assert_eq!(Interval::open_unordered(1.0, f64::NAN), None);
+    }
 
-#[cfg(test)]
-mod bound_tests {
-    use super::*;
+    #[test]
+    fn test_closed_allowing_degenerate() {
+        assert_eq!(
+            Interval::closed_allowing_degenerate(-1.0, 1.0),
+            Some(Interval::Closed {
+                bound_pair: BoundPair::new(-1.0, 1.0).unwrap()
+            })
+        );
+        assert_eq!(
+            Interval::closed_allowing_degenerate(0.5, 0.5),
+            Some(Interval::Singleton { at: 0.5 })
+        );
+        assert_eq!(Interval::closed_allowing_degenerate(1.0, -1.0), None);
+        assert_eq!(
+            Interval::closed_allowing_degenerate(f64::NAN, f64::NAN),
+            None
+        );
+    }
 
     #[test]
-    fn test_left_bound() {
-        // Test bounded intervals
-        let bp = BoundPair::new(1, 5).unwrap();
+    fn test_union() {
+        let closed = |l, r| Interval::Closed {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
+        let union = |a: Interval<i32>, b: Interval<i32>| a.union(&b).collect::<Vec<_>>();
 
-        // Closed interval should have closed left bound
-        assert!(matches!(
-            Interval::Closed { bound_pair: bp }.left_bound(),
-            Bound::Closed(1)
-        ));
+        assert_eq!(union(closed(1, 5), closed(3, 8)), vec![closed(1, 8)]);
+        assert_eq!(union(closed(3, 8), closed(1, 5)), vec![closed(1, 8)]);
+        assert_eq!(union(closed(1, 8), closed(3, 5)), vec![closed(1, 8)]);
+        assert_eq!(
+            union(closed(6, 8), closed(1, 5)),
+            vec![closed(1, 5), closed(6, 8)]
+        );
+        assert_eq!(
+            union(
+                Interval::UnboundedOpenRight { right: 1 },
+                Interval::UnboundedClosedLeft { left: 1 }
+            ),
+            vec![Interval::Unbounded]
+        );
+        assert_eq!(
+            union(
+                Interval::UnboundedOpenRight { right: 1 },
+                Interval::UnboundedOpenLeft { left: 1 }
+            ),
+            vec![
+                Interval::UnboundedOpenRight { right: 1 },
+                Interval::UnboundedOpenLeft { left: 1 }
+            ]
+        );
+        assert_eq!(
+            union(Interval::Singleton { at: 5 }, closed(1, 5)),
+            vec![closed(1, 5)]
+        );
+        assert_eq!(
+            union(
+                Interval::Singleton { at: 5 },
+                Interval::Open {
+                    bound_pair: BoundPair::new(1, 5).unwrap()
+                }
+            ),
+            vec![Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(1, 5).unwrap()
+            }]
+        );
+        assert_eq!(union(Interval::Empty, closed(1, 2)), vec![closed(1, 2)]);
+        assert_eq!(union(closed(1, 2), Interval::Empty), vec![closed(1, 2)]);
+        assert_eq!(
+            union(Interval::Empty, Interval::Empty),
+            vec![Interval::Empty]
+        );
+        assert_eq!(
+            closed(1, 2).union(&(2..4)).collect::<Vec<_>>(),
+            vec![Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(1, 4).unwrap()
+            }]
+        );
+    }
 
-        // Open interval should have open left bound
-        assert!(matches!(
-            Interval::Open { bound_pair: bp }.left_bound(),
-            Bound::Open(1)
-        ));
+    #[test]
+    fn test_contiguous_chain() {
+        let rho = |l, r| Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
+        let closed = |l, r| Interval::Closed {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
 
-        // Test unbounded intervals
-        assert!(matches!(
-            Interval::Unbounded::<i32>.left_bound(),
-            Bound::Unbounded
-        ));
+        let chain = [rho(0, 5), rho(5, 7), closed(7, 9)];
+        assert!(Interval::is_contiguous_chain(&chain));
+        assert_eq!(Interval::join_chain(&chain), Some(closed(0, 9)));
 
-        // Test empty interval
-        assert!(matches!(Interval::Empty::<i32>.left_bound(), Bound::None));
+        let with_singleton = [
+            rho(0, 5),
+            Interval::Singleton { at: 5 },
+            Interval::UnboundedOpenLeft { left: 5 },
+        ];
+        assert_eq!(
+            Interval::join_chain(&with_singleton),
+            Some(Interval::UnboundedClosedLeft { left: 0 })
+        );
 
-        // Test singleton
-        assert!(matches!(
-            Interval::Singleton { at: 3 }.left_bound(),
-            Bound::Closed(3)
-        ));
+        assert_eq!(Interval::join_chain(&[rho(0, 5)]), Some(rho(0, 5)));
+        // Gap, overlap, closed-closed overlap, out of order, Empty and empty
+        assert!(!Interval::is_contiguous_chain(&[rho(0, 5), rho(6, 7)]));
+        assert!(!Interval::is_contiguous_chain(&[rho(0, 5), rho(4, 7)]));
+        assert!(!Interval::is_contiguous_chain(&[
+            closed(0, 5),
+            closed(5, 7)
+        ]));
+        assert!(!Interval::is_contiguous_chain(&[rho(5, 7), rho(0, 5)]));
+        assert!(!Interval::is_contiguous_chain(&[Interval::<i32>::Empty]));
+        assert_eq!(Interval::<i32>::join_chain(&[]), None);
+    }
 
-        // Test half-open intervals
-        assert!(matches!(
-            Interval::LeftHalfOpen { bound_pair: bp }.left_bound(),
-            Bound::Open(1)
-        ));
-        assert!(matches!(
-            Interval::RightHalfOpen { bound_pair: bp }.left_bound(),
-            Bound::Closed(1)
-        ));
+    #[test]
+    fn test_difference() {
+        let closed = |l, r| Interval::Closed {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
+        let open = |l, r| Interval::Open {
+            bound_pair: BoundPair::new(l, r).unwrap(),
+        };
+        let minus = |a: Interval<i32>, b: Interval<i32>| a.difference(&b).collect::<Vec<_>>();
+
+        assert_eq!(
+            minus(closed(0, 10), open(3, 5)),
+            vec![closed(0, 3), closed(5, 10)]
+        );
+        assert_eq!(
+            minus(closed(0, 10), closed(5, 15)),
+            vec![Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(0, 5).unwrap()
+            }]
+        );
+        assert_eq!(
+            minus(closed(0, 10), Interval::Singleton { at: 0 }),
+            vec![Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(0, 10).unwrap()
+            }]
+        );
+        assert_eq!(
+            minus(Interval::Unbounded, Interval::Singleton { at: 0 }),
+            vec![
+                Interval::UnboundedOpenRight { right: 0 },
+                Interval::UnboundedOpenLeft { left: 0 }
+            ]
+        );
+        assert_eq!(minus(closed(0, 10), closed(20, 30)), vec![closed(0, 10)]);
+        assert_eq!(minus(closed(0, 10), Interval::Empty), vec![closed(0, 10)]);
+        assert_eq!(minus(open(3, 5), closed(0, 10)), vec![]);
+        assert_eq!(minus(Interval::Empty, closed(0, 10)), vec![]);
+        assert_eq!(
+            closed(0, 10).difference(&open(3, 5)).next_back(),
+            Some(closed(5, 10))
+        );
     }
 
     #[test]
-    fn test_right_bound() {
+    fn test_finite_endpoints() {
         let bp = BoundPair::new(1, 5).unwrap();
+        let endpoints = Interval::RightHalfOpen { bound_pair: bp }.finite_endpoints();
+        assert_eq!(endpoints.left, Some((1, true)));
+        assert_eq!(endpoints.right, Some((5, false)));
+        assert_eq!(
+            endpoints.into_iter().collect::<Vec<_>>(),
+            vec![(1, EndpointKind::LeftClosed), (5, EndpointKind::RightOpen)]
+        );
 
-        // Test bounded intervals
-        assert!(matches!(
-            Interval::Closed { bound_pair: bp }.right_bound(),
-            Bound::Closed(5)
-        ));
-        assert!(matches!(
-            Interval::Open { bound_pair: bp }.right_bound(),
-            Bound::Open(5)
-        ));
+        let endpoints = Interval::UnboundedOpenRight { right: 3 }.finite_endpoints();
+        assert_eq!((endpoints.left, endpoints.right), (None, Some((3, false))));
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(Interval::Singleton { at: 2 }.finite_endpoints().len(), 2);
+        assert!(Interval::<i32>::Empty.finite_endpoints().is_empty());
+    }
 
-        // Test special cases
-        assert!(matches!(
-            Interval::Unbounded::<i32>.right_bound(),
-            Bound::Unbounded
-        ));
-        assert!(matches!(Interval::Empty::<i32>.right_bound(), Bound::None));
-        assert!(matches!(
-            Interval::Singleton { at: 3 }.right_bound(),
-            Bound::Closed(3)
-        ));
+    #[test]
+    fn test_span() {
+        let bp = BoundPair::new(1, 5).unwrap();
+        let rho = Interval::RightHalfOpen { bound_pair: bp };
+        let open = Interval::Open { bound_pair: bp };
+        assert_eq!(rho.span(&open), rho);
+        assert_eq!(open.span(&rho), rho);
+        assert_eq!(
+            rho.span(&Interval::Singleton { at: 5 }),
+            Interval::Closed { bound_pair: bp }
+        );
+        assert_eq!(
+            Interval::Singleton { at: 9 }.span(&Interval::Singleton { at: -1 }),
+            Interval::Closed {
+                bound_pair: BoundPair::new(-1, 9).unwrap()
+            }
+        );
+        assert_eq!(
+            rho.span(&Interval::UnboundedOpenRight { right: 0 }),
+            Interval::UnboundedOpenRight { right: 5 }
+        );
+        assert_eq!(
+            rho.span(&Interval::UnboundedClosedLeft { left: 9 }),
+            Interval::UnboundedClosedLeft { left: 1 }
+        );
+        assert_eq!(Interval::Empty.span(&rho), rho);
+        assert_eq!(
+            Interval::<i32>::Empty.span(&Interval::Empty),
+            Interval::Empty
+        );
+        assert_eq!(
+            Interval::Singleton { at: 0 }.span(&(3..=4)),
+            Interval::Closed {
+                bound_pair: BoundPair::new(0, 4).unwrap()
+            }
+        );
+    }
 
-        // Test unbounded variants
-        assert!(matches!(
-            Interval::UnboundedClosedLeft { left: 1 }.right_bound(),
-            Bound::Unbounded
-        ));
-        assert!(matches!(
-            Interval::UnboundedOpenLeft { left: 1 }.right_bound(),
-            Bound::Unbounded
-        ));
+    #[test]
+    fn test_overlaps() {
+        let bp = BoundPair::new(1, 2).unwrap();
+        let next = BoundPair::new(2, 3).unwrap();
+        assert!(
+            Interval::Closed { bound_pair: bp }.overlaps(&Interval::Closed { bound_pair: next })
+        );
+        assert!(!Interval::RightHalfOpen { bound_pair: bp }
+            .overlaps(&Interval::Closed { bound_pair: next }));
+        assert!(!Interval::Closed { bound_pair: bp }
+            .overlaps(&Interval::LeftHalfOpen { bound_pair: next }));
+        assert!(!Interval::Open { bound_pair: bp }.overlaps(&Interval::Singleton { at: 2 }));
+        assert!(Interval::Singleton { at: 2 }.overlaps(&Interval::Singleton { at: 2 }));
+        assert!(Interval::UnboundedOpenRight { right: 2 }
+            .overlaps(&Interval::UnboundedOpenLeft { left: 1 }));
+        assert!(!Interval::UnboundedOpenRight { right: 2 }
+            .overlaps(&Interval::UnboundedClosedLeft { left: 2 }));
+        assert!(!Interval::<i32>::Unbounded.overlaps(&Interval::Empty));
+        assert!(!Interval::<i32>::Empty.overlaps(&Interval::Unbounded));
+        assert!(Interval::Open { bound_pair: bp }.overlaps(&(0..5)));
 
-        // Test half-open intervals
-        assert!(matches!(
-            Interval::LeftHalfOpen { bound_pair: bp }.right_bound(),
-            Bound::Closed(5)
-        ));
-        assert!(matches!(
-            Interval::RightHalfOpen { bound_pair: bp }.right_bound(),
-            Bound::Open(5)
-        ));
+        let examples = crate::generators::all_variant_examples(2, 6).unwrap();
+        for a in examples.iter() {
+            for b in crate::generators::all_variant_examples(4, 8)
+                .unwrap()
+                .iter()
+                .chain(examples.iter())
+            {
+                assert_eq!(
+                    a.overlaps(b),
+                    a.intersect(b) != Interval::Empty,
+                    "{} {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_touches() {
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(1, 2).unwrap(),
+        };
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(2, 3).unwrap(),
+        };
+        assert!(closed.touches(&open));
+        assert!(open.touches(&closed));
+        assert!(closed.touches(&Interval::UnboundedClosedLeft { left: 2 }));
+        assert!(Interval::UnboundedOpenRight { right: 1 }.touches(&closed));
+        assert!(Interval::Singleton { at: 2 }.touches(&closed));
+        assert!(!Interval::Singleton { at: 2 }.touches(&(0..5)));
+        assert!(!closed.touches(&Interval::Singleton { at: 3 }));
+        assert!(!closed.touches(&Interval::Unbounded));
+        assert!(!closed.touches(&Interval::Empty));
+        assert!(!closed.touches(&closed));
+        let right_half_open = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(1, 2).unwrap(),
+        };
+        let left_half_open = Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(2, 3).unwrap(),
+        };
+        assert!(!right_half_open.touches(&left_half_open));
+        assert!(!left_half_open.touches(&right_half_open));
+        assert!(!right_half_open.touches(&open));
+        assert!(Interval::Singleton { at: 2 }.touches(&Interval::Closed {
+            bound_pair: BoundPair::new(2, 3).unwrap(),
+        }));
+        assert!(!Interval::Singleton { at: 2 }.touches(&Interval::Closed {
+            bound_pair: BoundPair::new(1, 3).unwrap(),
+        }));
+        for interval in crate::generators::all_variant_examples(1, 2).unwrap() {
+            assert!(!interval.touches(&Interval::Empty));
+            assert_eq!(
+                interval.touches(&open),
+                open.touches(&interval),
+                "{:?}",
+                interval
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge() {
+        let open = |left, right| Interval::Open {
+            bound_pair: BoundPair::new(left, right).unwrap(),
+        };
+        assert_eq!(open(1, 2).merge(&open(2, 3)), None);
+        assert_eq!(
+            open(1, 2).merge(&Interval::Singleton { at: 2 }),
+            Some(Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(1, 2).unwrap()
+            })
+        );
+        assert_eq!(open(1, 3).merge(&open(2, 4)), Some(open(1, 4)));
+        assert_eq!(open(1, 3).merge(&Interval::Empty), Some(open(1, 3)));
+        assert_eq!(
+            Interval::Empty.merge(&Interval::<i32>::Empty),
+            Some(Interval::Empty)
+        );
+        assert_eq!(
+            open(1, 3).merge(&Interval::UnboundedClosedLeft { left: 3 }),
+            Some(Interval::UnboundedOpenLeft { left: 1 })
+        );
+        for interval in crate::generators::all_variant_examples(1, 2).unwrap() {
+            assert_eq!(
+                interval.merge(&open(5, 6)).is_some(),
+                interval.union(&open(5, 6)).count() == 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_structural_predicates() {
+        for interval in crate::generators::all_variant_examples(1, 2).unwrap() {
+            assert_eq!(interval.is_empty(), interval == Interval::Empty);
+            assert_eq!(interval.is_bounded(), !interval.is_unbounded());
+            assert_eq!(
+                interval.is_bounded(),
+                interval.is_empty() || interval.finite_bounds().is_some(),
+                "{:?}",
+                interval
+            );
+            assert_eq!(
+                interval.is_degenerate(),
+                interval.is_bounded() && interval.width().unwrap_or(0) == 0,
+                "{:?}",
+                interval
+            );
+        }
+    }
+
+    #[test]
+    fn test_symmetrize() {
+        let closed = |left, right| Interval::Closed {
+            bound_pair: BoundPair::new(left, right).unwrap(),
+        };
+        let left_half_open = Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(1, 5).unwrap(),
+        };
+        assert_eq!(left_half_open.symmetrize(2), Some(closed(-1, 5)));
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(1, 3).unwrap(),
+        };
+        assert_eq!(open.symmetrize(2), Some(open));
+        let right_half_open = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(1, 3).unwrap(),
+        };
+        assert_eq!(right_half_open.symmetrize(2), Some(closed(1, 3)));
+        assert_eq!(closed(3, 5).symmetrize(0), Some(closed(-5, 5)));
+        assert_eq!(
+            Interval::Singleton { at: 2 }.symmetrize(2),
+            Some(Interval::Singleton { at: 2 })
+        );
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 2 }.symmetrize(2),
+            Some(Interval::Unbounded)
+        );
+        assert_eq!(Interval::Empty.symmetrize(2), Some(Interval::Empty));
+        assert_eq!(Interval::Singleton { at: 1.0 }.symmetrize(f64::NAN), None);
+        assert_eq!(
+            Interval::Singleton { at: 10u8 }.symmetrize(12),
+            Some(Interval::Closed {
+                bound_pair: BoundPair::new(10, 14).unwrap()
+            })
+        );
+        for interval in crate::generators::all_variant_examples(-2, 2).unwrap() {
+            let symmetric = interval.symmetrize(0).unwrap();
+            assert!(symmetric.is_symmetric_about(0));
+            assert!(
+                interval.is_empty() || symmetric.contains(&interval),
+                "{:?}",
+                interval
+            );
+        }
+        assert!(!Interval::UnboundedOpenRight { right: 0 }.is_symmetric_about(0));
+        assert!(Interval::<i32>::Unbounded.is_symmetric_about(0));
+    }
+
+    #[test]
+    fn test_symmetrize_unrepresentable() {
+        fn closed<T: Copy + PartialOrd>(left: T, right: T) -> Interval<T> {
+            Interval::Closed {
+                bound_pair: BoundPair::new(left, right).unwrap(),
+            }
+        }
+        assert_eq!(closed(0u32, 5).symmetrize(2), None);
+        assert_eq!(closed(0u32, 5).symmetrize(3), Some(closed(0, 6)));
+        assert_eq!(closed(0u32, 4).symmetrize(2), Some(closed(0, 4)));
+        assert_eq!(closed(250u8, 255).symmetrize(254), None);
+        assert_eq!(closed(-100i8, 100).symmetrize(-50), None);
+        assert_eq!(closed(-100i8, 100).symmetrize(0), Some(closed(-100, 100)));
+        assert_eq!(closed(i8::MIN, i8::MAX).symmetrize(0), None);
+        assert!(!closed(0u32, 5).is_symmetric_about(2));
+        assert_eq!(closed(0.0, f64::MAX).symmetrize(-1.0), None);
+    }
+
+    #[test]
+    fn test_certainly_possibly_lt() {
+        let interval = |left, right, openness: Openness| {
+            let (left, right) = openness.bounds(left, right);
+            Interval::from_bounds(left, right)
+        };
+        let closed = interval(1.0, 2.0, Openness::Closed);
+        let open = interval(1.0, 2.0, Openness::Open);
+        let next_closed = interval(2.0, 3.0, Openness::Closed);
+        let next_open = interval(2.0, 3.0, Openness::Open);
+        assert!(!closed.certainly_lt(&next_closed));
+        assert!(closed.certainly_lt(&next_open));
+        assert!(open.certainly_lt(&next_closed));
+        assert!(!closed.certainly_lt(&closed));
+        assert!(closed.possibly_lt(&closed));
+        assert!(open.possibly_lt(&open));
+        assert!(!next_closed.possibly_lt(&closed));
+        assert!(!Interval::Singleton { at: 2.0 }.possibly_lt(&Interval::Singleton { at: 2.0 }));
+        assert!(closed.certainly_lt(&Interval::UnboundedOpenLeft { left: 2.0 }));
+        assert!(!closed.certainly_lt(&Interval::Unbounded));
+        assert!(Interval::Unbounded.possibly_lt(&closed));
+        assert!(closed.certainly_lt(&Interval::Empty));
+        assert!(!closed.possibly_lt(&Interval::Empty));
+        let nan = Interval::Singleton { at: f64::NAN };
+        assert!(!nan.certainly_lt(&closed));
+        assert!(!nan.possibly_lt(&closed));
+        for a in crate::generators::all_variant_examples(1, 2).unwrap() {
+            for b in crate::generators::all_variant_examples(1, 2).unwrap() {
+                if a.certainly_lt(&b) && !a.is_empty() && !b.is_empty() {
+                    assert!(a.possibly_lt(&b), "{:?} {:?}", a, b);
+                    assert!(!a.overlaps(&b), "{:?} {:?}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_openness_predicates() {
+        for interval in crate::generators::all_variant_examples(1, 2).unwrap() {
+            let endpoints = interval.finite_endpoints();
+            let closed = |endpoint: Option<(i32, bool)>| endpoint.is_some_and(|(_, closed)| closed);
+            assert_eq!(interval.is_left_closed(), closed(endpoints.left));
+            assert_eq!(interval.is_right_closed(), closed(endpoints.right));
+            assert_eq!(
+                interval.is_closed(),
+                interval.endpoints().all(|(_, kind)| kind.is_closed()),
+                "{:?}",
+                interval
+            );
+            assert_eq!(
+                interval.is_open(),
+                interval.endpoints().all(|(_, kind)| !kind.is_closed()),
+                "{:?}",
+                interval
+            );
+        }
+    }
+
+    #[test]
+    fn test_contains_point_tristate() {
+        for interval in crate::generators::all_variant_examples(1.0, 2.0).unwrap() {
+            for value in [0.5, 1.0, 1.5, 2.0, 2.5] {
+                let expected = if interval.contains(&Interval::Singleton { at: value }) {
+                    Containment::Yes
+                } else {
+                    Containment::No
+                };
+                assert_eq!(
+                    interval.contains_point_tristate(value),
+                    expected,
+                    "{:?} {}",
+                    interval,
+                    value
+                );
+            }
+            let nan = interval.contains_point_tristate(f64::NAN);
+            if interval.is_empty() {
+                assert_eq!(nan, Containment::No);
+            } else {
+                assert_eq!(nan, Containment::Unknown);
+            }
+        }
+        let nan_bound = Interval::UnboundedClosedLeft { left: f64::NAN };
+        assert_eq!(nan_bound.contains_point_tristate(1.0), Containment::Unknown);
+    }
+
+    #[test]
+    fn test_classify() {
+        let classes: std::collections::HashSet<_> = crate::generators::all_variant_examples(1, 2)
+            .unwrap()
+            .iter()
+            .map(Interval::classify)
+            .collect();
+        assert_eq!(classes.len(), 11);
+        assert_eq!(
+            Interval::Singleton { at: 1 }.classify(),
+            Interval::Singleton { at: 7 }.classify()
+        );
+        assert_eq!(
+            Interval::<i32>::Unbounded.classify(),
+            IntervalClass::Unbounded
+        );
+    }
+
+    #[test]
+    fn test_relate() {
+        let interval = |left, right, openness: Openness| {
+            let (left, right) = openness.bounds(left, right);
+            Interval::from_bounds(left, right)
+        };
+        let closed = |left, right| interval(left, right, Openness::Closed);
+        let base = closed(2.0, 4.0);
+        let cases = [
+            (closed(0.0, 1.0), AllenRelation::Precedes),
+            (interval(0.0, 2.0, Openness::Open), AllenRelation::Meets),
+            (
+                interval(0.0, 2.0, Openness::RightHalfOpen),
+                AllenRelation::Meets,
+            ),
+            (closed(0.0, 2.0), AllenRelation::Overlaps),
+            (closed(0.0, 3.0), AllenRelation::Overlaps),
+            (closed(0.0, 4.0), AllenRelation::FinishedBy),
+            (closed(0.0, 5.0), AllenRelation::Contains),
+            (Interval::Unbounded, AllenRelation::Contains),
+            (closed(2.0, 3.0), AllenRelation::Starts),
+            (Interval::Singleton { at: 2.0 }, AllenRelation::Starts),
+            (closed(2.0, 4.0), AllenRelation::Equals),
+            (closed(2.0, 5.0), AllenRelation::StartedBy),
+            (interval(2.0, 4.0, Openness::Open), AllenRelation::During),
+            (Interval::Singleton { at: 3.0 }, AllenRelation::During),
+            (closed(3.0, 4.0), AllenRelation::Finishes),
+            (closed(3.0, 5.0), AllenRelation::OverlappedBy),
+            (
+                interval(4.0, 5.0, Openness::LeftHalfOpen),
+                AllenRelation::MetBy,
+            ),
+            (closed(5.0, 6.0), AllenRelation::PrecededBy),
+        ];
+        for (other, relation) in cases {
+            assert_eq!(other.relate(&base), Some(relation), "{:?}", other);
+            assert_eq!(base.relate(&other), Some(relation.inverse()), "{:?}", other);
+        }
+        assert_eq!(
+            interval(0.0, 2.0, Openness::Open).relate(&interval(2.0, 4.0, Openness::Open)),
+            Some(AllenRelation::Precedes)
+        );
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 1.0 }.relate(&Interval::Unbounded),
+            Some(AllenRelation::Finishes)
+        );
+        assert_eq!(base.relate(&Interval::Empty), None);
+        assert_eq!(Interval::Singleton { at: f64::NAN }.relate(&base), None);
+        assert_eq!(
+            base.relate(&Interval::UnboundedOpenRight { right: f64::NAN }),
+            None
+        );
+        for a in crate::generators::all_variant_examples(1, 2).unwrap() {
+            for b in crate::generators::all_variant_examples(1, 2).unwrap() {
+                assert_eq!(
+                    a.relate(&b),
+                    b.relate(&a).map(|relation| relation.inverse())
+                );
+                assert_eq!(
+                    a.relate(&b) == Some(AllenRelation::Equals),
+                    !a.is_empty() && a == b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_gap() {
+        let interval = |left, right, openness: Openness| {
+            let (left, right) = openness.bounds(left, right);
+            Interval::from_bounds(left, right)
+        };
+        let closed = interval(1, 2, Openness::Closed);
+        assert_eq!(
+            closed.gap(&interval(4, 5, Openness::RightHalfOpen)),
+            interval(2, 4, Openness::Open)
+        );
+        assert_eq!(
+            interval(4, 5, Openness::Open).gap(&closed),
+            interval(2, 4, Openness::LeftHalfOpen)
+        );
+        assert_eq!(
+            interval(1, 2, Openness::Open).gap(&interval(2, 3, Openness::Open)),
+            Interval::Singleton { at: 2 }
+        );
+        assert_eq!(
+            closed.gap(&interval(2, 3, Openness::LeftHalfOpen)),
+            Interval::Empty
+        );
+        assert_eq!(
+            closed.gap(&interval(2, 3, Openness::Closed)),
+            Interval::Empty
+        );
+        assert_eq!(
+            closed.gap(&Interval::UnboundedClosedLeft { left: 7 }),
+            interval(2, 7, Openness::Open)
+        );
+        assert_eq!(closed.gap(&Interval::Unbounded), Interval::Empty);
+        assert_eq!(closed.gap(&Interval::Empty), Interval::Empty);
+        for a in crate::generators::all_variant_examples(1, 2).unwrap() {
+            for b in crate::generators::all_variant_examples(3, 4).unwrap() {
+                let gap = a.gap(&b);
+                assert_eq!(gap, b.gap(&a));
+                assert!(!gap.overlaps(&a) && !gap.overlaps(&b), "{:?} {:?}", a, b);
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod comparison_tests {
-    use super::*;
-    use std::cmp::Ordering;
+    #[test]
+    fn test_overlap_width() {
+        for a in crate::generators::all_variant_examples(1, 3).unwrap() {
+            for b in crate::generators::all_variant_examples(2, 4).unwrap() {
+                assert_eq!(
+                    a.overlap_width(&b),
+                    a.intersect(&b).width(),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+                assert_eq!(a.overlap_width(&b), b.overlap_width(&a));
+            }
+            for b in crate::generators::all_variant_examples(3, 5).unwrap() {
+                assert_eq!(
+                    a.overlap_width(&b),
+                    a.intersect(&b).width(),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+        let nan = Interval::Singleton { at: f64::NAN };
+        assert!(nan.overlap_width(&(0.0..1.0)).is_none());
+    }
 
     #[test]
-    fn test_left_partial_cmp_basic() {
-        let i1 = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
-        };
-        let i2 = Interval::Closed {
-            bound_pair: BoundPair::new(2, 6).unwrap(),
+    fn test_connected_components() {
+        let interval = |left, right, openness: Openness| {
+            let (left, right) = openness.bounds(left, right);
+            Interval::from_bounds(left, right)
         };
-        assert_eq!(i1.left_partial_cmp(&i2), Some(Ordering::Less));
-        assert_eq!(i2.left_partial_cmp(&i1), Some(Ordering::Greater));
+        let intervals = [
+            interval(5, 6, Openness::Open),
+            interval(0, 2, Openness::RightHalfOpen),
+            Interval::Empty,
+            interval(2, 3, Openness::Closed),
+            interval(3, 5, Openness::Open),
+            Interval::UnboundedClosedLeft { left: 10 },
+            interval(1, 2, Openness::Closed),
+            Interval::Singleton { at: 12 },
+        ];
+        let components = Interval::connected_components(&intervals);
+        assert_eq!(
+            components,
+            vec![
+                Component {
+                    hull: interval(0, 5, Openness::RightHalfOpen),
+                    members: vec![1, 3, 4, 6],
+                },
+                Component {
+                    hull: interval(5, 6, Openness::Open),
+                    members: vec![0],
+                },
+                Component {
+                    hull: Interval::UnboundedClosedLeft { left: 10 },
+                    members: vec![5, 7],
+                },
+            ]
+        );
+        assert!(Interval::<i32>::connected_components(&[]).is_empty());
+        assert!(Interval::<i32>::connected_components(&[Interval::Empty]).is_empty());
+        assert_eq!(
+            Interval::connected_components(&[
+                Interval::<i32>::Unbounded,
+                interval(1, 2, Openness::Open)
+            ]),
+            vec![Component {
+                hull: Interval::Unbounded,
+                members: vec![0, 1],
+            }]
+        );
     }
 
+    #[cfg(feature = "num-traits")]
     #[test]
-    fn test_left_partial_cmp_equal_bounds() {
-        let closed = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+    fn test_jaccard_index() {
+        let closed = |left, right| Interval::Closed {
+            bound_pair: BoundPair::new(left, right).unwrap(),
         };
-        let open = Interval::Open {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+        assert_eq!(
+            closed(0.0, 4.0).jaccard_index(&closed(1.0, 2.0)),
+            Some(0.25)
+        );
+        assert_eq!(closed(0.0, 1.0).jaccard_index(&closed(3.0, 4.0)), Some(0.0));
+        assert_eq!(
+            closed(0.0, 1.0).jaccard_index(&Interval::Singleton { at: 0.5 }),
+            Some(0.0)
+        );
+        assert_eq!(
+            Interval::Singleton { at: 1.0 }.jaccard_index(&Interval::Singleton { at: 1.0 }),
+            None
+        );
+        assert_eq!(closed(0.0, 1.0).jaccard_index(&Interval::Empty), None);
+        assert_eq!(
+            closed(0.0, 1.0).jaccard_index(&Interval::UnboundedClosedLeft { left: 0.5 }),
+            None
+        );
+        let integers = Interval::Closed {
+            bound_pair: BoundPair::new(0u32, 4).unwrap(),
         };
-        // Open bound is considered "greater" than closed bound at same value
-        assert_eq!(closed.left_partial_cmp(&open), Some(Ordering::Less));
-        assert_eq!(open.left_partial_cmp(&closed), Some(Ordering::Greater));
+        assert_eq!(integers.jaccard_index(&(2..6)), Some(2.0 / 6.0));
     }
 
+    #[cfg(feature = "num-traits")]
     #[test]
-    fn test_left_partial_cmp_unbounded() {
-        let finite = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+    fn test_weighted_overlap() {
+        let closed = |left, right| Interval::Closed {
+            bound_pair: BoundPair::new(left, right).unwrap(),
         };
-        let unbounded = Interval::Unbounded;
-        assert_eq!(finite.left_partial_cmp(&unbounded), Some(Ordering::Greater));
-        assert_eq!(unbounded.left_partial_cmp(&finite), Some(Ordering::Less));
+        let members = [
+            (closed(0, 10), 1.0),
+            (closed(5, 15), 0.5),
+            (Interval::Singleton { at: 7 }, 100.0),
+            (closed(20, 30), 3.0),
+            (Interval::Empty, 9.0),
+        ];
+        assert_eq!(closed(5, 10).weighted_overlap(&members), Some(5.0 + 2.5));
+        assert_eq!(closed(40, 50).weighted_overlap(&members), Some(0.0));
+        assert_eq!(closed(5, 10).weighted_overlap(&[]), Some(0.0));
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 25 }.weighted_overlap(&members),
+            Some(15.0)
+        );
+        let unbounded = [(Interval::UnboundedClosedLeft { left: 0 }, 1.0)];
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 25 }.weighted_overlap(&unbounded),
+            None
+        );
     }
 
     #[test]
-    fn test_left_partial_cmp_empty() {
-        let interval = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+    fn test_midpoint() {
+        use std::time::{Duration, Instant};
+        let closed = |left, right| Interval::Closed {
+            bound_pair: BoundPair::new(left, right).unwrap(),
         };
-        let empty = Interval::Empty;
-        assert_eq!(interval.left_partial_cmp(&empty), None);
-        assert_eq!(empty.left_partial_cmp(&interval), None);
+        assert_eq!(closed(1, 4).midpoint(), Some(2));
+        assert_eq!(closed(-4, -1).midpoint(), Some(-3));
+        assert_eq!(closed(0, 1).midpoint(), Some(0));
+        assert_eq!(
+            closed(i32::MAX - 2, i32::MAX).midpoint(),
+            Some(i32::MAX - 1)
+        );
+        assert_eq!(Interval::Singleton { at: 7 }.midpoint(), Some(7));
+        assert_eq!(Interval::<i32>::Empty.midpoint(), None);
+        assert_eq!(Interval::<i32>::Unbounded.midpoint(), None);
+        assert_eq!(Interval::UnboundedClosedRight { right: 1 }.midpoint(), None);
+        let unit = Interval::Closed {
+            bound_pair: BoundPair::new(0.0, 1.0).unwrap(),
+        };
+        assert_eq!(unit.midpoint(), Some(0.5));
+        let latency = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(Duration::from_millis(10), Duration::from_millis(15))
+                .unwrap(),
+        };
+        assert_eq!(latency.midpoint(), Some(Duration::from_micros(12_500)));
+        let now = Instant::now();
+        let window = Interval::deadline_window_at(now, Duration::from_secs(30));
+        assert_eq!(window.midpoint(), Some(now + Duration::from_secs(15)));
     }
 
     #[test]
-    fn test_right_partial_cmp_basic() {
-        let i1 = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+    fn test_lerp() {
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(2.0f64, 4.0).unwrap(),
         };
-        let i2 = Interval::Closed {
-            bound_pair: BoundPair::new(2, 6).unwrap(),
+        assert_eq!(open.lerp(0.0), Some(2.0));
+        assert_eq!(open.lerp(0.5), Some(3.0));
+        assert_eq!(open.lerp(1.0), Some(4.0));
+        assert_eq!(open.lerp(-1.0), Some(0.0));
+        assert!(open.lerp(f64::NAN).unwrap().is_nan());
+        assert_eq!(Interval::Singleton { at: 2.0 }.lerp(0.75), Some(2.0));
+        assert_eq!(Interval::<f64>::Empty.lerp(0.5), None);
+        assert_eq!(Interval::<f64>::Unbounded.lerp(0.5), None);
+        let integers = Interval::Closed {
+            bound_pair: BoundPair::new(10, 20).unwrap(),
         };
-        assert_eq!(i1.right_partial_cmp(&i2), Some(Ordering::Less));
-        assert_eq!(i2.right_partial_cmp(&i1), Some(Ordering::Greater));
+        assert_eq!(integers.lerp(3), Some(40));
     }
 
     #[test]
-    fn test_right_partial_cmp_mixed_bounds() {
-        let closed = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
-        };
-        let half_open = Interval::RightHalfOpen {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+    fn test_inverse_lerp() {
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(2.0f64, 4.0).unwrap(),
         };
-        // Open bound is considered "less" than closed bound at same value
+        assert_eq!(open.inverse_lerp(2.0), Some(0.0));
+        assert_eq!(open.inverse_lerp(3.0), Some(0.5));
+        assert_eq!(open.inverse_lerp(4.0), Some(1.0));
+        assert_eq!(open.inverse_lerp(0.0), Some(-1.0));
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert_eq!(open.inverse_lerp(open.lerp(t).unwrap()), Some(t));
+        }
+        assert_eq!(Interval::Singleton { at: 2.0 }.inverse_lerp(2.0), None);
+        assert_eq!(Interval::<f64>::Empty.inverse_lerp(0.5), None);
         assert_eq!(
-            closed.right_partial_cmp(&half_open),
-            Some(Ordering::Greater)
+            Interval::UnboundedOpenLeft { left: 0.0 }.inverse_lerp(0.5),
+            None
         );
-        assert_eq!(half_open.right_partial_cmp(&closed), Some(Ordering::Less));
+        let integers = Interval::Closed {
+            bound_pair: BoundPair::new(10, 20).unwrap(),
+        };
+        assert_eq!(integers.inverse_lerp(45), Some(3));
     }
 
     #[test]
-    fn test_right_partial_cmp_unbounded() {
-        let finite = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+    fn test_point_distance() {
+        for interval in crate::generators::all_variant_examples(2.0, 4.0).unwrap() {
+            for value in [2.0, 4.0] {
+                let contained = interval.contains(&Interval::Singleton { at: value });
+                let distance = interval.point_distance(value);
+                if contained {
+                    assert_eq!(distance, Some(0.0));
+                } else if interval == Interval::Empty {
+                    assert_eq!(distance, None);
+                }
+            }
+        }
+        let half_open = Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(2.0, 4.0).unwrap(),
         };
-        let unbounded_left = Interval::UnboundedClosedLeft { left: 1 };
+        assert_eq!(half_open.point_distance(2.0), Some(0.0));
+        assert_eq!(half_open.point_distance(0.5), Some(1.5));
+        assert_eq!(half_open.point_distance(5.0), Some(1.0));
         assert_eq!(
-            finite.right_partial_cmp(&unbounded_left),
-            Some(Ordering::Less)
+            Interval::Singleton { at: 2.0 }.point_distance(2.0),
+            Some(0.0)
         );
         assert_eq!(
-            unbounded_left.right_partial_cmp(&finite),
-            Some(Ordering::Greater)
+            Interval::Singleton { at: 2.0 }.point_distance(-1.0),
+            Some(3.0)
+        );
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 2.0 }.point_distance(1e9),
+            Some(0.0)
+        );
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 2.0 }.point_distance(1.0),
+            Some(1.0)
         );
+        assert_eq!(Interval::<f64>::Unbounded.point_distance(1.0), Some(0.0));
+        assert_eq!(Interval::<f64>::Empty.point_distance(1.0), None);
+        assert!(half_open.point_distance(f64::NAN).unwrap().is_nan());
     }
 
     #[test]
-    fn test_right_partial_cmp_empty() {
-        let interval = Interval::Closed {
-            bound_pair: BoundPair::new(1, 5).unwrap(),
+    fn test_fit_within() {
+        let examples = crate::generators::all_variant_examples(2, 6).unwrap();
+        for interval in examples {
+            for container in examples {
+                let (fitted, overflow) = interval.fit_within(&container);
+                assert_eq!(fitted, interval.intersect(&container));
+                let mut pieces = vec![overflow.left, fitted, overflow.right];
+                pieces.retain(|piece| *piece != Interval::Empty);
+                for pair in pieces.windows(2) {
+                    assert!(pair[0].certainly_lt(&pair[1]));
+                }
+                let rejoined = pieces
+                    .iter()
+                    .fold(Interval::Empty, |hull, piece| hull.span(piece));
+                assert_eq!(rejoined, interval);
+            }
+        }
+        let window = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(0, 10).unwrap(),
         };
-        let empty = Interval::Empty;
-        assert_eq!(interval.right_partial_cmp(&empty), None);
-        assert_eq!(empty.right_partial_cmp(&interval), None);
+        let (fitted, overflow) = Interval::Unbounded.fit_within(&window);
+        assert_eq!(fitted, window);
+        assert_eq!(overflow.left, Interval::UnboundedOpenRight { right: 0 });
+        assert_eq!(overflow.right, Interval::UnboundedClosedLeft { left: 10 });
+        let (fitted, overflow) = window.fit_within(&(12..20));
+        assert_eq!(fitted, Interval::Empty);
+        assert_eq!(overflow.left, window);
+        assert_eq!(overflow.right, Interval::Empty);
+        let (fitted, overflow) = window.fit_within(&Interval::Empty);
+        assert_eq!(fitted, Interval::Empty);
+        assert_eq!(overflow.left, window);
+        assert_eq!(overflow.right, Interval::Empty);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::bound_pair::BoundPair;
-    use crate::interval::Bound;
-    use crate::interval::Interval;
-    use itertools::Either;
-    use quickcheck::Arbitrary;
-    use quickcheck::Gen;
-    use quickcheck::TestResult;
-    use quickcheck_macros::quickcheck;
-    use std::cmp::Ordering;
+    #[test]
+    fn test_translate() {
+        let examples = crate::generators::all_variant_examples(1, 5).unwrap();
+        let shifted = crate::generators::all_variant_examples(11, 15).unwrap();
+        for (interval, expected) in examples.iter().zip(shifted.iter()) {
+            assert_eq!(interval.translate(10), *expected);
+        }
+        let examples = crate::generators::all_variant_examples(1.5, 2.5).unwrap();
+        for interval in examples {
+            assert_eq!(interval.translate(0.0), interval);
+        }
+        let tiny = Interval::Closed {
+            bound_pair: BoundPair::new(0.0, f64::EPSILON).unwrap(),
+        };
+        assert_eq!(tiny.translate(1e6), Interval::Singleton { at: 1e6 });
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(0.0, f64::EPSILON).unwrap(),
+        };
+        assert_eq!(open.translate(1e6), Interval::Empty);
+    }
 
-    impl<T> Arbitrary for Interval<T>
-    where
-        T: Arbitrary + Copy + Clone + PartialOrd + Send + 'static,
-    {
-        fn arbitrary(g: &mut Gen) -> Interval<T> {
-            const VARIANT_COUNT: usize = 12;
-            let variant_idx = g.size() % VARIANT_COUNT;
+    #[test]
+    fn test_scale() {
+        let examples = crate::generators::all_variant_examples(1.0, 3.0).unwrap();
+        for interval in examples {
+            let scaled = interval.scale(1.0, 2.0);
+            if interval.is_bounded() || interval.is_empty() {
+                assert_eq!(scaled, Some(interval));
+            } else {
+                assert_eq!(scaled, None);
+            }
+        }
+        let stretched = crate::generators::all_variant_examples(0.0, 4.0).unwrap();
+        for (interval, expected) in examples.iter().zip(stretched.iter()) {
+            if interval.is_bounded() && !interval.is_degenerate() {
+                assert_eq!(interval.scale(2.0, 2.0), Some(*expected));
+            }
+        }
+        let left_half_open = Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(1.0, 3.0).unwrap(),
+        };
+        assert_eq!(
+            left_half_open.scale(-1.0, 0.0),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(-3.0, -1.0).unwrap(),
+            })
+        );
+        assert_eq!(
+            left_half_open.scale(0.0, 5.0),
+            Some(Interval::Singleton { at: 5.0 })
+        );
+        assert_eq!(left_half_open.scale(2.0, f64::NAN), None);
+        let unsigned = Interval::Closed {
+            bound_pair: BoundPair::new(2u32, 6).unwrap(),
+        };
+        assert_eq!(
+            unsigned.scale(3, 3),
+            Some(Interval::Closed {
+                bound_pair: BoundPair::new(0, 12).unwrap(),
+            })
+        );
+    }
 
-            match variant_idx {
-                0 => {
-                    let bound_pair = loop {
-                        let left = T::arbitrary(g);
-                        let right = T::arbitrary(g);
-                        if let Some(bp) = BoundPair::new(left, right) {
-                            break bp;
-                        }
-                    };
-                    Interval::Closed { bound_pair }
-                }
-                1 => {
-                    let bound_pair = loop {
-                        let left = T::arbitrary(g);
-                        let right = T::arbitrary(g);
-                        if let Some(bp) = BoundPair::new(left, right) {
-                            break bp;
-                        }
-                    };
-                    Interval::Open { bound_pair }
-                }
-                2 => {
-                    let bound_pair = loop {
-                        let left = T::arbitrary(g);
-                        let right = T::arbitrary(g);
-                        if let Some(bp) = BoundPair::new(left, right) {
-                            break bp;
-                        }
-                    };
-                    Interval::LeftHalfOpen { bound_pair }
-                }
-                3 => {
-                    let bound_pair = loop {
-                        let left = T::arbitrary(g);
-                        let right = T::arbitrary(g);
-                        if let Some(bp) = BoundPair::new(left, right) {
-                            break bp;
-                        }
-                    };
-                    Interval::LeftHalfOpen { bound_pair }
-                }
-                4 => {
-                    let bound_pair = loop {
-                        let left = T::arbitrary(g);
-                        let right = T::arbitrary(g);
-                        if let Some(bp) = BoundPair::new(left, right) {
-                            break bp;
-                        }
-                    };
-                    Interval::RightHalfOpen { bound_pair }
-                }
-                5 => Interval::UnboundedClosedRight {
-                    right: T::arbitrary(g),
-                },
-                6 => Interval::UnboundedOpenRight {
-                    right: T::arbitrary(g),
-                },
-                7 => Interval::UnboundedClosedLeft {
-                    left: T::arbitrary(g),
-                },
-                8 => Interval::UnboundedOpenLeft {
-                    left: T::arbitrary(g),
-                },
-                9 => Interval::Singleton {
-                    at: T::arbitrary(g),
-                },
-                10 => Interval::Unbounded,
-                11 => Interval::Empty,
-                _ => unreachable!("variant_idx is always < VARIANT_COUNT"),
+    #[test]
+    fn test_reflect() {
+        let examples = crate::generators::all_variant_examples(1, 3).unwrap();
+        for interval in examples {
+            let reflected = interval.reflect();
+            assert_eq!(-interval, reflected);
+            assert_eq!(reflected.reflect(), interval);
+            assert_eq!(reflected.width(), interval.width());
+            assert_eq!(reflected.is_left_closed(), interval.is_right_closed());
+            assert_eq!(reflected.is_right_closed(), interval.is_left_closed());
+            assert_eq!(reflected.is_empty(), interval.is_empty());
+            for value in -4..=4 {
+                assert_eq!(
+                    reflected.contains(&Interval::Singleton { at: -value }),
+                    interval.contains(&Interval::Singleton { at: value })
+                );
+            }
+        }
+        assert_eq!(Interval::<f64>::Unbounded.reflect(), Interval::Unbounded);
+        assert_eq!(Interval::<f64>::Empty.reflect(), Interval::Empty);
+        assert_eq!(
+            -Interval::Singleton { at: 0.0 },
+            Interval::Singleton { at: -0.0 }
+        );
+    }
+
+    #[test]
+    fn test_pad() {
+        let examples = crate::generators::all_variant_examples(2, 4).unwrap();
+        let padded = crate::generators::all_variant_examples(1, 5).unwrap();
+        for (interval, expected) in examples.iter().zip(padded.iter()) {
+            assert_eq!(interval.pad(0), *interval);
+            if !interval.is_degenerate() {
+                assert_eq!(interval.pad(1), *expected);
+                assert_eq!(expected.pad(-1), *interval);
             }
         }
+        assert_eq!(
+            Interval::Singleton { at: 3 }.pad(1),
+            Interval::Closed {
+                bound_pair: BoundPair::new(2, 4).unwrap()
+            }
+        );
+        assert_eq!(Interval::Singleton { at: 3 }.pad(-1), Interval::Empty);
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(2, 4).unwrap(),
+        };
+        assert_eq!(open.pad(-1), Interval::Empty);
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(2, 4).unwrap(),
+        };
+        assert_eq!(closed.pad(-1), Interval::Singleton { at: 3 });
+        assert_eq!(closed.pad(-2), Interval::Empty);
+        assert_eq!(Interval::<i32>::Unbounded.pad(-100), Interval::Unbounded);
+    }
 
-        // fn shrink(&self) -> Box<Iterator<Item = Self>> {
-        //     match self {
-        //         // &Interval::Unbounded => Box::new(Interval::Unbounded),
-        //         // &Qqq::Kokoko(ref x) => Box::new(x.shrink().map(|s| Qqq::Kokoko(s))),
-        //         _ => quickcheck::empty_shrinker(),
-        //     }
-        // }
+    #[test]
+    fn test_split_at() {
+        for interval in crate::generators::all_variant_examples(2, 6).unwrap() {
+            for at in 1..=7 {
+                for keep in [SplitSide::Left, SplitSide::Right] {
+                    let (below, above) = interval.split_at(at, keep);
+                    assert!(below.certainly_lt(&above));
+                    assert_eq!(below.span(&above), interval);
+                    let point = Interval::Singleton { at };
+                    if interval.contains(&point) {
+                        let keeper = match keep {
+                            SplitSide::Left => below,
+                            SplitSide::Right => above,
+                        };
+                        assert!(keeper.contains(&point));
+                    }
+                }
+            }
+        }
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(0, 10).unwrap(),
+        };
+        assert_eq!(
+            closed.split_at(0, SplitSide::Left),
+            (
+                Interval::Singleton { at: 0 },
+                Interval::LeftHalfOpen {
+                    bound_pair: BoundPair::new(0, 10).unwrap()
+                }
+            )
+        );
+        assert_eq!(
+            closed.split_at(0, SplitSide::Right),
+            (Interval::Empty, closed)
+        );
+        assert_eq!(
+            Interval::Unbounded.split_at(3, SplitSide::Left),
+            (
+                Interval::UnboundedClosedRight { right: 3 },
+                Interval::UnboundedOpenLeft { left: 3 }
+            )
+        );
     }
 
     #[test]
-    fn test_bounded_complements() {
+    fn test_endpoints() {
         let bp = BoundPair::new(1, 5).unwrap();
-        let mut it = Interval::Closed { bound_pair: bp }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 1 }));
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 5 }));
-        assert_eq!(it.next(), None);
-
-        it = Interval::Open { bound_pair: bp }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedClosedRight { right: 1 }));
-        assert_eq!(it.next(), Some(Interval::UnboundedClosedLeft { left: 5 }));
-        assert_eq!(it.next(), None);
-
-        it = Interval::LeftHalfOpen { bound_pair: bp }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedClosedRight { right: 1 }));
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 5 }));
-        assert_eq!(it.next(), None);
-
-        it = Interval::RightHalfOpen { bound_pair: bp }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 1 }));
-        assert_eq!(it.next(), Some(Interval::UnboundedClosedLeft { left: 5 }));
-        assert_eq!(it.next(), None);
+        assert_eq!(
+            Interval::LeftHalfOpen { bound_pair: bp }
+                .endpoints()
+                .collect::<Vec<_>>(),
+            vec![(1, EndpointKind::LeftOpen), (5, EndpointKind::RightClosed)]
+        );
+        assert_eq!(
+            Interval::Singleton { at: 2 }
+                .endpoints()
+                .rev()
+                .collect::<Vec<_>>(),
+            vec![
+                (2, EndpointKind::RightClosed),
+                (2, EndpointKind::LeftClosed)
+            ]
+        );
+        assert_eq!(
+            Interval::UnboundedClosedRight { right: 5 }
+                .endpoints()
+                .collect::<Vec<_>>(),
+            vec![(5, EndpointKind::RightClosed)]
+        );
+        assert_eq!(Interval::<i32>::Empty.endpoints().next(), None);
+        assert!(EndpointKind::LeftOpen.is_left() && !EndpointKind::LeftOpen.is_closed());
+        assert!(!EndpointKind::RightClosed.is_left() && EndpointKind::RightClosed.is_closed());
     }
 
     #[test]
-    fn test_unbounded_complements() {
-        let mut it = Interval::UnboundedClosedRight { right: 5 }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 5 }));
-        assert_eq!(it.next(), None);
-
-        it = Interval::UnboundedOpenRight { right: 5 }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedClosedLeft { left: 5 }));
-        assert_eq!(it.next(), None);
-
-        it = Interval::UnboundedClosedLeft { left: 1 }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 1 }));
-        assert_eq!(it.next(), None);
-
-        it = Interval::UnboundedOpenLeft { left: 1 }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedClosedRight { right: 1 }));
-        assert_eq!(it.next(), None);
-
-        let mut it = Interval::Singleton { at: 2.0 }.complement();
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenRight { right: 2.0 }));
-        assert_eq!(it.next(), Some(Interval::UnboundedOpenLeft { left: 2.0 }));
-        assert_eq!(it.next(), None);
-
-        it = Interval::Unbounded.complement();
-        assert_eq!(it.next(), Some(Interval::Empty));
-        assert_eq!(it.next(), None);
-
-        it = Interval::Empty.complement();
-        assert_eq!(it.next(), Some(Interval::Unbounded));
-        assert_eq!(it.next(), None);
+    fn test_padded_to_min_width() {
+        let open = Interval::Open {
+            bound_pair: BoundPair::new(1.0, 2.0).unwrap(),
+        };
+        assert_eq!(
+            open.padded_to_min_width(3.0, PadAnchor::Right),
+            Interval::Open {
+                bound_pair: BoundPair::new(-1.0, 2.0).unwrap()
+            }
+        );
+        assert_eq!(
+            open.padded_to_min_width(2.0, PadAnchor::Center),
+            Interval::Open {
+                bound_pair: BoundPair::new(0.5, 2.5).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::Singleton { at: 4u32 }.padded_to_min_width(2, PadAnchor::Center),
+            Interval::Closed {
+                bound_pair: BoundPair::new(3, 5).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::Singleton { at: 4u32 }.padded_to_min_width(0, PadAnchor::Center),
+            Interval::Singleton { at: 4 }
+        );
+        assert_eq!(
+            Interval::UnboundedOpenLeft { left: 1 }.padded_to_min_width(5, PadAnchor::Left),
+            Interval::UnboundedOpenLeft { left: 1 }
+        );
+        assert_eq!(
+            Interval::<i32>::Empty.padded_to_min_width(5, PadAnchor::Left),
+            Interval::Empty
+        );
+        assert_eq!(open.padded_to_min_width(f64::NAN, PadAnchor::Left), open);
     }
 
     #[test]
-    fn interval_display() {
-        let bp = BoundPair::new(1, 5).ok_or("invalid BoundPair").unwrap();
-
-        assert_eq!(format!("{}", Interval::Closed { bound_pair: bp }), "[1..5]");
-        assert_eq!(format!("{}", Interval::Open { bound_pair: bp }), "(1..5)");
+    fn test_padded_to_min_width_at_domain_limits() {
+        let closed = |left, right| Interval::Closed {
+            bound_pair: BoundPair::new(left, right).unwrap(),
+        };
         assert_eq!(
-            format!("{}", Interval::LeftHalfOpen { bound_pair: bp }),
-            "(1..5]"
+            closed(0u32, 1).padded_to_min_width(5, PadAnchor::Center),
+            closed(0, 5)
         );
         assert_eq!(
-            format!("{}", Interval::RightHalfOpen { bound_pair: bp }),
-            "[1..5)"
+            closed(0u32, 1).padded_to_min_width(5, PadAnchor::Right),
+            closed(0, 5)
         );
         assert_eq!(
-            format!("{}", Interval::UnboundedClosedRight { right: 5 }),
-            "(←..5]"
+            Interval::Singleton { at: u32::MAX }.padded_to_min_width(4, PadAnchor::Left),
+            closed(u32::MAX - 4, u32::MAX)
         );
         assert_eq!(
-            format!("{}", Interval::UnboundedOpenRight { right: 5 }),
-            "(←..5)"
+            Interval::Singleton { at: 1u32 }.padded_to_min_width(u32::MAX, PadAnchor::Center),
+            closed(0, u32::MAX)
         );
+        let domain = closed(0u32, u32::MAX);
         assert_eq!(
-            format!("{}", Interval::UnboundedClosedLeft { left: 1 }),
-            "[1..→)"
+            domain.padded_to_min_width(u32::MAX, PadAnchor::Center),
+            domain
         );
+    }
+
+    #[test]
+    fn test_shrink_simplifies_variant() {
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(-40, 100).unwrap(),
+        };
+        let shrunk: Vec<_> = i.shrink().collect();
         assert_eq!(
-            format!("{}", Interval::UnboundedOpenLeft { left: 1 }),
-            "(1..→)"
+            &shrunk[..2],
+            &[Interval::Empty, Interval::Singleton { at: -40 }]
+        );
+        assert!(shrunk.len() > 2);
+        assert!(Interval::<i32>::Empty.shrink().next().is_none());
+        assert_eq!(
+            Interval::<i32>::Unbounded.shrink().collect::<Vec<_>>(),
+            vec![Interval::Empty]
+        );
+    }
+
+    #[quickcheck]
+    fn shrink_preserves_validity(i: Interval<i32>) -> bool {
+        i.shrink().all(|shrunk| match shrunk {
+            Interval::Closed { bound_pair }
+            | Interval::Open { bound_pair }
+            | Interval::LeftHalfOpen { bound_pair }
+            | Interval::RightHalfOpen { bound_pair } => bound_pair.left() < bound_pair.right(),
+            _ => true,
+        } && shrunk != i)
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(
+            " ( 1 .. 2 ] ".parse::<Interval<u8>>(),
+            Ok(Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(1, 2).unwrap()
+            })
+        );
+        for malformed in [
+            "", "[", "1..2", "[1..2", "(1]", "[←..2]", "(1..→]", "[a..2]", "{1..2}",
+        ] {
+            assert_eq!(
+                malformed.parse::<Interval<u8>>(),
+                Err(IntervalError::MalformedEncoding),
+                "{}",
+                malformed
+            );
+        }
+        assert_eq!(
+            "(2..2)".parse::<Interval<u8>>(),
+            Err(IntervalError::EqualBounds)
+        );
+        assert_eq!(
+            "[NaN..1.0]".parse::<Interval<f64>>(),
+            Err(IntervalError::IncomparableBounds)
         );
-        assert_eq!(format!("{}", Interval::Singleton { at: 3.0 }), "[3.0]");
-        assert_eq!(format!("{}", Interval::Unbounded::<u32> {}), "(←..→)");
-        assert_eq!(format!("{}", Interval::Empty::<u32> {}), "Empty");
     }
 
     #[quickcheck]
@@ -1140,6 +5645,293 @@ mod tests {
         TestResult::from_bool(double_complement == i)
     }
 
+    #[test]
+    fn test_chunks_exact_partition() {
+        let chunks: Vec<_> = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(0, 9).unwrap(),
+        }
+        .chunks(3, RemainderPolicy::Drop)
+        .collect();
+        assert_eq!(
+            chunks,
+            vec![
+                Interval::RightHalfOpen {
+                    bound_pair: BoundPair::new(0, 3).unwrap()
+                },
+                Interval::RightHalfOpen {
+                    bound_pair: BoundPair::new(3, 6).unwrap()
+                },
+                Interval::RightHalfOpen {
+                    bound_pair: BoundPair::new(6, 9).unwrap()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chunks_inherit_outer_bounds() {
+        let mut it = Interval::Open {
+            bound_pair: BoundPair::new(0.0, 2.5).unwrap(),
+        }
+        .chunks(1.0, RemainderPolicy::Keep);
+        assert_eq!(
+            it.next(),
+            Some(Interval::Open {
+                bound_pair: BoundPair::new(0.0, 1.0).unwrap()
+            })
+        );
+        assert_eq!(
+            it.next(),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(1.0, 2.0).unwrap()
+            })
+        );
+        assert_eq!(
+            it.next(),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(2.0, 2.5).unwrap()
+            })
+        );
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_chunks_remainder_policies() {
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(0, 5).unwrap(),
+        };
+        assert_eq!(
+            i.chunks(2, RemainderPolicy::Keep).last(),
+            Some(Interval::Closed {
+                bound_pair: BoundPair::new(4, 5).unwrap()
+            })
+        );
+        assert_eq!(
+            i.chunks(2, RemainderPolicy::Drop).last(),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(2, 4).unwrap()
+            })
+        );
+        assert_eq!(
+            i.chunks(2, RemainderPolicy::Extend).last(),
+            Some(Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(4, 6).unwrap()
+            })
+        );
+        // A chunk width exceeding the interval width leaves only the remainder
+        assert_eq!(i.chunks(10, RemainderPolicy::Drop).next(), None);
+        assert_eq!(i.chunks(10, RemainderPolicy::Keep).next(), Some(i));
+    }
+
+    #[test]
+    fn test_chunks_degenerate_inputs() {
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(0, 5).unwrap(),
+        };
+        assert_eq!(i.chunks(0, RemainderPolicy::Keep).next(), None);
+        assert_eq!(i.chunks(-1, RemainderPolicy::Keep).next(), None);
+        assert_eq!(
+            Interval::<i32>::Empty
+                .chunks(1, RemainderPolicy::Keep)
+                .next(),
+            None
+        );
+        assert_eq!(
+            Interval::UnboundedClosedRight { right: 5 }
+                .chunks(1, RemainderPolicy::Keep)
+                .next(),
+            None
+        );
+        assert_eq!(
+            Interval::Singleton { at: 1 }
+                .chunks(1, RemainderPolicy::Keep)
+                .collect::<Vec<_>>(),
+            vec![Interval::Singleton { at: 1 }]
+        );
+        assert_eq!(
+            Interval::Singleton { at: 1 }
+                .chunks(1, RemainderPolicy::Drop)
+                .next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_chunks_right_unbounded() {
+        let chunks: Vec<_> = Interval::UnboundedClosedLeft { left: 0u32 }
+            .chunks(10, RemainderPolicy::Keep)
+            .take(3)
+            .collect();
+        assert_eq!(
+            chunks[2],
+            Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(20, 30).unwrap()
+            }
+        );
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_numeric_constructors() {
+        assert_eq!(
+            Interval::<u8>::unit(),
+            Interval::Closed {
+                bound_pair: BoundPair::new(0, 1).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::<f64>::nonnegative(),
+            Interval::UnboundedClosedLeft { left: 0.0 }
+        );
+        assert_eq!(
+            Interval::<i64>::positive(),
+            Interval::UnboundedOpenLeft { left: 0 }
+        );
+        assert!(Interval::<f64>::nonnegative().contains(&Interval::unit()));
+        assert!(!Interval::<f64>::positive().contains(&Interval::unit()));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_symmetric() {
+        assert_eq!(
+            Interval::symmetric(1.5),
+            Interval::Closed {
+                bound_pair: BoundPair::new(-1.5, 1.5).unwrap()
+            }
+        );
+        assert_eq!(Interval::symmetric(-0.0), Interval::Singleton { at: 0.0 });
+        assert_eq!(Interval::symmetric(-1.5), Interval::Empty);
+        assert_eq!(Interval::symmetric(f64::NAN), Interval::Empty);
+    }
+
+    #[test]
+    fn test_remap_to() {
+        let from = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(10.0, 20.0).unwrap(),
+        };
+        let to = Interval::Open {
+            bound_pair: BoundPair::new(0.0, 1.0).unwrap(),
+        };
+        assert_eq!(from.remap_to(&to, 10.0), Some(0.0));
+        assert_eq!(from.remap_to(&to, 15.0), Some(0.5));
+        assert_eq!(from.remap_to(&to, 20.0), Some(1.0));
+        // Values outside of self are extrapolated
+        assert_eq!(from.remap_to(&to, 30.0), Some(2.0));
+        assert_eq!(to.remap_to(&from, 0.25), Some(12.5));
+    }
+
+    #[test]
+    fn test_remap_to_integer() {
+        let from = Interval::Closed {
+            bound_pair: BoundPair::new(0, 10).unwrap(),
+        };
+        let to = Interval::Closed {
+            bound_pair: BoundPair::new(100, 200).unwrap(),
+        };
+        assert_eq!(from.remap_to(&to, 5), Some(150));
+        assert_eq!(to.remap_to(&from, 155), Some(5));
+    }
+
+    #[test]
+    fn test_remap_to_unsupported() {
+        let bounded = Interval::Closed {
+            bound_pair: BoundPair::new(0, 10).unwrap(),
+        };
+        let singleton = Interval::Singleton { at: 3 };
+        assert_eq!(singleton.remap_to(&bounded, 3), None);
+        assert_eq!(bounded.remap_to(&singleton, 5), Some(3));
+        assert_eq!(bounded.remap_to(&Interval::Empty, 5), None);
+        assert_eq!(bounded.remap_to(&Interval::Unbounded, 5), None);
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 0 }.remap_to(&bounded, 5),
+            None
+        );
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_checked_translate() {
+        let bp = BoundPair::new(i64::MAX - 10, i64::MAX - 1).unwrap();
+        let i = Interval::LeftHalfOpen { bound_pair: bp };
+        assert_eq!(
+            i.checked_translate(1),
+            Some(Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(i64::MAX - 9, i64::MAX).unwrap()
+            })
+        );
+        assert_eq!(i.checked_translate(2), None);
+        assert_eq!(
+            Interval::UnboundedOpenRight {
+                right: i64::MIN + 1
+            }
+            .checked_translate(-1),
+            Some(Interval::UnboundedOpenRight { right: i64::MIN })
+        );
+        assert_eq!(
+            Interval::UnboundedOpenRight { right: i64::MIN }.checked_translate(-1),
+            None
+        );
+        assert_eq!(
+            Interval::<i64>::Unbounded.checked_translate(i64::MAX),
+            Some(Interval::Unbounded)
+        );
+        assert_eq!(
+            Interval::<i64>::Empty.checked_translate(1),
+            Some(Interval::Empty)
+        );
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_saturating_translate_collapse() {
+        let bp = BoundPair::new(i8::MIN + 1, i8::MIN + 3).unwrap();
+        assert_eq!(
+            Interval::Closed { bound_pair: bp }.saturating_translate(-100),
+            Interval::Singleton { at: i8::MIN }
+        );
+        assert_eq!(
+            Interval::RightHalfOpen { bound_pair: bp }.saturating_translate(-100),
+            Interval::Empty
+        );
+        assert_eq!(
+            Interval::Singleton { at: 120i8 }.saturating_translate(100),
+            Interval::Singleton { at: i8::MAX }
+        );
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_checked_and_saturating_expand() {
+        let i = Interval::Closed {
+            bound_pair: BoundPair::new(0u8, 250).unwrap(),
+        };
+        assert_eq!(i.checked_expand(1), None);
+        assert_eq!(
+            i.saturating_expand(10),
+            Interval::Closed {
+                bound_pair: BoundPair::new(0, u8::MAX).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::Closed {
+                bound_pair: BoundPair::new(-2i16, 2).unwrap()
+            }
+            .checked_expand(-2),
+            Some(Interval::Singleton { at: 0 })
+        );
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 5i16 }.checked_expand(2),
+            Some(Interval::UnboundedClosedLeft { left: 3 })
+        );
+        assert_eq!(
+            Interval::Singleton { at: 5i16 }.saturating_expand(1),
+            Interval::Closed {
+                bound_pair: BoundPair::new(4, 6).unwrap()
+            }
+        );
+    }
+
     #[test]
     fn test_intersection_edge_cases() {
         // Test intersection resulting in singleton
@@ -1283,17 +6075,15 @@ mod tests {
                     i2.right_bound(),
                 ];
 
-                TestResult::from_bool(test_points.iter().all(|&x| match x {
-                    Bound::Closed(v) | Bound::Open(v) => {
-                        if !i1_contains(v) && !i2_contains(v) {
+                TestResult::from_bool(test_points.iter().all(|&x| {
+                    match x {
+                        Bound::Closed(v) | Bound::Open(v) if !i1_contains(v) && !i2_contains(v) => {
                             intersection
                                 .complement()
                                 .any(|c| c.contains(&Interval::Singleton { at: v }))
-                        } else {
-                            true
                         }
+                        _ => true,
                     }
-                    _ => true,
                 }))
             }
         }
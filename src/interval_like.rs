@@ -0,0 +1,257 @@
+//! Abstraction over types which describe an interval
+//!
+//! [IntervalLike] is implemented by [Interval], the std range types, and the
+//! fixed kind structs of [crate::typed], so that Interval operations such as
+//! [Interval::contains] and [Interval::intersect] accept any of them.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::bound_pair::BoundPair;
+//! use intervals_general::interval::Interval;
+//! # fn main() -> std::result::Result<(), String> {
+//! let closed = Interval::Closed {
+//!     bound_pair: BoundPair::new(0, 10).ok_or("invalid BoundPair")?,
+//! };
+//! assert!(closed.contains(&(3..7)));
+//! assert!(!closed.contains(&(3..)));
+//! assert_eq!(
+//!     closed.intersect(&(5..=20)),
+//!     Interval::Closed {
+//!         bound_pair: BoundPair::new(5, 10).ok_or("invalid BoundPair")?
+//!     }
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::bound_pair::BoundPair;
+use crate::interval::Interval;
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// A type describing an interval over bound data type T
+///
+/// The trait is limited to conversion and bounds access.  The queries (e.g.
+/// contains, intersect, overlaps) remain inherent methods of [Interval]
+/// accepting any IntervalLike operand, so that a std range is queried via
+/// its Interval, e.g. `(3..7).to_interval().overlaps(&(5..9))`.  Provided
+/// query methods here would be ambiguous with those of
+/// [IntervalOps](crate::typed::IntervalOps).
+pub trait IntervalLike<T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    /// Convert to the equivalent Interval
+    fn to_interval(&self) -> Interval<T>;
+
+    /// The left and right bounds, or None if the interval is Empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval_like::IntervalLike;
+    /// use std::ops::Bound;
+    ///
+    /// assert_eq!((1..4).bounds(), Some((Bound::Included(1), Bound::Excluded(4))));
+    /// assert_eq!((..=4).bounds(), Some((Bound::Unbounded, Bound::Included(4))));
+    /// assert_eq!((4..1).bounds(), None);
+    /// ```
+    fn bounds(&self) -> Option<(Bound<T>, Bound<T>)> {
+        match self.to_interval() {
+            Interval::Closed { bound_pair } => Some((
+                Bound::Included(bound_pair.left),
+                Bound::Included(bound_pair.right),
+            )),
+            Interval::Open { bound_pair } => Some((
+                Bound::Excluded(bound_pair.left),
+                Bound::Excluded(bound_pair.right),
+            )),
+            Interval::LeftHalfOpen { bound_pair } => Some((
+                Bound::Excluded(bound_pair.left),
+                Bound::Included(bound_pair.right),
+            )),
+            Interval::RightHalfOpen { bound_pair } => Some((
+                Bound::Included(bound_pair.left),
+                Bound::Excluded(bound_pair.right),
+            )),
+            Interval::UnboundedClosedRight { right } => {
+                Some((Bound::Unbounded, Bound::Included(right)))
+            }
+            Interval::UnboundedOpenRight { right } => {
+                Some((Bound::Unbounded, Bound::Excluded(right)))
+            }
+            Interval::UnboundedClosedLeft { left } => {
+                Some((Bound::Included(left), Bound::Unbounded))
+            }
+            Interval::UnboundedOpenLeft { left } => Some((Bound::Excluded(left), Bound::Unbounded)),
+            Interval::Singleton { at } => Some((Bound::Included(at), Bound::Included(at))),
+            Interval::Unbounded => Some((Bound::Unbounded, Bound::Unbounded)),
+            Interval::Empty => None,
+        }
+    }
+}
+
+impl<T> IntervalLike<T> for Interval<T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    fn to_interval(&self) -> Interval<T> {
+        *self
+    }
+}
+
+/// `start..end` is RightHalfOpen, or Empty unless start < end
+impl<T> IntervalLike<T> for Range<T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    fn to_interval(&self) -> Interval<T> {
+        match BoundPair::new(self.start, self.end) {
+            Some(bound_pair) => Interval::RightHalfOpen { bound_pair },
+            None => Interval::Empty,
+        }
+    }
+}
+
+/// `start..=end` is Closed, Singleton if start == end, or Empty if start > end
+///
+/// An exhausted RangeInclusive iterator is still treated as its original
+/// bounds.
+impl<T> IntervalLike<T> for RangeInclusive<T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    fn to_interval(&self) -> Interval<T> {
+        let (start, end) = (*self.start(), *self.end());
+        match BoundPair::new(start, end) {
+            Some(bound_pair) => Interval::Closed { bound_pair },
+            None if start == end => Interval::Singleton { at: start },
+            None => Interval::Empty,
+        }
+    }
+}
+
+/// `start..` is UnboundedClosedLeft
+impl<T> IntervalLike<T> for RangeFrom<T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    fn to_interval(&self) -> Interval<T> {
+        Interval::UnboundedClosedLeft { left: self.start }
+    }
+}
+
+/// `..end` is UnboundedOpenRight
+impl<T> IntervalLike<T> for RangeTo<T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    fn to_interval(&self) -> Interval<T> {
+        Interval::UnboundedOpenRight { right: self.end }
+    }
+}
+
+/// `..=end` is UnboundedClosedRight
+impl<T> IntervalLike<T> for RangeToInclusive<T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    fn to_interval(&self) -> Interval<T> {
+        Interval::UnboundedClosedRight { right: self.end }
+    }
+}
+
+/// `..` is Unbounded
+impl<T> IntervalLike<T> for RangeFull
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    fn to_interval(&self) -> Interval<T> {
+        Interval::Unbounded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::all_variant_examples;
+
+    #[test]
+    fn test_range_conversions() {
+        let bp = BoundPair::new(1, 5).unwrap();
+        assert_eq!(
+            (1..5).to_interval(),
+            Interval::RightHalfOpen { bound_pair: bp }
+        );
+        assert_eq!((5..5).to_interval(), Interval::Empty);
+        assert_eq!((1..=5).to_interval(), Interval::Closed { bound_pair: bp });
+        assert_eq!((5..=5).to_interval(), Interval::Singleton { at: 5 });
+        let (high, low) = (6, 5);
+        assert_eq!((high..=low).to_interval(), Interval::Empty);
+        assert_eq!(
+            (1..).to_interval(),
+            Interval::UnboundedClosedLeft { left: 1 }
+        );
+        assert_eq!(
+            (..5).to_interval(),
+            Interval::UnboundedOpenRight { right: 5 }
+        );
+        assert_eq!(
+            (..=5).to_interval(),
+            Interval::UnboundedClosedRight { right: 5 }
+        );
+        assert_eq!(IntervalLike::<i32>::to_interval(&..), Interval::Unbounded);
+    }
+
+    #[test]
+    fn test_nan_range_is_empty() {
+        assert_eq!((f64::NAN..1.0).to_interval(), Interval::Empty);
+        assert_eq!((0.0..=f64::NAN).to_interval(), Interval::Empty);
+    }
+
+    #[test]
+    fn test_bounds_roundtrip() {
+        for interval in all_variant_examples(1, 5).unwrap() {
+            let rebuilt = match interval.bounds() {
+                None => Interval::Empty,
+                Some(bounds) => {
+                    let to_bound = |bound| match bound {
+                        Bound::Included(value) => crate::interval::Bound::Closed(value),
+                        Bound::Excluded(value) => crate::interval::Bound::Open(value),
+                        Bound::Unbounded => crate::interval::Bound::Unbounded,
+                    };
+                    Interval::from_bounds(to_bound(bounds.0), to_bound(bounds.1))
+                }
+            };
+            assert_eq!(rebuilt, interval);
+        }
+    }
+
+    #[test]
+    fn test_queries_accept_ranges() {
+        let i = Interval::Open {
+            bound_pair: BoundPair::new(0, 10).unwrap(),
+        };
+        assert!(i.contains(&(1..10)));
+        assert!(!i.contains(&(1..=10)));
+        assert!(!i.contains(&(0..5)));
+        assert_eq!(
+            i.intersect(&(..5)),
+            Interval::Open {
+                bound_pair: BoundPair::new(0, 5).unwrap()
+            }
+        );
+        assert_eq!(i.intersect(&(10..20)), Interval::Empty);
+        assert_eq!(i.intersect(&..), i);
+        assert!((3..7).to_interval().overlaps(&(5..9)));
+        assert!(!(3..7).to_interval().overlaps(&(7..9)));
+    }
+}
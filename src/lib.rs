@@ -28,12 +28,38 @@
 //!
 //! #### Implementation Constraints
 //!
-//! 1. no_std support
 //! 1. No use of of panic, assert
 //! 1. Minimize error handling by design
 //! 1. Make the library hard to use incorrectly
 
+pub mod blocks;
 pub mod bound_pair;
+pub mod bytes;
+#[cfg(feature = "chrono")]
+pub mod calendar;
+pub mod discrete;
+pub mod domain;
+pub mod error;
+pub mod fixed_point;
+pub mod float;
+pub mod generators;
+#[cfg(feature = "async-graphql")]
+pub mod graphql;
 pub mod interval;
+pub mod interval_like;
+pub mod numeric;
+#[cfg(feature = "prost")]
+pub mod proto;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "num-rational")]
+pub mod rational;
+pub mod recurring;
+#[cfg(feature = "serde")]
+pub mod serde_compat;
+pub mod time;
+pub mod typed;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use interval::Interval;
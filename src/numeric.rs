@@ -0,0 +1,376 @@
+//! Numerical methods over f64 Intervals
+//!
+//! An Interval is the natural carrier for both the domain of a numerical
+//! method and the enclosure of its result, so the methods here take their
+//! domain as self and report results as Intervals.
+
+use crate::bound_pair::BoundPair;
+use crate::interval::Interval;
+
+/// Quadrature rule refined by [Interval::integrate]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum QuadratureMethod {
+    /// Adaptive trapezoid rule, second order
+    Trapezoid,
+    /// Adaptive Simpson rule, fourth order (exact for cubics)
+    Simpson,
+}
+
+/// Result of [Interval::integrate]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Integral {
+    /// The estimated value of the integral
+    pub value: f64,
+    /// The estimated enclosure of the integral, `[value - error, value + error]`
+    pub estimate: Interval<f64>,
+}
+
+// Maximum subdivision depth, bounding evaluations of the integrand to about
+// 2^(MAX_DEPTH + 2)
+const MAX_DEPTH: u32 = 20;
+
+impl QuadratureMethod {
+    // Apply the rule over [a, b] given f(a), f((a + b) / 2) and f(b)
+    fn rule(self, a: f64, b: f64, fa: f64, fm: f64, fb: f64) -> f64 {
+        match self {
+            QuadratureMethod::Trapezoid => (b - a) / 2.0 * (fa + fb),
+            QuadratureMethod::Simpson => (b - a) / 6.0 * (fa + 4.0 * fm + fb),
+        }
+    }
+
+    // Denominator converting the difference between successive refinements to
+    // an estimate of the refined error (2^order - 1)
+    fn error_factor(self) -> f64 {
+        match self {
+            QuadratureMethod::Trapezoid => 3.0,
+            QuadratureMethod::Simpson => 15.0,
+        }
+    }
+}
+
+// A segment of the domain awaiting refinement
+struct Segment {
+    left: f64,
+    right: f64,
+    f_left: f64,
+    f_mid: f64,
+    f_right: f64,
+    whole: f64,
+}
+
+// Refine the segment until the error estimate meets tolerance, returning the
+// value and the error estimate
+fn refine<F>(
+    f: &mut F,
+    method: QuadratureMethod,
+    segment: Segment,
+    tolerance: f64,
+    depth: u32,
+) -> (f64, f64)
+where
+    F: FnMut(f64) -> f64,
+{
+    let Segment {
+        left,
+        right,
+        f_left,
+        f_mid,
+        f_right,
+        whole,
+    } = segment;
+    let mid = left + (right - left) / 2.0;
+    let (left_mid, right_mid) = (left + (mid - left) / 2.0, mid + (right - mid) / 2.0);
+    let (f_left_mid, f_right_mid) = (f(left_mid), f(right_mid));
+    let left_half = method.rule(left, mid, f_left, f_left_mid, f_mid);
+    let right_half = method.rule(mid, right, f_mid, f_right_mid, f_right);
+
+    let error = (left_half + right_half - whole).abs() / method.error_factor();
+    // A NaN error never meets tolerance, so stop refining immediately
+    if depth == 0 || error <= tolerance || error.is_nan() {
+        return (left_half + right_half, error);
+    }
+    let (left_value, left_error) = refine(
+        f,
+        method,
+        Segment {
+            left,
+            right: mid,
+            f_left,
+            f_mid: f_left_mid,
+            f_right: f_mid,
+            whole: left_half,
+        },
+        tolerance / 2.0,
+        depth - 1,
+    );
+    let (right_value, right_error) = refine(
+        f,
+        method,
+        Segment {
+            left: mid,
+            right,
+            f_left: f_mid,
+            f_mid: f_right_mid,
+            f_right,
+            whole: right_half,
+        },
+        tolerance / 2.0,
+        depth - 1,
+    );
+    (left_value + right_value, left_error + right_error)
+}
+
+impl Interval<f64> {
+    /// Integrate f over self by adaptive quadrature
+    ///
+    /// The domain is recursively bisected until the estimated error of each
+    /// piece meets its share of tolerance (or a maximum depth is reached, in
+    /// which case the reported error may exceed tolerance).  The integrand is
+    /// evaluated at the bounds even where they are open, as bound openness
+    /// does not affect the integral.  Returns None for unbounded or Empty
+    /// domains, for a negative or NaN tolerance, or if f yields NaN; the
+    /// integral over a Singleton is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// use intervals_general::numeric::QuadratureMethod;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let domain = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, std::f64::consts::PI).ok_or("invalid BoundPair")?,
+    /// };
+    /// let integral = domain
+    ///     .integrate(f64::sin, QuadratureMethod::Simpson, 1e-10)
+    ///     .ok_or("integration failed")?;
+    /// assert!((integral.value - 2.0).abs() < 1e-9);
+    /// assert!(integral.estimate.contains(&Interval::Singleton { at: integral.value }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integrate<F>(
+        &self,
+        mut f: F,
+        method: QuadratureMethod,
+        tolerance: f64,
+    ) -> Option<Integral>
+    where
+        F: FnMut(f64) -> f64,
+    {
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return None;
+        }
+        let (left, right) = match *self {
+            Interval::Closed { bound_pair }
+            | Interval::Open { bound_pair }
+            | Interval::LeftHalfOpen { bound_pair }
+            | Interval::RightHalfOpen { bound_pair } => (bound_pair.left, bound_pair.right),
+            Interval::Singleton { .. } => {
+                return Some(Integral {
+                    value: 0.0,
+                    estimate: Interval::Singleton { at: 0.0 },
+                })
+            }
+            _ => return None,
+        };
+
+        let mid = left + (right - left) / 2.0;
+        let (f_left, f_mid, f_right) = (f(left), f(mid), f(right));
+        let segment = Segment {
+            left,
+            right,
+            f_left,
+            f_mid,
+            f_right,
+            whole: method.rule(left, right, f_left, f_mid, f_right),
+        };
+        let (value, error) = refine(&mut f, method, segment, tolerance, MAX_DEPTH);
+        if value.is_nan() || error.is_nan() {
+            return None;
+        }
+        Some(Integral {
+            value,
+            estimate: Interval::around(value, 0.0, error),
+        })
+    }
+
+    /// Enclose the minimizer of a unimodal f over self by golden-section search
+    ///
+    /// The enclosure is narrowed by the golden ratio per evaluation of f until
+    /// its width is at most tolerance (or can no longer be narrowed in f64
+    /// precision), and is returned as a Closed interval (or a Singleton once
+    /// collapsed).  If f is not unimodal over self, the enclosure contains a
+    /// local minimizer.  Open bounds are treated as closed, so a minimizer at
+    /// an open bound (an unattained infimum) is enclosed at that bound.
+    /// Returns None for unbounded or Empty domains, or for a non-positive or
+    /// NaN tolerance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let domain = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, 5.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let minimizer = domain
+    ///     .minimize_unimodal(|x| (x - 2.0) * (x - 2.0), 1e-6)
+    ///     .ok_or("invalid domain")?;
+    /// assert!(minimizer.contains(&Interval::Singleton { at: 2.0 }));
+    /// assert!(minimizer.width().is_some_and(|width| width <= 1e-6));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn minimize_unimodal<F>(&self, mut f: F, tolerance: f64) -> Option<Interval<f64>>
+    where
+        F: FnMut(f64) -> f64,
+    {
+        if tolerance.is_nan() || tolerance <= 0.0 {
+            return None;
+        }
+        let (mut left, mut right) = match *self {
+            Interval::Closed { bound_pair }
+            | Interval::Open { bound_pair }
+            | Interval::LeftHalfOpen { bound_pair }
+            | Interval::RightHalfOpen { bound_pair } => (bound_pair.left, bound_pair.right),
+            Interval::Singleton { at } => return Some(Interval::Singleton { at }),
+            _ => return None,
+        };
+
+        let inv_phi = (5.0f64.sqrt() - 1.0) / 2.0;
+        let mut lower = right - inv_phi * (right - left);
+        let mut upper = left + inv_phi * (right - left);
+        let (mut f_lower, mut f_upper) = (f(lower), f(upper));
+        while right - left > tolerance {
+            let width = right - left;
+            if f_lower < f_upper {
+                right = upper;
+                (upper, f_upper) = (lower, f_lower);
+                lower = right - inv_phi * (right - left);
+                f_lower = f(lower);
+            } else {
+                left = lower;
+                (lower, f_lower) = (upper, f_upper);
+                upper = left + inv_phi * (right - left);
+                f_upper = f(upper);
+            }
+            if right - left >= width {
+                break;
+            }
+        }
+
+        Some(match BoundPair::new(left, right) {
+            Some(bound_pair) => Interval::Closed { bound_pair },
+            None => Interval::Singleton { at: left },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn closed(left: f64, right: f64) -> Interval<f64> {
+        Interval::Closed {
+            bound_pair: BoundPair::new(left, right).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_simpson_exact_for_cubics() {
+        let integral = closed(-1.0, 2.0)
+            .integrate(|x| x * x * x - 2.0 * x, QuadratureMethod::Simpson, 1e-12)
+            .unwrap();
+        assert!((integral.value - 0.75).abs() < 1e-12);
+        assert_eq!(integral.estimate.width().map(|w| w < 1e-12), Some(true));
+    }
+
+    #[test]
+    fn test_trapezoid_meets_tolerance() {
+        let integral = closed(0.0, 1.0)
+            .integrate(|x| x * x, QuadratureMethod::Trapezoid, 1e-8)
+            .unwrap();
+        assert!((integral.value - 1.0 / 3.0).abs() < 1e-7);
+        assert!(integral
+            .estimate
+            .contains(&Interval::Singleton { at: 1.0 / 3.0 }));
+    }
+
+    #[test]
+    fn test_adaptive_refinement() {
+        // Sharply peaked integrand, integral of 1 / (1 + (100 x)^2) over [-1, 1]
+        let exact = 2.0 * 100.0f64.atan() / 100.0;
+        let mut evaluations = 0;
+        let integral = closed(-1.0, 1.0)
+            .integrate(
+                |x| {
+                    evaluations += 1;
+                    1.0 / (1.0 + (100.0 * x) * (100.0 * x))
+                },
+                QuadratureMethod::Simpson,
+                1e-10,
+            )
+            .unwrap();
+        assert!((integral.value - exact).abs() < 1e-9);
+        assert!(evaluations < 10_000);
+    }
+
+    #[test]
+    fn test_integrate_degenerate_domains() {
+        let method = QuadratureMethod::Simpson;
+        assert_eq!(
+            Interval::Singleton { at: 1.0 }
+                .integrate(|x| x, method, 1e-6)
+                .map(|i| i.value),
+            Some(0.0)
+        );
+        assert!(Interval::UnboundedClosedLeft { left: 0.0 }
+            .integrate(|x| x, method, 1e-6)
+            .is_none());
+        assert!(Interval::Empty.integrate(|x| x, method, 1e-6).is_none());
+        assert!(closed(0.0, 1.0)
+            .integrate(|x| x, method, f64::NAN)
+            .is_none());
+        assert!(closed(-1.0, 1.0)
+            .integrate(|x| (x - 0.5).sqrt(), method, 1e-6)
+            .is_none());
+    }
+
+    #[test]
+    fn test_minimize_unimodal_interior() {
+        let minimizer = closed(-3.0, 1.0)
+            .minimize_unimodal(|x| (x + 1.25).abs(), 1e-9)
+            .unwrap();
+        assert!(minimizer.contains(&Interval::Singleton { at: -1.25 }));
+        assert!(minimizer.width().unwrap() <= 1e-9);
+    }
+
+    #[test]
+    fn test_minimize_unimodal_at_bound() {
+        let domain = Interval::Open {
+            bound_pair: BoundPair::new(0.0, 1.0).unwrap(),
+        };
+        let minimizer = domain.minimize_unimodal(|x| x, 1e-6).unwrap();
+        assert!(minimizer.contains(&Interval::Singleton { at: 0.0 }));
+    }
+
+    #[test]
+    fn test_minimize_unimodal_precision_limit() {
+        let minimizer = closed(0.0, 1.0)
+            .minimize_unimodal(|x| (x - 0.3) * (x - 0.3), f64::MIN_POSITIVE)
+            .unwrap();
+        assert!(minimizer.width().unwrap() < 1e-7);
+    }
+
+    #[test]
+    fn test_minimize_unimodal_degenerate_domains() {
+        assert_eq!(
+            Interval::Singleton { at: 2.0 }.minimize_unimodal(|x| x, 1e-6),
+            Some(Interval::Singleton { at: 2.0 })
+        );
+        assert!(Interval::Unbounded.minimize_unimodal(|x| x, 1e-6).is_none());
+        assert!(closed(0.0, 1.0).minimize_unimodal(|x| x, 0.0).is_none());
+    }
+}
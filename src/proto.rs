@@ -0,0 +1,292 @@
+//! Canonical protobuf messages for Intervals
+//!
+//! Defines prost messages equivalent to the following schema, so services
+//! exchanging Intervals over gRPC share one encoding and one set of openness
+//! conventions:
+//!
+//! ```proto
+//! enum IntervalKind {
+//!   INTERVAL_KIND_UNSPECIFIED = 0;
+//!   INTERVAL_KIND_CLOSED = 1;
+//!   INTERVAL_KIND_OPEN = 2;
+//!   INTERVAL_KIND_LEFT_HALF_OPEN = 3;
+//!   INTERVAL_KIND_RIGHT_HALF_OPEN = 4;
+//!   INTERVAL_KIND_UNBOUNDED_CLOSED_RIGHT = 5;
+//!   INTERVAL_KIND_UNBOUNDED_OPEN_RIGHT = 6;
+//!   INTERVAL_KIND_UNBOUNDED_CLOSED_LEFT = 7;
+//!   INTERVAL_KIND_UNBOUNDED_OPEN_LEFT = 8;
+//!   INTERVAL_KIND_SINGLETON = 9;
+//!   INTERVAL_KIND_UNBOUNDED = 10;
+//!   INTERVAL_KIND_EMPTY = 11;
+//! }
+//!
+//! message IntervalF64 {
+//!   IntervalKind kind = 1;
+//!   optional double left = 2;
+//!   optional double right = 3;
+//! }
+//!
+//! message IntervalI64 {
+//!   IntervalKind kind = 1;
+//!   optional int64 left = 2;
+//!   optional int64 right = 3;
+//! }
+//! ```
+//!
+//! A bound is present exactly when the kind is bounded on that side, and a
+//! Singleton carries its point as both left and right.  Decoding validates
+//! the message, rejecting unknown kinds, missing or superfluous bounds, and
+//! bounds violating the BoundPair invariant.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::bound_pair::BoundPair;
+//! use intervals_general::interval::Interval;
+//! use intervals_general::proto::IntervalI64;
+//! use prost::Message;
+//! # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! let interval = Interval::LeftHalfOpen {
+//!     bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+//! };
+//! let bytes = IntervalI64::from(interval).encode_to_vec();
+//! let decoded = Interval::try_from(IntervalI64::decode(bytes.as_slice())?)?;
+//! assert_eq!(decoded, interval);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::bound_pair::BoundPair;
+use crate::error::IntervalError;
+use crate::interval::Interval;
+
+/// The Interval variant carried by a message
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum IntervalKind {
+    Unspecified = 0,
+    Closed = 1,
+    Open = 2,
+    LeftHalfOpen = 3,
+    RightHalfOpen = 4,
+    UnboundedClosedRight = 5,
+    UnboundedOpenRight = 6,
+    UnboundedClosedLeft = 7,
+    UnboundedOpenLeft = 8,
+    Singleton = 9,
+    Unbounded = 10,
+    Empty = 11,
+}
+
+// Split an Interval into its message kind and optional bounds
+fn encode<T>(interval: Interval<T>) -> (IntervalKind, Option<T>, Option<T>)
+where
+    T: Copy,
+{
+    match interval {
+        Interval::Closed { bound_pair } => (
+            IntervalKind::Closed,
+            Some(bound_pair.left),
+            Some(bound_pair.right),
+        ),
+        Interval::Open { bound_pair } => (
+            IntervalKind::Open,
+            Some(bound_pair.left),
+            Some(bound_pair.right),
+        ),
+        Interval::LeftHalfOpen { bound_pair } => (
+            IntervalKind::LeftHalfOpen,
+            Some(bound_pair.left),
+            Some(bound_pair.right),
+        ),
+        Interval::RightHalfOpen { bound_pair } => (
+            IntervalKind::RightHalfOpen,
+            Some(bound_pair.left),
+            Some(bound_pair.right),
+        ),
+        Interval::UnboundedClosedRight { right } => {
+            (IntervalKind::UnboundedClosedRight, None, Some(right))
+        }
+        Interval::UnboundedOpenRight { right } => {
+            (IntervalKind::UnboundedOpenRight, None, Some(right))
+        }
+        Interval::UnboundedClosedLeft { left } => {
+            (IntervalKind::UnboundedClosedLeft, Some(left), None)
+        }
+        Interval::UnboundedOpenLeft { left } => (IntervalKind::UnboundedOpenLeft, Some(left), None),
+        Interval::Singleton { at } => (IntervalKind::Singleton, Some(at), Some(at)),
+        Interval::Unbounded => (IntervalKind::Unbounded, None, None),
+        Interval::Empty => (IntervalKind::Empty, None, None),
+    }
+}
+
+// Validate message fields and rebuild the Interval
+fn decode<T>(kind: i32, left: Option<T>, right: Option<T>) -> Result<Interval<T>, IntervalError>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    let kind = IntervalKind::try_from(kind).map_err(|_| IntervalError::MalformedEncoding)?;
+    match (kind, left, right) {
+        (IntervalKind::Closed, Some(left), Some(right)) => Ok(Interval::Closed {
+            bound_pair: BoundPair::try_new(left, right)?,
+        }),
+        (IntervalKind::Open, Some(left), Some(right)) => Ok(Interval::Open {
+            bound_pair: BoundPair::try_new(left, right)?,
+        }),
+        (IntervalKind::LeftHalfOpen, Some(left), Some(right)) => Ok(Interval::LeftHalfOpen {
+            bound_pair: BoundPair::try_new(left, right)?,
+        }),
+        (IntervalKind::RightHalfOpen, Some(left), Some(right)) => Ok(Interval::RightHalfOpen {
+            bound_pair: BoundPair::try_new(left, right)?,
+        }),
+        (IntervalKind::UnboundedClosedRight, None, Some(right)) => {
+            Ok(Interval::UnboundedClosedRight { right })
+        }
+        (IntervalKind::UnboundedOpenRight, None, Some(right)) => {
+            Ok(Interval::UnboundedOpenRight { right })
+        }
+        (IntervalKind::UnboundedClosedLeft, Some(left), None) => {
+            Ok(Interval::UnboundedClosedLeft { left })
+        }
+        (IntervalKind::UnboundedOpenLeft, Some(left), None) => {
+            Ok(Interval::UnboundedOpenLeft { left })
+        }
+        (IntervalKind::Singleton, Some(left), Some(right)) if left == right => {
+            Ok(Interval::Singleton { at: left })
+        }
+        (IntervalKind::Unbounded, None, None) => Ok(Interval::Unbounded),
+        (IntervalKind::Empty, None, None) => Ok(Interval::Empty),
+        _ => Err(IntervalError::MalformedEncoding),
+    }
+}
+
+macro_rules! interval_message {
+    ($(#[$meta:meta])* $name:ident, $t:ty, $scalar:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, prost::Message)]
+        pub struct $name {
+            /// The [IntervalKind] of the encoded Interval
+            #[prost(enumeration = "IntervalKind", tag = "1")]
+            pub kind: i32,
+            /// The left bound, present when the kind is left bounded
+            #[prost($scalar, optional, tag = "2")]
+            pub left: Option<$t>,
+            /// The right bound, present when the kind is right bounded
+            #[prost($scalar, optional, tag = "3")]
+            pub right: Option<$t>,
+        }
+
+        impl From<Interval<$t>> for $name {
+            fn from(interval: Interval<$t>) -> $name {
+                let (kind, left, right) = encode(interval);
+                $name {
+                    kind: kind as i32,
+                    left,
+                    right,
+                }
+            }
+        }
+
+        impl TryFrom<$name> for Interval<$t> {
+            type Error = IntervalError;
+
+            fn try_from(message: $name) -> Result<Interval<$t>, IntervalError> {
+                decode(message.kind, message.left, message.right)
+            }
+        }
+    };
+}
+
+interval_message!(
+    /// Protobuf message for `Interval<f64>`
+    IntervalF64,
+    f64,
+    double
+);
+
+interval_message!(
+    /// Protobuf message for `Interval<i64>`
+    IntervalI64,
+    i64,
+    int64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::all_variant_examples;
+    use prost::Message;
+
+    #[test]
+    fn test_roundtrip_all_variants() {
+        for interval in all_variant_examples(-1.5, 2.5).unwrap() {
+            let bytes = IntervalF64::from(interval).encode_to_vec();
+            let message = IntervalF64::decode(bytes.as_slice()).unwrap();
+            assert_eq!(Interval::try_from(message), Ok(interval));
+        }
+        for interval in all_variant_examples(i64::MIN, i64::MAX).unwrap() {
+            assert_eq!(
+                Interval::try_from(IntervalI64::from(interval)),
+                Ok(interval)
+            );
+        }
+    }
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(
+            IntervalI64::from(Interval::UnboundedOpenLeft { left: 3 }),
+            IntervalI64 {
+                kind: IntervalKind::UnboundedOpenLeft as i32,
+                left: Some(3),
+                right: None,
+            }
+        );
+        assert_eq!(
+            IntervalI64::from(Interval::Singleton { at: 3 }),
+            IntervalI64 {
+                kind: IntervalKind::Singleton as i32,
+                left: Some(3),
+                right: Some(3),
+            }
+        );
+    }
+
+    #[test]
+    fn test_reject_malformed() {
+        let message = |kind: IntervalKind, left, right| IntervalI64 {
+            kind: kind as i32,
+            left,
+            right,
+        };
+        assert_eq!(
+            Interval::try_from(IntervalI64::default()),
+            Err(IntervalError::MalformedEncoding)
+        );
+        assert_eq!(
+            Interval::try_from(IntervalI64 {
+                kind: 42,
+                left: None,
+                right: None
+            }),
+            Err(IntervalError::MalformedEncoding)
+        );
+        assert_eq!(
+            Interval::try_from(message(IntervalKind::Closed, Some(1), None)),
+            Err(IntervalError::MalformedEncoding)
+        );
+        assert_eq!(
+            Interval::try_from(message(IntervalKind::Empty, Some(1), None)),
+            Err(IntervalError::MalformedEncoding)
+        );
+        assert_eq!(
+            Interval::try_from(message(IntervalKind::Singleton, Some(1), Some(2))),
+            Err(IntervalError::MalformedEncoding)
+        );
+        assert_eq!(
+            Interval::try_from(message(IntervalKind::Open, Some(2), Some(1))),
+            Err(IntervalError::ReversedBounds)
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Random partitions of Intervals
+//!
+//! Enabled by the `rand` feature, for the generation of synthetic workloads
+//! (e.g. benchmark inputs) with realistic, unevenly sized pieces.
+
+use crate::float::FloatBound;
+use crate::interval::{Bound, Interval};
+use rand::distr::{Distribution, StandardUniform};
+use rand::{Rng, RngExt};
+
+impl<T> Interval<T>
+where
+    T: FloatBound,
+    StandardUniform: Distribution<T>,
+{
+    /// Partition the interval into n pieces of random width
+    ///
+    /// Cuts the interval at n - 1 points drawn uniformly at random, so that
+    /// the piece widths follow the uniform spacings (i.e. flat Dirichlet)
+    /// distribution.  As with [chunks](Interval::chunks), pieces are
+    /// right-half-open, save that the first piece inherits the left bound of
+    /// self and the final piece inherits the right bound of self, so that
+    /// the pieces exactly partition self in ascending order.  Coinciding
+    /// cut points (vanishingly rare) yield Empty pieces.
+    ///
+    /// Returns None if n is zero or self lacks finite bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let interval = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, 100.0).ok_or("invalid BoundPair")?,
+    /// };
+    /// let pieces = interval.split_random(&mut rng, 4).ok_or("not splittable")?;
+    /// assert_eq!(pieces.len(), 4);
+    /// let total: f64 = pieces.iter().filter_map(|piece| piece.width()).sum();
+    /// assert!((total - 100.0).abs() < 1e-9);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split_random<R>(&self, rng: &mut R, n: usize) -> Option<Vec<Interval<T>>>
+    where
+        R: Rng + ?Sized,
+    {
+        let (left, right) = self.finite_bounds()?;
+        let width = right - left;
+        if n == 0 || width >= T::INFINITY {
+            return None;
+        }
+
+        let mut cuts: Vec<T> = (1..n).map(|_| left + rng.random::<T>() * width).collect();
+        cuts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut pieces = Vec::with_capacity(n);
+        let mut piece_left = self.left_bound();
+        for cut in cuts {
+            pieces.push(Interval::from_bounds(piece_left, Bound::Open(cut)));
+            piece_left = Bound::Closed(cut);
+        }
+        pieces.push(Interval::from_bounds(piece_left, self.right_bound()));
+        Some(pieces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound_pair::BoundPair;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_split_random_partitions() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let interval = Interval::LeftHalfOpen {
+            bound_pair: BoundPair::new(-5.0, 5.0).unwrap(),
+        };
+        for n in 1..20 {
+            let pieces = interval.split_random(&mut rng, n).unwrap();
+            assert_eq!(pieces.len(), n);
+            assert_eq!(pieces[0].left_bound(), interval.left_bound());
+            assert_eq!(pieces[n - 1].right_bound(), interval.right_bound());
+            for pair in pieces.windows(2) {
+                assert_eq!(pair[0].intersect(&pair[1]), Interval::Empty);
+                match (pair[0].right_bound(), pair[1].left_bound()) {
+                    (Bound::Open(a), Bound::Closed(b)) => assert_eq!(a, b),
+                    bounds => panic!("pieces must abut, got {:?}", bounds),
+                }
+            }
+            assert!(pieces.iter().all(|piece| interval.contains(piece)));
+        }
+    }
+
+    #[test]
+    fn test_split_random_single_piece() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let interval = Interval::Open {
+            bound_pair: BoundPair::new(0.0f32, 1.0).unwrap(),
+        };
+        assert_eq!(interval.split_random(&mut rng, 1), Some(vec![interval]));
+    }
+
+    #[test]
+    fn test_split_random_unsplittable() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let interval = Interval::Closed {
+            bound_pair: BoundPair::new(0.0, 1.0).unwrap(),
+        };
+        assert_eq!(interval.split_random(&mut rng, 0), None);
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: 0.0 }.split_random(&mut rng, 3),
+            None
+        );
+        assert_eq!(Interval::<f64>::Empty.split_random(&mut rng, 3), None);
+        let infinite = Interval::Closed {
+            bound_pair: BoundPair::new(f64::NEG_INFINITY, f64::INFINITY).unwrap(),
+        };
+        assert_eq!(infinite.split_random(&mut rng, 3), None);
+    }
+}
@@ -0,0 +1,85 @@
+//! Exact Intervals over rational numbers
+//!
+//! `Interval<Ratio<i64>>` (and the other num-rational `Ratio` types) support
+//! the interval operations as any other bound type, with no floating point
+//! rounding: widths, translations, expansions and chunking are exact, and
+//! [Interval::midpoint](crate::interval::Interval::midpoint) is the exact center.  Ratio arithmetic panics on
+//! overflow; the checked operations (e.g. [Interval::checked_translate](crate::interval::Interval::checked_translate))
+//! return None instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::bound_pair::BoundPair;
+//! use intervals_general::interval::Interval;
+//! use num_rational::Ratio;
+//! # fn main() -> std::result::Result<(), String> {
+//! let third = Ratio::new(1i64, 3);
+//! let interval = Interval::Closed {
+//!     bound_pair: BoundPair::new(third, third * 2).ok_or("invalid BoundPair")?,
+//! };
+//! assert_eq!(interval.width(), Some(third));
+//! assert_eq!(interval.midpoint(), Some(Ratio::new(1, 2)));
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(test)]
+mod tests {
+    use crate::bound_pair::BoundPair;
+    use crate::interval::{Interval, RemainderPolicy};
+    use num_rational::Ratio;
+
+    fn ratio(numer: i64, denom: i64) -> Ratio<i64> {
+        Ratio::new(numer, denom)
+    }
+
+    #[test]
+    fn test_exact_width_and_midpoint() {
+        let interval = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(ratio(1, 10), ratio(3, 10)).unwrap(),
+        };
+        assert_eq!(interval.width(), Some(ratio(1, 5)));
+        assert_eq!(interval.midpoint(), Some(ratio(1, 5)));
+        assert_eq!(
+            Interval::Singleton { at: ratio(2, 7) }.midpoint(),
+            Some(ratio(2, 7))
+        );
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: ratio(1, 2) }.midpoint(),
+            None
+        );
+        assert_eq!(Interval::<Ratio<i64>>::Empty.midpoint(), None);
+    }
+
+    #[test]
+    fn test_exact_arithmetic() {
+        let interval = Interval::Closed {
+            bound_pair: BoundPair::new(ratio(1, 10), ratio(2, 10)).unwrap(),
+        };
+        let shifted = interval.checked_translate(ratio(1, 10)).unwrap();
+        assert_eq!(
+            shifted,
+            Interval::Closed {
+                bound_pair: BoundPair::new(ratio(1, 5), ratio(3, 10)).unwrap()
+            }
+        );
+        assert_eq!(shifted.width(), interval.width());
+        assert_eq!(
+            interval.checked_expand(ratio(1, 20)).unwrap().width(),
+            Some(ratio(1, 5))
+        );
+        assert_eq!(interval.checked_translate(ratio(i64::MAX, 1)), None);
+        let chunks: Vec<_> = interval
+            .chunks(ratio(1, 30), RemainderPolicy::Drop)
+            .collect();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks
+                .iter()
+                .filter_map(Interval::width)
+                .sum::<Ratio<i64>>(),
+            ratio(1, 10)
+        );
+    }
+}
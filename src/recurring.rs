@@ -0,0 +1,481 @@
+//! Recurring Intervals (e.g. maintenance windows, office hours)
+//!
+//! A [RecurringInterval] is a base Interval repeated every period, optionally
+//! limited to a number of occurrences or to occurrences starting before an
+//! end.  Occurrences are materialized lazily, so open ended schedules need
+//! no pre-expansion.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::bound_pair::BoundPair;
+//! use intervals_general::interval::Interval;
+//! use intervals_general::recurring::RecurringInterval;
+//! # fn main() -> std::result::Result<(), String> {
+//! // A two hour blackout starting at hour 1 of every day, in hours
+//! let blackout = Interval::RightHalfOpen {
+//!     bound_pair: BoundPair::new(1, 3).ok_or("invalid BoundPair")?,
+//! };
+//! let schedule = RecurringInterval::new(blackout, 24).ok_or("invalid schedule")?;
+//! let week = Interval::RightHalfOpen {
+//!     bound_pair: BoundPair::new(48, 48 + 7 * 24).ok_or("invalid BoundPair")?,
+//! };
+//! assert_eq!(schedule.occurrences_within(&week).count(), 7);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::interval::{Bound, Interval};
+
+/// A base Interval recurring every period
+///
+/// The occurrence k is the base Interval with both bounds advanced by k
+/// periods, bound openness preserved.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RecurringInterval<T, P> {
+    base: Interval<T>,
+    period: P,
+    count: Option<usize>,
+    end: Option<T>,
+}
+
+impl<T, P> RecurringInterval<T, P>
+where
+    T: Copy,
+    T: PartialOrd,
+    T: std::ops::Add<P, Output = T>,
+    P: Copy,
+{
+    /// Create an unending recurrence of base every period
+    ///
+    /// Returns None unless base has finite bounds and period advances them
+    /// (i.e. `left + period > left`).  That check performs the addition, so
+    /// a base lying within one period of the maximum of T overflows just as
+    /// `left + period` does (panicking for integers in debug builds).
+    /// Likewise occurrences are only computed once requested, so a schedule
+    /// limited by count may end within a period of the maximum of T, while
+    /// any other overflows on advancing beyond it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use intervals_general::recurring::RecurringInterval;
+    ///
+    /// assert!(RecurringInterval::new(Interval::Singleton { at: 0 }, 10).is_some());
+    /// assert!(RecurringInterval::new(Interval::Singleton { at: 0 }, 0).is_none());
+    /// assert!(RecurringInterval::new(Interval::UnboundedClosedLeft { left: 0 }, 10).is_none());
+    /// ```
+    pub fn new(base: Interval<T>, period: P) -> Option<RecurringInterval<T, P>> {
+        let (left, _) = base.finite_bounds()?;
+        if left + period > left {
+            Some(RecurringInterval {
+                base,
+                period,
+                count: None,
+                end: None,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Limit the recurrence to the first count occurrences
+    pub fn with_count(self, count: usize) -> RecurringInterval<T, P> {
+        RecurringInterval {
+            count: Some(count),
+            ..self
+        }
+    }
+
+    /// Limit the recurrence to occurrences whose left bound precedes end
+    pub fn with_end(self, end: T) -> RecurringInterval<T, P> {
+        RecurringInterval {
+            end: Some(end),
+            ..self
+        }
+    }
+
+    /// The base Interval, i.e. the first occurrence
+    pub fn base(&self) -> &Interval<T> {
+        &self.base
+    }
+
+    /// The offset between consecutive occurrences
+    pub fn period(&self) -> &P {
+        &self.period
+    }
+
+    /// Iterate over every occurrence in ascending order
+    ///
+    /// The iterator is endless unless a count or end was specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// use intervals_general::recurring::RecurringInterval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let base = Interval::Closed {
+    ///     bound_pair: BoundPair::new(0.0, 0.5).ok_or("invalid BoundPair")?,
+    /// };
+    /// let schedule = RecurringInterval::new(base, 2.0)
+    ///     .ok_or("invalid schedule")?
+    ///     .with_count(3);
+    /// let lefts: Vec<_> = schedule
+    ///     .occurrences()
+    ///     .filter_map(|occurrence| occurrence.finite_endpoints().left)
+    ///     .map(|(left, _)| left)
+    ///     .collect();
+    /// assert_eq!(lefts, vec![0.0, 2.0, 4.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn occurrences(&self) -> Occurrences<T, P> {
+        Occurrences {
+            next: Some(self.base),
+            advance: false,
+            period: self.period,
+            remaining: self.count,
+            end: self.end,
+        }
+    }
+
+    /// Iterate over the occurrences overlapping window, clipped to window
+    ///
+    /// Yields the intersection of each overlapping occurrence with window, in
+    /// ascending order, ending once occurrences lie beyond window.
+    /// Occurrences preceding window are stepped over one at a time, so the
+    /// cost is linear in the number of occurrences before the end of window.
+    pub fn occurrences_within(&self, window: &Interval<T>) -> OccurrencesWithin<T, P> {
+        OccurrencesWithin {
+            occurrences: self.occurrences(),
+            window: *window,
+        }
+    }
+}
+
+impl<T, P, W> RecurringInterval<T, P>
+where
+    T: Copy,
+    T: PartialOrd,
+    T: std::ops::Add<P, Output = T>,
+    T: std::ops::Sub<Output = W>,
+    P: Copy,
+    W: Copy,
+    W: PartialOrd,
+    W: Default,
+    W: std::ops::Add<Output = W>,
+    W: std::ops::Sub<Output = W>,
+{
+    /// The total width of the occurrences within window (e.g. working time)
+    ///
+    /// None if the width is unbounded, i.e. window is right-unbounded and the
+    /// recurrence has neither count nor end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// use intervals_general::recurring::RecurringInterval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// // Business hours 9 to 17 each day, in hours
+    /// let hours = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(9, 17).ok_or("invalid BoundPair")?,
+    /// };
+    /// let business = RecurringInterval::new(hours, 24).ok_or("invalid schedule")?;
+    /// let ticket = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(15, 24 + 11).ok_or("invalid BoundPair")?,
+    /// };
+    /// assert_eq!(business.working_width(&ticket), Some(4));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn working_width(&self, window: &Interval<T>) -> Option<W> {
+        let endless = self.count.is_none() && self.end.is_none();
+        if endless && matches!(window.right_bound(), Bound::Unbounded) {
+            return None;
+        }
+        Some(
+            self.occurrences_within(window)
+                .filter_map(|occurrence| occurrence.width())
+                .fold(W::default(), |total, width| total + width),
+        )
+    }
+
+    /// The point at which amount of working time has passed since start
+    ///
+    /// Working time accrues only within occurrences, so e.g. an SLA clock
+    /// pauses outside of business hours.  None if amount is negative, or if
+    /// the occurrences end first (or never accrue width) before amount has
+    /// passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::bound_pair::BoundPair;
+    /// use intervals_general::interval::Interval;
+    /// use intervals_general::recurring::RecurringInterval;
+    /// # fn main() -> std::result::Result<(), String> {
+    /// let hours = Interval::RightHalfOpen {
+    ///     bound_pair: BoundPair::new(9, 17).ok_or("invalid BoundPair")?,
+    /// };
+    /// let business = RecurringInterval::new(hours, 24).ok_or("invalid schedule")?;
+    /// // Eight working hours from 15 on day zero is 15 on day one
+    /// assert_eq!(business.advance_working(15, 8), Some(24 + 15));
+    /// // Time outside business hours waits for the next opening
+    /// assert_eq!(business.advance_working(20, 0), Some(24 + 9));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn advance_working(&self, start: T, amount: W) -> Option<T>
+    where
+        T: std::ops::Add<W, Output = T>,
+    {
+        if amount < W::default() {
+            return None;
+        }
+        if amount > W::default() && self.base.width().is_none_or(|width| width <= W::default()) {
+            return None;
+        }
+        let mut remaining = amount;
+        for occurrence in self.occurrences_within(&Interval::UnboundedClosedLeft { left: start }) {
+            let (left, right) = occurrence.finite_bounds()?;
+            let width = right - left;
+            if remaining <= width {
+                return Some(left + remaining);
+            }
+            remaining = remaining - width;
+        }
+        None
+    }
+}
+
+/// Iterator over the occurrences of a RecurringInterval
+///
+/// Constructed by [RecurringInterval::occurrences].
+#[derive(Debug, Clone)]
+pub struct Occurrences<T, P> {
+    // The last occurrence yielded (or the base before the first), None once
+    // exhausted
+    next: Option<Interval<T>>,
+    // Whether next must be advanced by a period before it is yielded
+    advance: bool,
+    period: P,
+    remaining: Option<usize>,
+    end: Option<T>,
+}
+
+impl<T, P> Iterator for Occurrences<T, P>
+where
+    T: Copy,
+    T: PartialOrd,
+    T: std::ops::Add<P, Output = T>,
+    P: Copy,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Interval<T>> {
+        // Checked before advancing, so no occurrence beyond the count is
+        // ever computed
+        let previous = self.next.take()?;
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let occurrence = if self.advance {
+            let period = self.period;
+            let left_bound = previous
+                .left_bound()
+                .try_map(|value| Some(value + period))?;
+            let right_bound = previous
+                .right_bound()
+                .try_map(|value| Some(value + period))?;
+            Interval::from_bounds(left_bound, right_bound)
+        } else {
+            previous
+        };
+        let (left, _) = occurrence.finite_bounds()?;
+        if self.end.is_some_and(|end| left >= end) {
+            return None;
+        }
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        self.next = Some(occurrence);
+        self.advance = true;
+        Some(occurrence)
+    }
+}
+
+impl<T, P> std::iter::FusedIterator for Occurrences<T, P>
+where
+    T: Copy,
+    T: PartialOrd,
+    T: std::ops::Add<P, Output = T>,
+    P: Copy,
+{
+}
+
+/// Iterator over the occurrences of a RecurringInterval within a window
+///
+/// Constructed by [RecurringInterval::occurrences_within].
+#[derive(Debug, Clone)]
+pub struct OccurrencesWithin<T, P> {
+    occurrences: Occurrences<T, P>,
+    window: Interval<T>,
+}
+
+impl<T, P> Iterator for OccurrencesWithin<T, P>
+where
+    T: Copy,
+    T: PartialOrd,
+    T: std::ops::Add<P, Output = T>,
+    P: Copy,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Interval<T>> {
+        loop {
+            let occurrence = self.occurrences.next()?;
+            if occurrence.overlaps(&self.window) {
+                return Some(occurrence.intersect(&self.window));
+            }
+            // A non-overlapping occurrence starting at or after the right
+            // bound of the window, as do all which follow
+            let (left, _) = occurrence.finite_bounds()?;
+            let beyond = match self.window.right_bound() {
+                Bound::Unbounded => false,
+                Bound::Closed(right) | Bound::Open(right) => left >= right,
+                Bound::None => true,
+            };
+            if beyond {
+                self.occurrences.next = None;
+                return None;
+            }
+        }
+    }
+}
+
+impl<T, P> std::iter::FusedIterator for OccurrencesWithin<T, P>
+where
+    T: Copy,
+    T: PartialOrd,
+    T: std::ops::Add<P, Output = T>,
+    P: Copy,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound_pair::BoundPair;
+
+    fn rho(left: i32, right: i32) -> Interval<i32> {
+        Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(left, right).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let schedule = RecurringInterval::new(rho(0, 2), 5).unwrap();
+        assert_eq!(
+            schedule.occurrences().take(3).collect::<Vec<_>>(),
+            vec![rho(0, 2), rho(5, 7), rho(10, 12)]
+        );
+        assert_eq!(schedule.with_count(2).occurrences().count(), 2);
+        assert_eq!(schedule.with_count(0).occurrences().next(), None);
+        assert_eq!(
+            schedule.with_end(10).occurrences().collect::<Vec<_>>(),
+            vec![rho(0, 2), rho(5, 7)]
+        );
+        assert_eq!(schedule.with_end(11).with_count(5).occurrences().count(), 3);
+    }
+
+    #[test]
+    fn test_occurrences_near_max() {
+        let last = Interval::Singleton { at: i32::MAX - 6 };
+        let schedule = RecurringInterval::new(last, 5).unwrap();
+        assert_eq!(
+            schedule.with_count(2).occurrences().collect::<Vec<_>>(),
+            vec![last, Interval::Singleton { at: i32::MAX - 1 }]
+        );
+        let mut occurrences = schedule.with_count(1).occurrences();
+        assert_eq!(occurrences.next(), Some(last));
+        assert_eq!(occurrences.next(), None);
+        assert_eq!(occurrences.next(), None);
+    }
+
+    #[test]
+    fn test_new_rejects() {
+        assert!(RecurringInterval::new(rho(0, 2), -5).is_none());
+        assert!(RecurringInterval::new(Interval::<i32>::Empty, 5).is_none());
+        assert!(RecurringInterval::new(Interval::<i32>::Unbounded, 5).is_none());
+        assert!(RecurringInterval::new(Interval::Singleton { at: 0.0 }, f64::NAN).is_none());
+    }
+
+    #[test]
+    fn test_occurrences_within() {
+        let schedule = RecurringInterval::new(rho(0, 2), 5).unwrap();
+        let window = Interval::Closed {
+            bound_pair: BoundPair::new(6, 15).unwrap(),
+        };
+        assert_eq!(
+            schedule.occurrences_within(&window).collect::<Vec<_>>(),
+            vec![rho(6, 7), rho(10, 12), Interval::Singleton { at: 15 }]
+        );
+        let window = rho(6, 15);
+        assert_eq!(
+            schedule.occurrences_within(&window).collect::<Vec<_>>(),
+            vec![rho(6, 7), rho(10, 12)]
+        );
+        assert_eq!(schedule.occurrences_within(&rho(2, 5)).next(), None);
+        assert_eq!(schedule.occurrences_within(&Interval::Empty).next(), None);
+        assert_eq!(
+            schedule
+                .with_count(4)
+                .occurrences_within(&Interval::UnboundedClosedLeft { left: 12 })
+                .collect::<Vec<_>>(),
+            vec![rho(15, 17)]
+        );
+    }
+
+    #[test]
+    fn test_working_width() {
+        let business = RecurringInterval::new(rho(9, 17), 24).unwrap();
+        assert_eq!(business.working_width(&rho(0, 24 * 5)), Some(40));
+        assert_eq!(business.working_width(&rho(17, 24 + 9)), Some(0));
+        assert_eq!(business.working_width(&Interval::Empty), Some(0));
+        let after_noon = Interval::UnboundedClosedLeft { left: 12 };
+        assert_eq!(business.working_width(&after_noon), None);
+        assert_eq!(business.with_count(2).working_width(&after_noon), Some(13));
+    }
+
+    #[test]
+    fn test_advance_working() {
+        let business = RecurringInterval::new(rho(9, 17), 24).unwrap();
+        assert_eq!(business.advance_working(10, 3), Some(13));
+        assert_eq!(business.advance_working(10, 7), Some(17));
+        assert_eq!(business.advance_working(10, 8), Some(24 + 10));
+        assert_eq!(business.advance_working(0, 17), Some(48 + 10));
+        assert_eq!(business.with_count(2).advance_working(0, 17), None);
+        let instants = RecurringInterval::new(Interval::Singleton { at: 0 }, 24).unwrap();
+        assert_eq!(instants.advance_working(1, 0), Some(24));
+        assert_eq!(instants.advance_working(1, 1), None);
+        assert_eq!(business.advance_working(20, -5), None);
+    }
+
+    #[test]
+    fn test_time_schedule() {
+        use std::time::{Duration, Instant};
+        let now = Instant::now();
+        let base = Interval::deadline_window_at(now, Duration::from_secs(60));
+        let schedule = RecurringInterval::new(base, Duration::from_secs(3600)).unwrap();
+        let day = Interval::deadline_window_at(now, Duration::from_secs(24 * 3600));
+        let total: Duration = schedule
+            .occurrences_within(&day)
+            .filter_map(|occurrence| occurrence.width())
+            .sum();
+        assert_eq!(total, Duration::from_secs(24 * 60));
+    }
+}
@@ -0,0 +1,502 @@
+//! Versioned serde representation of Intervals
+//!
+//! The derived serde implementation of [Interval] uses the externally tagged
+//! enum layout (e.g. `{"Closed":{"bound_pair":{"left":1,"right":2}}}` in
+//! JSON), which ties persisted data to the exact shape of the enum.  This
+//! module provides an explicitly versioned, internally tagged layout for use
+//! with `#[serde(with = "intervals_general::serde_compat")]`:
+//!
+//! ```json
+//! {"v":1,"kind":"Closed","left":1,"right":2}
+//! ```
+//!
+//! Serialization always writes the current [VERSION].  Deserialization
+//! accepts every known version as well as the legacy externally tagged
+//! layout, so stored data can be migrated lazily.
+//!
+//! For human edited formats such as YAML and RON, the [kind] submodule
+//! provides the same layout without the version field or legacy fallback,
+//! and the [string] submodule the compact Display notation.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::bound_pair::BoundPair;
+//! use intervals_general::interval::Interval;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Window {
+//!     #[serde(with = "intervals_general::serde_compat")]
+//!     span: Interval<i32>,
+//! }
+//!
+//! # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! let window = Window {
+//!     span: Interval::RightHalfOpen {
+//!         bound_pair: BoundPair::new(1, 5).ok_or("invalid BoundPair")?,
+//!     },
+//! };
+//! let json = serde_json::to_string(&window)?;
+//! assert_eq!(json, r#"{"span":{"v":1,"kind":"RightHalfOpen","left":1,"right":5}}"#);
+//!
+//! // Data persisted in the legacy layout remains readable
+//! let legacy = r#"{"span":{"RightHalfOpen":{"bound_pair":{"left":1,"right":5}}}}"#;
+//! assert_eq!(serde_json::from_str::<Window>(legacy)?.span, window.span);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::bound_pair::BoundPair;
+use crate::interval::Interval;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The representation version written by [serialize]
+pub const VERSION: u32 = 1;
+
+// Data-free mirror of the Interval variants, used as the internal tag
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+enum Kind {
+    Closed,
+    Open,
+    LeftHalfOpen,
+    RightHalfOpen,
+    UnboundedClosedRight,
+    UnboundedOpenRight,
+    UnboundedClosedLeft,
+    UnboundedOpenLeft,
+    Singleton,
+    Unbounded,
+    Empty,
+}
+
+#[derive(Serialize)]
+struct VersionedRef<'a, T> {
+    v: u32,
+    kind: Kind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    left: Option<&'a T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    right: Option<&'a T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    at: Option<&'a T>,
+}
+
+#[derive(Deserialize)]
+struct Versioned<T> {
+    v: u32,
+    kind: Kind,
+    left: Option<T>,
+    right: Option<T>,
+    at: Option<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AnyLayout<T> {
+    Versioned(Versioned<T>),
+    Legacy(Interval<T>),
+}
+
+/// Serialize an Interval in the current versioned layout
+pub fn serialize<S, T>(interval: &Interval<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let (kind, left, right, at) = to_fields(interval);
+    VersionedRef {
+        v: VERSION,
+        kind,
+        left,
+        right,
+        at,
+    }
+    .serialize(serializer)
+}
+
+/// Deserialize an Interval from any known versioned layout or the legacy
+/// externally tagged layout
+///
+/// Versioned data is validated: the fields present must match the kind, and
+/// bounded kinds must satisfy the BoundPair invariant (left < right).  Legacy
+/// data is held to the same invariant.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Interval<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    T: Copy,
+    T: PartialOrd,
+{
+    match AnyLayout::deserialize(deserializer)? {
+        AnyLayout::Legacy(interval) => {
+            // The derived layout bypasses BoundPair::new, so revalidate
+            let (kind, left, right, at) = to_fields(&interval);
+            from_fields(kind, left.copied(), right.copied(), at.copied())
+        }
+        AnyLayout::Versioned(versioned) => from_versioned(versioned),
+    }
+}
+
+// Split an Interval into its kind and the bound fields present
+fn to_fields<T>(interval: &Interval<T>) -> (Kind, Option<&T>, Option<&T>, Option<&T>) {
+    match interval {
+        Interval::Closed { bound_pair } => (
+            Kind::Closed,
+            Some(&bound_pair.left),
+            Some(&bound_pair.right),
+            None,
+        ),
+        Interval::Open { bound_pair } => (
+            Kind::Open,
+            Some(&bound_pair.left),
+            Some(&bound_pair.right),
+            None,
+        ),
+        Interval::LeftHalfOpen { bound_pair } => (
+            Kind::LeftHalfOpen,
+            Some(&bound_pair.left),
+            Some(&bound_pair.right),
+            None,
+        ),
+        Interval::RightHalfOpen { bound_pair } => (
+            Kind::RightHalfOpen,
+            Some(&bound_pair.left),
+            Some(&bound_pair.right),
+            None,
+        ),
+        Interval::UnboundedClosedRight { right } => {
+            (Kind::UnboundedClosedRight, None, Some(right), None)
+        }
+        Interval::UnboundedOpenRight { right } => {
+            (Kind::UnboundedOpenRight, None, Some(right), None)
+        }
+        Interval::UnboundedClosedLeft { left } => {
+            (Kind::UnboundedClosedLeft, Some(left), None, None)
+        }
+        Interval::UnboundedOpenLeft { left } => (Kind::UnboundedOpenLeft, Some(left), None, None),
+        Interval::Singleton { at } => (Kind::Singleton, None, None, Some(at)),
+        Interval::Unbounded => (Kind::Unbounded, None, None, None),
+        Interval::Empty => (Kind::Empty, None, None, None),
+    }
+}
+
+fn from_versioned<T, E>(versioned: Versioned<T>) -> Result<Interval<T>, E>
+where
+    T: Copy,
+    T: PartialOrd,
+    E: Error,
+{
+    if versioned.v != VERSION {
+        return Err(E::custom(format!(
+            "unsupported interval representation version {}",
+            versioned.v
+        )));
+    }
+    from_fields(
+        versioned.kind,
+        versioned.left,
+        versioned.right,
+        versioned.at,
+    )
+}
+
+// Validate that the fields present match the kind and rebuild the Interval
+fn from_fields<T, E>(
+    kind: Kind,
+    left: Option<T>,
+    right: Option<T>,
+    at: Option<T>,
+) -> Result<Interval<T>, E>
+where
+    T: Copy,
+    T: PartialOrd,
+    E: Error,
+{
+    let bound_pair = |left, right| {
+        BoundPair::new(left, right)
+            .ok_or_else(|| E::custom("interval bounds must satisfy left < right"))
+    };
+    let malformed = || E::custom(format!("malformed fields for {:?} interval", kind));
+
+    match (kind, left, right, at) {
+        (Kind::Closed, Some(left), Some(right), None) => Ok(Interval::Closed {
+            bound_pair: bound_pair(left, right)?,
+        }),
+        (Kind::Open, Some(left), Some(right), None) => Ok(Interval::Open {
+            bound_pair: bound_pair(left, right)?,
+        }),
+        (Kind::LeftHalfOpen, Some(left), Some(right), None) => Ok(Interval::LeftHalfOpen {
+            bound_pair: bound_pair(left, right)?,
+        }),
+        (Kind::RightHalfOpen, Some(left), Some(right), None) => Ok(Interval::RightHalfOpen {
+            bound_pair: bound_pair(left, right)?,
+        }),
+        (Kind::UnboundedClosedRight, None, Some(right), None) => {
+            Ok(Interval::UnboundedClosedRight { right })
+        }
+        (Kind::UnboundedOpenRight, None, Some(right), None) => {
+            Ok(Interval::UnboundedOpenRight { right })
+        }
+        (Kind::UnboundedClosedLeft, Some(left), None, None) => {
+            Ok(Interval::UnboundedClosedLeft { left })
+        }
+        (Kind::UnboundedOpenLeft, Some(left), None, None) => {
+            Ok(Interval::UnboundedOpenLeft { left })
+        }
+        (Kind::Singleton, None, None, Some(at)) => Ok(Interval::Singleton { at }),
+        (Kind::Unbounded, None, None, None) => Ok(Interval::Unbounded),
+        (Kind::Empty, None, None, None) => Ok(Interval::Empty),
+        _ => Err(malformed()),
+    }
+}
+
+/// Unversioned `kind` plus bounds layout, for human edited formats
+///
+/// Writes e.g. `{"kind":"Closed","left":1,"right":2}`, omitting absent
+/// bounds.  Unlike the versioned layout, deserialization does not fall back
+/// to the legacy layout, and so never relies upon self-describing input,
+/// which keeps YAML and RON configs natural:
+///
+/// ```yaml
+/// kind: RightHalfOpen
+/// left: 1
+/// right: 5
+/// ```
+///
+/// RON writes present bounds as options, e.g.
+/// `(kind: UnboundedOpenLeft, left: Some(1))` (or `left: 1` with the RON
+/// `implicit_some` extension enabled).  Use with
+/// `#[serde(with = "intervals_general::serde_compat::kind")]`.
+pub mod kind {
+    use super::{from_fields, to_fields, Kind};
+    use crate::interval::Interval;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "Interval")]
+    struct KindRef<'a, T> {
+        kind: Kind,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        left: Option<&'a T>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        right: Option<&'a T>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        at: Option<&'a T>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "Interval", deny_unknown_fields)]
+    struct KindFields<T> {
+        kind: Kind,
+        left: Option<T>,
+        right: Option<T>,
+        at: Option<T>,
+    }
+
+    /// Serialize an Interval in the kind layout
+    pub fn serialize<S, T>(interval: &Interval<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let (kind, left, right, at) = to_fields(interval);
+        KindRef {
+            kind,
+            left,
+            right,
+            at,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize an Interval from the kind layout
+    ///
+    /// The fields present must match the kind, and bounded kinds must satisfy
+    /// the BoundPair invariant (left < right).
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Interval<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+        T: Copy,
+        T: PartialOrd,
+    {
+        let fields = KindFields::deserialize(deserializer)?;
+        from_fields(fields.kind, fields.left, fields.right, fields.at)
+    }
+}
+
+/// Display notation string layout, e.g. `"[1..5)"`
+///
+/// The most compact representation in any format, reading naturally in
+/// configs (`span: "[1..5)"` in YAML, `span: "(←..5]"` in RON).  Bounds are
+/// written with the Debug implementation of T and read with its FromStr
+/// implementation, suiting the primitive numeric types.  Use with
+/// `#[serde(with = "intervals_general::serde_compat::string")]`.
+pub mod string {
+    use crate::interval::Interval;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize an Interval as its Display notation string
+    pub fn serialize<S, T>(interval: &Interval<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: std::fmt::Debug,
+    {
+        serializer.collect_str(interval)
+    }
+
+    /// Deserialize an Interval from its Display notation string
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Interval<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: std::str::FromStr,
+        T: Copy,
+        T: PartialOrd,
+    {
+        let notation = String::deserialize(deserializer)?;
+        notation.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::all_variant_examples;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(with = "crate::serde_compat")]
+        interval: Interval<i32>,
+    }
+
+    #[test]
+    fn test_serialize_layout() {
+        let to_json = |interval| serde_json::to_string(&Wrapper { interval }).unwrap();
+        assert_eq!(
+            to_json(Interval::Closed {
+                bound_pair: BoundPair::new(1, 2).unwrap()
+            }),
+            r#"{"interval":{"v":1,"kind":"Closed","left":1,"right":2}}"#
+        );
+        assert_eq!(
+            to_json(Interval::UnboundedOpenLeft { left: 1 }),
+            r#"{"interval":{"v":1,"kind":"UnboundedOpenLeft","left":1}}"#
+        );
+        assert_eq!(
+            to_json(Interval::Singleton { at: 3 }),
+            r#"{"interval":{"v":1,"kind":"Singleton","at":3}}"#
+        );
+        assert_eq!(
+            to_json(Interval::Empty),
+            r#"{"interval":{"v":1,"kind":"Empty"}}"#
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_all_variants() {
+        for interval in all_variant_examples(1, 5).unwrap() {
+            let json = serde_json::to_string(&Wrapper { interval }).unwrap();
+            let wrapper: Wrapper = serde_json::from_str(&json).unwrap();
+            assert_eq!(wrapper.interval, interval);
+        }
+    }
+
+    #[test]
+    fn test_read_legacy_layout() {
+        for interval in all_variant_examples(1, 5).unwrap() {
+            let legacy = format!(
+                r#"{{"interval":{}}}"#,
+                serde_json::to_string(&interval).unwrap()
+            );
+            let wrapper: Wrapper = serde_json::from_str(&legacy).unwrap();
+            assert_eq!(wrapper.interval, interval);
+        }
+    }
+
+    #[test]
+    fn test_reject_reversed_legacy_bounds() {
+        let json = r#"{"interval":{"Closed":{"bound_pair":{"left":5,"right":1}}}}"#;
+        assert!(serde_json::from_str::<Wrapper>(json).is_err());
+        let json = r#"{"interval":{"Open":{"bound_pair":{"left":1,"right":1}}}}"#;
+        assert!(serde_json::from_str::<Wrapper>(json).is_err());
+    }
+
+    #[test]
+    fn test_reject_unknown_version() {
+        let json = r#"{"interval":{"v":2,"kind":"Empty"}}"#;
+        assert!(serde_json::from_str::<Wrapper>(json).is_err());
+    }
+
+    #[test]
+    fn test_reject_invalid_bounds() {
+        let json = r#"{"interval":{"v":1,"kind":"Closed","left":5,"right":1}}"#;
+        assert!(serde_json::from_str::<Wrapper>(json).is_err());
+    }
+
+    #[test]
+    fn test_reject_mismatched_fields() {
+        let json = r#"{"interval":{"v":1,"kind":"Closed","left":1}}"#;
+        assert!(serde_json::from_str::<Wrapper>(json).is_err());
+        let json = r#"{"interval":{"v":1,"kind":"Empty","at":1}}"#;
+        assert!(serde_json::from_str::<Wrapper>(json).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_compat::kind")]
+        by_kind: Interval<i32>,
+        #[serde(with = "crate::serde_compat::string")]
+        by_string: Interval<f64>,
+    }
+
+    #[test]
+    fn test_human_layouts() {
+        let config = Config {
+            by_kind: Interval::RightHalfOpen {
+                bound_pair: BoundPair::new(1, 5).unwrap(),
+            },
+            by_string: Interval::UnboundedClosedRight { right: 2.5 },
+        };
+        assert_eq!(
+            serde_yaml::to_string(&config).unwrap(),
+            "by_kind:\n  kind: RightHalfOpen\n  left: 1\n  right: 5\nby_string: (←..2.5]\n"
+        );
+        assert_eq!(
+            ron::to_string(&config).unwrap(),
+            r#"(by_kind:(kind:RightHalfOpen,left:Some(1),right:Some(5)),by_string:"(←..2.5]")"#
+        );
+    }
+
+    #[test]
+    fn test_human_layouts_roundtrip_all_variants() {
+        let ints = all_variant_examples(1, 5).unwrap();
+        let floats = all_variant_examples(-0.5, 0.5).unwrap();
+        for (by_kind, by_string) in ints.into_iter().zip(floats) {
+            let config = Config { by_kind, by_string };
+            let yaml = serde_yaml::to_string(&config).unwrap();
+            assert_eq!(serde_yaml::from_str::<Config>(&yaml).unwrap(), config);
+            let ron = ron::to_string(&config).unwrap();
+            assert_eq!(ron::from_str::<Config>(&ron).unwrap(), config);
+        }
+    }
+
+    #[test]
+    fn test_human_layouts_reject_invalid() {
+        let yaml = "by_kind:\n  kind: Closed\n  left: 5\n  right: 1\nby_string: Empty\n";
+        assert!(serde_yaml::from_str::<Config>(yaml).is_err());
+        let yaml = "by_kind:\n  kind: Empty\n  extra: 1\nby_string: Empty\n";
+        assert!(serde_yaml::from_str::<Config>(yaml).is_err());
+        let ron = r#"(by_kind:(kind:Empty),by_string:"[2.0..1.0]")"#;
+        assert!(ron::from_str::<Config>(ron).is_err());
+        let ron = r#"(by_kind:(kind:UnboundedOpenLeft,left:1),by_string:"Empty")"#;
+        assert!(ron::from_str::<Config>(ron).is_err());
+        let ron = r#"#![enable(implicit_some)] (by_kind:(kind:UnboundedOpenLeft,left:1),by_string:"Empty")"#;
+        assert_eq!(
+            ron::from_str::<Config>(ron).unwrap().by_kind,
+            Interval::UnboundedOpenLeft { left: 1 }
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! Timeout and rate-limit window helpers over std time types
+//!
+//! Windows are `Interval<Instant>` (or `Interval<SystemTime>`) values, whose
+//! [Interval::width] is already a Duration by way of the Sub based design.
+//! Each helper reading the clock has an `_at` counterpart taking the current
+//! time explicitly, for deterministic use and testing.
+//!
+//! Intervals of Durations (e.g. latency buckets or timeout budgets) are
+//! supported as any other bound type, with widths which are again Durations.
+//!
+//! The module is not behind a `std` feature, as the crate has none and
+//! depends upon std throughout.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::interval::Interval;
+//! use std::time::{Duration, Instant};
+//!
+//! let window: Interval<Instant> = Interval::deadline_window(Duration::from_secs(30));
+//! assert!(!window.has_elapsed());
+//! assert!(window.remaining().is_some_and(|left| left <= Duration::from_secs(30)));
+//! assert_eq!(window.width(), Some(Duration::from_secs(30)));
+//! ```
+
+use crate::bound_pair::BoundPair;
+use crate::interval::Interval;
+use std::time::{Duration, Instant, SystemTime};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for std::time::Instant {}
+    impl Sealed for std::time::SystemTime {}
+}
+
+/// Clock time data types (Instant and SystemTime)
+///
+/// Enables the window helpers to be written once for both std clocks.  This
+/// trait is sealed and cannot be implemented outside of intervals-general.
+pub trait TimePoint: private::Sealed + Copy + PartialOrd {
+    /// The current time
+    fn now() -> Self;
+
+    /// self + duration, or None if not representable
+    fn checked_add(&self, duration: Duration) -> Option<Self>;
+
+    /// The time from self until later, zero if later is not after self
+    fn saturating_until(&self, later: Self) -> Duration;
+}
+
+impl TimePoint for Instant {
+    fn now() -> Self {
+        Instant::now()
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Instant::checked_add(self, duration)
+    }
+
+    fn saturating_until(&self, later: Self) -> Duration {
+        later.saturating_duration_since(*self)
+    }
+}
+
+impl TimePoint for SystemTime {
+    fn now() -> Self {
+        SystemTime::now()
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        SystemTime::checked_add(self, duration)
+    }
+
+    fn saturating_until(&self, later: Self) -> Duration {
+        later.duration_since(*self).unwrap_or(Duration::ZERO)
+    }
+}
+
+impl<T> Interval<T>
+where
+    T: TimePoint,
+{
+    /// The RightHalfOpen window from now until now + duration
+    ///
+    /// A zero duration yields the Empty interval, and a deadline beyond the
+    /// representable range yields the UnboundedClosedLeft interval from now.
+    pub fn deadline_window(duration: Duration) -> Interval<T> {
+        Self::deadline_window_at(T::now(), duration)
+    }
+
+    /// The window from now until now + duration, see [Self::deadline_window]
+    pub fn deadline_window_at(now: T, duration: Duration) -> Interval<T> {
+        match now.checked_add(duration) {
+            Some(deadline) => match BoundPair::new(now, deadline) {
+                Some(bound_pair) => Interval::RightHalfOpen { bound_pair },
+                None => Interval::Empty,
+            },
+            None => Interval::UnboundedClosedLeft { left: now },
+        }
+    }
+
+    /// The time left until the right bound, zero once passed
+    ///
+    /// None if the window has no right bound (it is right-unbounded or
+    /// Empty).
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining_at(T::now())
+    }
+
+    /// The time left at now until the right bound, see [Self::remaining]
+    pub fn remaining_at(&self, now: T) -> Option<Duration> {
+        match *self {
+            Interval::Closed { bound_pair }
+            | Interval::Open { bound_pair }
+            | Interval::LeftHalfOpen { bound_pair }
+            | Interval::RightHalfOpen { bound_pair } => {
+                Some(now.saturating_until(bound_pair.right))
+            }
+            Interval::UnboundedClosedRight { right } | Interval::UnboundedOpenRight { right } => {
+                Some(now.saturating_until(right))
+            }
+            Interval::Singleton { at } => Some(now.saturating_until(at)),
+            Interval::UnboundedClosedLeft { .. }
+            | Interval::UnboundedOpenLeft { .. }
+            | Interval::Unbounded
+            | Interval::Empty => None,
+        }
+    }
+
+    /// Verify whether every instant of the window lies in the past
+    ///
+    /// The Empty interval contains no instants, and so has always elapsed.
+    pub fn has_elapsed(&self) -> bool {
+        self.has_elapsed_at(T::now())
+    }
+
+    /// Verify whether every instant of the window precedes now, see
+    /// [Self::has_elapsed]
+    pub fn has_elapsed_at(&self, now: T) -> bool {
+        self.intersect(&(now..)) == Interval::Empty
+    }
+}
+
+impl Interval<Duration> {
+    /// The Closed interval of Durations between a and b, in either order
+    ///
+    /// Equal Durations yield the zero width Singleton interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intervals_general::interval::Interval;
+    /// use std::time::Duration;
+    ///
+    /// let budget = Interval::duration_between(Duration::from_millis(250), Duration::from_millis(50));
+    /// assert_eq!(budget.width(), Some(Duration::from_millis(200)));
+    /// assert!(budget.contains(&Interval::Singleton { at: Duration::from_millis(100) }));
+    ///
+    /// let instant = Interval::duration_between(Duration::from_secs(1), Duration::from_secs(1));
+    /// assert_eq!(instant, Interval::Singleton { at: Duration::from_secs(1) });
+    /// assert_eq!(instant.width(), Some(Duration::ZERO));
+    /// ```
+    pub fn duration_between(a: Duration, b: Duration) -> Interval<Duration> {
+        match BoundPair::new(a.min(b), a.max(b)) {
+            Some(bound_pair) => Interval::Closed { bound_pair },
+            None => Interval::Singleton { at: a },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deadline_window() {
+        let now = Instant::now();
+        let window = Interval::deadline_window_at(now, Duration::from_millis(250));
+        assert_eq!(window.width(), Some(Duration::from_millis(250)));
+        assert!(window.contains(&Interval::Singleton { at: now }));
+        assert_eq!(
+            Interval::deadline_window_at(now, Duration::ZERO),
+            Interval::Empty
+        );
+        assert_eq!(
+            Interval::deadline_window_at(now, Duration::MAX),
+            Interval::UnboundedClosedLeft { left: now }
+        );
+    }
+
+    #[test]
+    fn test_remaining() {
+        let now = Instant::now();
+        let window = Interval::deadline_window_at(now, Duration::from_secs(10));
+        let later = now + Duration::from_secs(4);
+        assert_eq!(window.remaining_at(later), Some(Duration::from_secs(6)));
+        assert_eq!(
+            window.remaining_at(now + Duration::from_secs(11)),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            Interval::UnboundedClosedLeft { left: now }.remaining_at(later),
+            None
+        );
+        assert_eq!(Interval::<Instant>::Empty.remaining_at(later), None);
+    }
+
+    #[test]
+    fn test_has_elapsed() {
+        let now = Instant::now();
+        let end = now + Duration::from_secs(10);
+        let window = Interval::deadline_window_at(now, Duration::from_secs(10));
+        assert!(!window.has_elapsed_at(now));
+        assert!(window.has_elapsed_at(end));
+        let closed = Interval::Closed {
+            bound_pair: BoundPair::new(now, end).unwrap(),
+        };
+        assert!(!closed.has_elapsed_at(end));
+        assert!(Interval::<Instant>::Empty.has_elapsed_at(now));
+        assert!(!Interval::<Instant>::Unbounded.has_elapsed_at(end));
+    }
+
+    #[test]
+    fn test_system_time_window() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let window = Interval::deadline_window_at(now, Duration::from_secs(60));
+        assert_eq!(
+            window.remaining_at(now + Duration::from_secs(15)),
+            Some(Duration::from_secs(45))
+        );
+        assert_eq!(
+            window.remaining_at(now + Duration::from_secs(90)),
+            Some(Duration::ZERO)
+        );
+        assert!(window.has_elapsed_at(now + Duration::from_secs(60)));
+        assert!(!Interval::<SystemTime>::deadline_window(Duration::from_secs(60)).has_elapsed());
+    }
+
+    #[test]
+    fn test_duration_between() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Interval::duration_between(ms(10), ms(30)),
+            Interval::duration_between(ms(30), ms(10))
+        );
+        assert_eq!(
+            Interval::duration_between(ms(10), ms(30)).width(),
+            Some(ms(20))
+        );
+        assert_eq!(
+            Interval::duration_between(Duration::ZERO, Duration::ZERO),
+            Interval::Singleton { at: Duration::ZERO }
+        );
+        assert_eq!(
+            Interval::duration_between(Duration::ZERO, Duration::MAX).width(),
+            Some(Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn test_duration_intervals() {
+        let ms = Duration::from_millis;
+        let fast = Interval::RightHalfOpen {
+            bound_pair: BoundPair::new(Duration::ZERO, ms(100)).unwrap(),
+        };
+        let slow = Interval::UnboundedClosedLeft { left: ms(100) };
+        assert_eq!(fast.intersect(&slow), Interval::Empty);
+        assert_eq!(fast.intersect(&(ms(50)..)).width(), Some(ms(50)));
+        assert_eq!(slow.width(), None);
+        assert!(fast.contains(&Interval::Singleton { at: Duration::ZERO }));
+        assert_eq!(
+            fast.complement().collect::<Vec<_>>(),
+            vec![
+                Interval::UnboundedOpenRight {
+                    right: Duration::ZERO
+                },
+                slow
+            ]
+        );
+    }
+}
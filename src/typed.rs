@@ -0,0 +1,332 @@
+//! Monomorphic interval types of a single fixed kind
+//!
+//! The [Interval] enum supports every interval kind at the cost of runtime
+//! dispatch on each operation.  Hot loops over homogeneous interval kinds
+//! (e.g. exclusively right-half-open time windows) can instead use the fixed
+//! kind structs here, which share the [IntervalOps] trait and convert cheaply
+//! to and from the dynamic [Interval] enum.  See the `static_alternative`
+//! benchmark for the motivating performance comparison.
+//!
+//! # Examples
+//!
+//! ```
+//! use intervals_general::interval::Interval;
+//! use intervals_general::typed::{IntervalOps, RightHalfOpenInterval};
+//! # fn main() -> std::result::Result<(), String> {
+//! let a = RightHalfOpenInterval::new(1, 5).ok_or("invalid bounds")?;
+//! let b = RightHalfOpenInterval::new(3, 8).ok_or("invalid bounds")?;
+//!
+//! let overlap = a.intersect(&b).ok_or("empty intersection")?;
+//! assert_eq!((overlap.left(), overlap.right()), (3, 5));
+//! assert!(a.intersect(&RightHalfOpenInterval::new(5, 6).ok_or("invalid bounds")?).is_none());
+//!
+//! let dynamic: Interval<i32> = overlap.into();
+//! assert_eq!(RightHalfOpenInterval::try_from(dynamic), Ok(overlap));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::bound_pair::BoundPair;
+use crate::error::IntervalError;
+use crate::interval::Interval;
+use crate::interval_like::IntervalLike;
+use std::cmp::Ordering;
+
+/// Operations shared by the fixed kind interval types
+///
+/// Every implementor represents a non-empty interval, so intersection yields
+/// None exactly when the result is empty.  Conversion to the dynamic
+/// Interval enum is provided by the [IntervalLike] supertrait.
+pub trait IntervalOps<T>: IntervalLike<T> + Sized + Copy
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    /// Construct the interval, or None if the bounds do not describe a
+    /// non-empty interval of this kind
+    fn new(left: T, right: T) -> Option<Self>;
+
+    /// The left bound value
+    fn left(&self) -> T;
+
+    /// The right bound value
+    fn right(&self) -> T;
+
+    /// Verify whether the interval contains value
+    fn contains_value(&self, value: &T) -> bool;
+
+    /// Intersect with another interval of the same kind, None if empty
+    fn intersect(&self, other: &Self) -> Option<Self>;
+
+    /// Verify whether the interval contains another of the same kind
+    fn contains(&self, other: &Self) -> bool {
+        self.left() <= other.left() && other.right() <= self.right()
+    }
+
+    /// Compute right - left
+    fn width(&self) -> <T as std::ops::Sub>::Output
+    where
+        T: std::ops::Sub,
+    {
+        self.right() - self.left()
+    }
+}
+
+// The greater of two values, preferring a when incomparable
+fn max_bound<T: PartialOrd + Copy>(a: T, b: T) -> T {
+    match a.partial_cmp(&b) {
+        Some(Ordering::Less) => b,
+        _ => a,
+    }
+}
+
+// The lesser of two values, preferring a when incomparable
+fn min_bound<T: PartialOrd + Copy>(a: T, b: T) -> T {
+    match a.partial_cmp(&b) {
+        Some(Ordering::Greater) => b,
+        _ => a,
+    }
+}
+
+macro_rules! fixed_kind_interval {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $variant:ident, $valid:expr, $contains:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $name<T> {
+            left: T,
+            right: T,
+        }
+
+        impl<T> IntervalOps<T> for $name<T>
+        where
+            T: Copy,
+            T: PartialOrd,
+        {
+            fn new(left: T, right: T) -> Option<Self> {
+                let valid: fn(&T, &T) -> bool = $valid;
+                if valid(&left, &right) {
+                    Some($name { left, right })
+                } else {
+                    None
+                }
+            }
+
+            fn left(&self) -> T {
+                self.left
+            }
+
+            fn right(&self) -> T {
+                self.right
+            }
+
+            fn contains_value(&self, value: &T) -> bool {
+                let contains: fn(&T, &T, &T) -> bool = $contains;
+                contains(&self.left, value, &self.right)
+            }
+
+            fn intersect(&self, other: &Self) -> Option<Self> {
+                Self::new(
+                    max_bound(self.left, other.left),
+                    min_bound(self.right, other.right),
+                )
+            }
+        }
+
+        impl<T> IntervalLike<T> for $name<T>
+        where
+            T: Copy,
+            T: PartialOrd,
+        {
+            fn to_interval(&self) -> Interval<T> {
+                Interval::from(*self)
+            }
+        }
+
+        impl<T> From<$name<T>> for Interval<T>
+        where
+            T: Copy,
+            T: PartialOrd,
+        {
+            fn from(interval: $name<T>) -> Interval<T> {
+                Interval::from_bounds(
+                    fixed_kind_interval!(@left $variant interval.left),
+                    fixed_kind_interval!(@right $variant interval.right),
+                )
+            }
+        }
+
+        impl<T> TryFrom<Interval<T>> for $name<T>
+        where
+            T: Copy,
+            T: PartialOrd,
+        {
+            type Error = IntervalError;
+
+            fn try_from(interval: Interval<T>) -> Result<$name<T>, IntervalError> {
+                match interval {
+                    Interval::$variant {
+                        bound_pair: BoundPair { left, right },
+                    } => Ok($name { left, right }),
+                    _ => fixed_kind_interval!(@singleton $variant interval),
+                }
+            }
+        }
+    };
+    (@left Closed $v:expr) => { crate::interval::Bound::Closed($v) };
+    (@left RightHalfOpen $v:expr) => { crate::interval::Bound::Closed($v) };
+    (@left $other:ident $v:expr) => { crate::interval::Bound::Open($v) };
+    (@right Closed $v:expr) => { crate::interval::Bound::Closed($v) };
+    (@right LeftHalfOpen $v:expr) => { crate::interval::Bound::Closed($v) };
+    (@right $other:ident $v:expr) => { crate::interval::Bound::Open($v) };
+    (@singleton Closed $i:expr) => {
+        match $i {
+            Interval::Singleton { at } => Ok(ClosedInterval { left: at, right: at }),
+            _ => Err(IntervalError::KindMismatch),
+        }
+    };
+    (@singleton $other:ident $i:expr) => { Err(IntervalError::KindMismatch) };
+}
+
+fixed_kind_interval!(
+    /// A closed interval `[a, b]` with a <= b
+    ///
+    /// Unlike the Closed Interval variant, a == b is permitted (representing
+    /// the Singleton), so that intersection is closed over this type.
+    ClosedInterval,
+    Closed,
+    |left, right| left <= right,
+    |left, value, right| left <= value && value <= right
+);
+
+fixed_kind_interval!(
+    /// An open interval `(a, b)` with a < b
+    OpenInterval,
+    Open,
+    |left, right| left < right,
+    |left, value, right| left < value && value < right
+);
+
+fixed_kind_interval!(
+    /// A left-half-open interval `(a, b]` with a < b
+    LeftHalfOpenInterval,
+    LeftHalfOpen,
+    |left, right| left < right,
+    |left, value, right| left < value && value <= right
+);
+
+fixed_kind_interval!(
+    /// A right-half-open interval `[a, b)` with a < b
+    RightHalfOpenInterval,
+    RightHalfOpen,
+    |left, right| left < right,
+    |left, value, right| left <= value && value < right
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construction() {
+        assert!(ClosedInterval::new(1, 1).is_some());
+        assert!(ClosedInterval::new(2, 1).is_none());
+        assert!(OpenInterval::new(1, 1).is_none());
+        assert!(LeftHalfOpenInterval::new(1.0, f64::NAN).is_none());
+        assert!(RightHalfOpenInterval::new(1, 2).is_some());
+    }
+
+    #[test]
+    fn test_contains_value() {
+        let closed = ClosedInterval::new(1, 3).unwrap();
+        let open = OpenInterval::new(1, 3).unwrap();
+        let left_half_open = LeftHalfOpenInterval::new(1, 3).unwrap();
+        let right_half_open = RightHalfOpenInterval::new(1, 3).unwrap();
+
+        assert!(closed.contains_value(&1) && closed.contains_value(&3));
+        assert!(!open.contains_value(&1) && !open.contains_value(&3));
+        assert!(!left_half_open.contains_value(&1) && left_half_open.contains_value(&3));
+        assert!(right_half_open.contains_value(&1) && !right_half_open.contains_value(&3));
+        assert!(!closed.contains_value(&4));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let a = ClosedInterval::new(0, 5).unwrap();
+        let b = ClosedInterval::new(5, 9).unwrap();
+        assert_eq!(a.intersect(&b), ClosedInterval::new(5, 5));
+        assert_eq!(a.intersect(&ClosedInterval::new(6, 9).unwrap()), None);
+
+        let a = LeftHalfOpenInterval::new(0, 5).unwrap();
+        let b = LeftHalfOpenInterval::new(5, 9).unwrap();
+        assert_eq!(a.intersect(&b), None);
+        assert_eq!(
+            a.intersect(&LeftHalfOpenInterval::new(2, 9).unwrap()),
+            LeftHalfOpenInterval::new(2, 5)
+        );
+    }
+
+    #[test]
+    fn test_intersect_matches_dynamic() {
+        let bounds = [(0, 4), (2, 6), (4, 8), (5, 7), (-1, 10)];
+        for &(l1, r1) in &bounds {
+            for &(l2, r2) in &bounds {
+                let (a, b) = (
+                    RightHalfOpenInterval::new(l1, r1).unwrap(),
+                    RightHalfOpenInterval::new(l2, r2).unwrap(),
+                );
+                let expected = a.to_interval().intersect(&b.to_interval());
+                let actual = a.intersect(&b).map_or(Interval::Empty, |i| i.to_interval());
+                assert_eq!(actual, expected);
+
+                let (a, b) = (
+                    ClosedInterval::new(l1, r1).unwrap(),
+                    ClosedInterval::new(l2, r2).unwrap(),
+                );
+                let expected = a.to_interval().intersect(&b.to_interval());
+                let actual = a.intersect(&b).map_or(Interval::Empty, |i| i.to_interval());
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_contains_and_width() {
+        let outer = OpenInterval::new(0.0, 10.0).unwrap();
+        let inner = OpenInterval::new(0.0, 5.0).unwrap();
+        assert!(outer.contains(&inner));
+        assert!(!inner.contains(&outer));
+        assert_eq!(outer.width(), 10.0);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let bp = BoundPair::new(1, 5).unwrap();
+        assert_eq!(
+            ClosedInterval::try_from(Interval::Closed { bound_pair: bp }),
+            ClosedInterval::new(1, 5).ok_or(IntervalError::KindMismatch)
+        );
+        assert_eq!(
+            ClosedInterval::try_from(Interval::Singleton { at: 2 }),
+            ClosedInterval::new(2, 2).ok_or(IntervalError::KindMismatch)
+        );
+        assert_eq!(
+            OpenInterval::try_from(Interval::Closed { bound_pair: bp }),
+            Err(IntervalError::KindMismatch)
+        );
+        assert_eq!(
+            OpenInterval::try_from(Interval::Singleton { at: 2 }),
+            Err(IntervalError::KindMismatch)
+        );
+        assert_eq!(
+            Interval::from(LeftHalfOpenInterval::new(1, 5).unwrap()),
+            Interval::LeftHalfOpen { bound_pair: bp }
+        );
+        assert_eq!(
+            Interval::from(ClosedInterval::new(3, 3).unwrap()),
+            Interval::Singleton { at: 3 }
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! wasm-bindgen bindings for primitive bound Intervals
+//!
+//! Exposes [IntervalF64] and [IntervalI64] (an i64 bound surfaces as a JS
+//! BigInt) to JavaScript, so web front-ends share the exact Interval
+//! semantics of the Rust implementation rather than re-implementing them.
+//! Each wrapper converts to and from the corresponding [Interval] for use on
+//! the Rust side.
+//!
+//! ```js
+//! const a = IntervalF64.rightHalfOpen(1.0, 5.0);
+//! const b = IntervalF64.closed(3.0, 8.0);
+//! a.intersect(b).toString(); // "[3.0..5.0)"
+//! ```
+
+use crate::bound_pair::BoundPair;
+use crate::interval::Interval;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+macro_rules! wasm_interval {
+    ($(#[$meta:meta])* $name:ident, $t:ty) => {
+        $(#[$meta])*
+        #[wasm_bindgen]
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $name {
+            inner: Interval<$t>,
+        }
+
+        #[wasm_bindgen]
+        impl $name {
+            /// The Closed interval `[left..right]`, or undefined unless
+            /// left < right
+            pub fn closed(left: $t, right: $t) -> Option<$name> {
+                BoundPair::new(left, right)
+                    .map(|bound_pair| Interval::Closed { bound_pair }.into())
+            }
+
+            /// The Open interval `(left..right)`, or undefined unless
+            /// left < right
+            pub fn open(left: $t, right: $t) -> Option<$name> {
+                BoundPair::new(left, right)
+                    .map(|bound_pair| Interval::Open { bound_pair }.into())
+            }
+
+            /// The LeftHalfOpen interval `(left..right]`, or undefined unless
+            /// left < right
+            #[wasm_bindgen(js_name = leftHalfOpen)]
+            pub fn left_half_open(left: $t, right: $t) -> Option<$name> {
+                BoundPair::new(left, right)
+                    .map(|bound_pair| Interval::LeftHalfOpen { bound_pair }.into())
+            }
+
+            /// The RightHalfOpen interval `[left..right)`, or undefined unless
+            /// left < right
+            #[wasm_bindgen(js_name = rightHalfOpen)]
+            pub fn right_half_open(left: $t, right: $t) -> Option<$name> {
+                BoundPair::new(left, right)
+                    .map(|bound_pair| Interval::RightHalfOpen { bound_pair }.into())
+            }
+
+            /// The UnboundedClosedRight interval `(←..right]`
+            #[wasm_bindgen(js_name = unboundedClosedRight)]
+            pub fn unbounded_closed_right(right: $t) -> $name {
+                Interval::UnboundedClosedRight { right }.into()
+            }
+
+            /// The UnboundedOpenRight interval `(←..right)`
+            #[wasm_bindgen(js_name = unboundedOpenRight)]
+            pub fn unbounded_open_right(right: $t) -> $name {
+                Interval::UnboundedOpenRight { right }.into()
+            }
+
+            /// The UnboundedClosedLeft interval `[left..→)`
+            #[wasm_bindgen(js_name = unboundedClosedLeft)]
+            pub fn unbounded_closed_left(left: $t) -> $name {
+                Interval::UnboundedClosedLeft { left }.into()
+            }
+
+            /// The UnboundedOpenLeft interval `(left..→)`
+            #[wasm_bindgen(js_name = unboundedOpenLeft)]
+            pub fn unbounded_open_left(left: $t) -> $name {
+                Interval::UnboundedOpenLeft { left }.into()
+            }
+
+            /// The Singleton interval `[at]`
+            pub fn singleton(at: $t) -> $name {
+                Interval::Singleton { at }.into()
+            }
+
+            /// The Unbounded interval `(←..→)`
+            pub fn unbounded() -> $name {
+                Interval::Unbounded.into()
+            }
+
+            /// The Empty interval
+            pub fn empty() -> $name {
+                Interval::Empty.into()
+            }
+
+            /// Verify whether self contains the specified interval
+            pub fn contains(&self, other: &$name) -> bool {
+                self.inner.contains(&other.inner)
+            }
+
+            /// Verify whether self contains the specified value
+            #[wasm_bindgen(js_name = containsValue)]
+            pub fn contains_value(&self, value: $t) -> bool {
+                self.inner.contains(&Interval::Singleton { at: value })
+            }
+
+            /// Intersect with the specified interval
+            pub fn intersect(&self, other: &$name) -> $name {
+                self.inner.intersect(&other.inner).into()
+            }
+
+            /// Display in the same notation as the Rust Display implementation
+            #[wasm_bindgen(js_name = toString)]
+            pub fn display(&self) -> String {
+                self.inner.to_string()
+            }
+        }
+
+        impl From<Interval<$t>> for $name {
+            fn from(inner: Interval<$t>) -> $name {
+                $name { inner }
+            }
+        }
+
+        impl From<$name> for Interval<$t> {
+            fn from(wrapper: $name) -> Interval<$t> {
+                wrapper.inner
+            }
+        }
+    };
+}
+
+wasm_interval!(
+    /// JavaScript binding for `Interval<f64>`
+    IntervalF64,
+    f64
+);
+
+wasm_interval!(
+    /// JavaScript binding for `Interval<i64>`
+    IntervalI64,
+    i64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructors() {
+        assert!(IntervalF64::closed(1.0, 1.0).is_none());
+        assert!(IntervalF64::open(f64::NAN, 1.0).is_none());
+        assert_eq!(
+            Interval::from(IntervalI64::left_half_open(1, 5).unwrap()),
+            Interval::LeftHalfOpen {
+                bound_pair: BoundPair::new(1, 5).unwrap()
+            }
+        );
+        assert_eq!(
+            Interval::from(IntervalI64::unbounded_open_left(3)),
+            Interval::UnboundedOpenLeft { left: 3 }
+        );
+    }
+
+    #[test]
+    fn test_queries_match_interval() {
+        let a = IntervalF64::right_half_open(1.0, 5.0).unwrap();
+        let b = IntervalF64::closed(3.0, 8.0).unwrap();
+        assert_eq!(a.intersect(&b).display(), "[3.0..5.0)");
+        assert!(a.contains_value(1.0));
+        assert!(!a.contains_value(5.0));
+        assert!(IntervalF64::unbounded().contains(&a));
+        assert!(!a.contains(&IntervalF64::empty()));
+        assert!(!IntervalI64::singleton(2).contains(&IntervalI64::unbounded_closed_right(2)));
+        assert_eq!(IntervalI64::empty().display(), "Empty");
+    }
+}